@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, Map,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    vec, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
 /// Centralized contract error codes. Auth failures are signaled by host panic (require_auth).
@@ -41,6 +41,102 @@ pub enum RevoraError {
     PayoutAssetMismatch = 15,
     /// Metadata string exceeds maximum allowed length.
     MetadataTooLarge = 16,
+    /// Offering has been closed; no further revenue can be reported.
+    OfferingClosed = 17,
+    /// Revenue for this period was already reported and `override_existing` was not set.
+    DuplicatePeriod = 18,
+    /// Batch input exceeds the maximum allowed size.
+    BatchTooLarge = 19,
+    /// Caller is not authorized to perform this action.
+    Unauthorized = 20,
+    /// Contract has already been initialized.
+    AlreadyInitialized = 21,
+    /// Platform fee exceeded 10000 bps (100%).
+    InvalidFeeBps = 22,
+    /// Holdings snapshot has zero total supply.
+    EmptyHoldings = 23,
+    /// Metadata string is empty.
+    EmptyMetadata = 24,
+    /// No metadata is stored for the offering.
+    MetadataNotFound = 25,
+    /// Requested period range spans more than MAX_PAGE_LIMIT periods.
+    RangeTooWide = 26,
+    /// Contract is paused; state-changing entrypoints are disabled.
+    ContractPaused = 27,
+    /// Role bitflags are zero or contain unknown bits.
+    InvalidRole = 28,
+    /// Issuer already has an offering for the token.
+    DuplicateOffering = 29,
+    /// Metadata length cap is zero or above MAX_METADATA_LENGTH_CEILING.
+    InvalidMetadataCap = 30,
+    /// Revenue report with this idempotency key was already accepted.
+    DuplicateReport = 31,
+    /// `min_bps` is greater than `max_bps`.
+    InvalidBpsRange = 32,
+    /// Period guard is on and `period_id` is not above the highest period reported so far.
+    NonMonotonicPeriod = 33,
+    /// Page limit override is zero or above MAX_PAGE_LIMIT_CEILING.
+    InvalidPageLimit = 34,
+    /// Offering decimals above MAX_DECIMALS (18).
+    InvalidDecimals = 35,
+    /// Rescue amount is not positive or exceeds the balance not owed to holders or fees.
+    InsufficientSurplus = 36,
+    /// Report memo or custom event data exceeds MAX_MEMO_LENGTH bytes.
+    MemoTooLong = 37,
+    /// Unknown sort mode passed to `get_offerings_page_sorted`.
+    InvalidSortMode = 38,
+    /// Destructive call made without `confirm = true`.
+    ConfirmationRequired = 39,
+    /// No revenue report is stored for the requested period.
+    ReportNotFound = 40,
+    /// Non-negative report amount below the offering's minimum report amount.
+    AmountBelowMinimum = 41,
+    /// Offering is paused via `pause_offering`.
+    OfferingPaused = 42,
+    /// FX rate passed to `report_revenue_fx` is zero or negative.
+    InvalidFxRate = 43,
+    /// Blacklist addition would exceed the token's blacklist size cap.
+    BlacklistFull = 44,
+    /// Claim deadline passed to `report_revenue_with_deadline` is not in the future.
+    InvalidDeadline = 45,
+    /// `sweep_expired` before the period's claim deadline (or with none set).
+    DeadlineNotReached = 46,
+    /// `request_blacklist_appeal` by an investor who is not blacklisted for the token.
+    NotBlacklisted = 47,
+    /// Offering parameters were locked with `lock_offering_config`.
+    OfferingLocked = 48,
+    /// `emit_custom_event` tag collides with an event symbol the contract emits itself.
+    ReservedEventTag = 49,
+    /// Revenue report within the offering's `set_report_cooldown` interval.
+    CooldownActive = 50,
+}
+
+/// Error codes beyond `RevoraError`, which is at the 50-case limit of a contract error
+/// spec. Raised with `panic_with_error!`, so callers see them as contract error codes
+/// (`InvokeError::Contract(code)`) rather than `RevoraError` values.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u32)]
+pub enum RevoraErrorExt {
+    /// Token-moving call re-entered while another one is still in progress.
+    Reentrancy = 51,
+    /// Registration or revenue report after the contract was deprecated (`mark_deprecated`).
+    ContractDeprecated = 52,
+    /// A cumulative amount (revenue, fees, claims or deposits) would overflow `i128`.
+    Overflow = 53,
+    /// Pagination cursor past the end of the list it claims to page through.
+    InvalidCursor = 54,
+    /// `withdraw_fees` to an address other than the registered fee destination.
+    UnregisteredDestination = 55,
+    /// A time-locked change was accepted before its delay elapsed.
+    TimelockActive = 56,
+    /// Holdings snapshot whose balances sum to more than the offering's `max_supply`.
+    SupplyCapExceeded = 57,
+    /// Negative `max_supply` at registration.
+    InvalidSupplyCap = 58,
+    /// Offering status change not allowed from its current status (e.g. reopening a
+    /// closed offering).
+    InvalidStatusTransition = 59,
 }
 
 // ── Event symbols ────────────────────────────────────────────
@@ -50,10 +146,43 @@ const EVENT_REVENUE_REPORT_INITIAL: Symbol = symbol_short!("rev_init");
 const EVENT_REVENUE_REPORT_INITIAL_ASSET: Symbol = symbol_short!("rev_inia");
 const EVENT_REVENUE_REPORT_OVERRIDE: Symbol = symbol_short!("rev_ovrd");
 const EVENT_REVENUE_REPORT_OVERRIDE_ASSET: Symbol = symbol_short!("rev_ovra");
-const EVENT_REVENUE_REPORT_REJECTED: Symbol = symbol_short!("rev_rej");
-const EVENT_REVENUE_REPORT_REJECTED_ASSET: Symbol = symbol_short!("rev_reja");
 const EVENT_BL_ADD: Symbol = symbol_short!("bl_add");
 const EVENT_BL_REM: Symbol = symbol_short!("bl_rem");
+const EVENT_BL_BATCH: Symbol = symbol_short!("bl_batch");
+const EVENT_BL_ADD_UNTIL: Symbol = symbol_short!("bl_add_u");
+const EVENT_BL_ADD_REASON: Symbol = symbol_short!("bl_add_r");
+const EVENT_PAGE_LIMIT_SET: Symbol = symbol_short!("page_cfg");
+const EVENT_METADATA_RENAMED: Symbol = symbol_short!("meta_ren");
+const EVENT_REPORTER_CONTRACT_SET: Symbol = symbol_short!("rep_c_set");
+const EVENT_ISSUER_CLEARED: Symbol = symbol_short!("iss_clear");
+const EVENT_METADATA_HASH: Symbol = symbol_short!("meta_hash");
+const EVENT_OWNERSHIP_STARTED: Symbol = symbol_short!("own_start");
+const EVENT_OWNERSHIP_DONE: Symbol = symbol_short!("own_done");
+const EVENT_REPORT_MEMO: Symbol = symbol_short!("rev_memo");
+const EVENT_REPORT_FX: Symbol = symbol_short!("rev_fx");
+const EVENT_CLAIM_DEADLINE: Symbol = symbol_short!("rev_dl");
+const EVENT_SWEPT: Symbol = symbol_short!("swept");
+const EVENT_RESCUE: Symbol = symbol_short!("rescue");
+const EVENT_REPORTER_SET: Symbol = symbol_short!("rep_set");
+const EVENT_BL_FORCE_REMOVED: Symbol = symbol_short!("bl_frc_rm");
+const EVENT_MIGRATED: Symbol = symbol_short!("migrated");
+const EVENT_COUNT_REPAIRED: Symbol = symbol_short!("cnt_fix");
+const EVENT_TOKEN_FIXED: Symbol = symbol_short!("tok_fixed");
+const EVENT_CONFIG_LOCKED: Symbol = symbol_short!("cfg_lock");
+const EVENT_METADATA_DEFAULT: Symbol = symbol_short!("meta_dflt");
+const EVENT_METADATA_TYPE: Symbol = symbol_short!("meta_type");
+const EVENT_DEPRECATED: Symbol = symbol_short!("deprecate");
+const EVENT_FEE_DEST_SET: Symbol = symbol_short!("fee_dest");
+const EVENT_FEE_DEST_DONE: Symbol = symbol_short!("fee_dacc");
+const EVENT_OFFERING_PAUSED: Symbol = symbol_short!("off_pause");
+const EVENT_OFFERING_UNPAUSED: Symbol = symbol_short!("off_unpse");
+const EVENT_BL_TRANSFERRED: Symbol = symbol_short!("bl_xfer");
+const EVENT_BL_CLEARED: Symbol = symbol_short!("bl_clear");
+const EVENT_BL_CAP_SET: Symbol = symbol_short!("bl_cap");
+const EVENT_BL_APPEAL: Symbol = symbol_short!("bl_appeal");
+const EVENT_WL_ADD: Symbol = symbol_short!("wl_add");
+const EVENT_WL_REM: Symbol = symbol_short!("wl_rem");
+const EVENT_WL_MODE: Symbol = symbol_short!("wl_mode");
 // Versioned event symbols (v1). We emit legacy events for compatibility
 // and also emit explicit v1 events that include a leading `version` field.
 const EVENT_OFFER_REG_V1: Symbol = symbol_short!("ofr_reg1");
@@ -63,15 +192,22 @@ const EVENT_REV_REP_V1: Symbol = symbol_short!("rv_rep1");
 const EVENT_REV_REPA_V1: Symbol = symbol_short!("rv_repa1");
 
 const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Version of this contract's interface, returned by `get_contract_version` and carried in
+/// the `upgraded` event. Bump it whenever a method signature or event shape changes.
+pub const CONTRACT_VERSION: u32 = 3;
 const EVENT_CONCENTRATION_WARNING: Symbol = symbol_short!("conc_warn");
 const EVENT_REV_DEPOSIT: Symbol = symbol_short!("rev_dep");
+const EVENT_HOLDINGS_RECORDED: Symbol = symbol_short!("hold_rec");
 const EVENT_CLAIM: Symbol = symbol_short!("claim");
 const EVENT_SHARE_SET: Symbol = symbol_short!("share_set");
 const EVENT_FREEZE: Symbol = symbol_short!("freeze");
+const EVENT_UPGRADED: Symbol = symbol_short!("upgraded");
 const EVENT_CLAIM_DELAY_SET: Symbol = symbol_short!("delay_set");
 const EVENT_ISSUER_TRANSFER_PROPOSED: Symbol = symbol_short!("iss_prop");
 const EVENT_ISSUER_TRANSFER_ACCEPTED: Symbol = symbol_short!("iss_acc");
 const EVENT_ISSUER_TRANSFER_CANCELLED: Symbol = symbol_short!("iss_canc");
+const EVENT_ISSUER_TRANSFERRED: Symbol = symbol_short!("iss_xfer");
 const EVENT_TESTNET_MODE: Symbol = symbol_short!("test_mode");
 const EVENT_INIT: Symbol = symbol_short!("init");
 const EVENT_PAUSED: Symbol = symbol_short!("paused");
@@ -79,16 +215,179 @@ const EVENT_UNPAUSED: Symbol = symbol_short!("unpaused");
 const EVENT_DIST_CALC: Symbol = symbol_short!("dist_calc");
 const EVENT_METADATA_SET: Symbol = symbol_short!("meta_set");
 const EVENT_METADATA_UPDATED: Symbol = symbol_short!("meta_upd");
+const EVENT_METADATA_DELETED: Symbol = symbol_short!("meta_del");
+const EVENT_METADATA_CAP_SET: Symbol = symbol_short!("meta_cap");
+const EVENT_OFFER_UPDATED: Symbol = symbol_short!("offer_upd");
+const EVENT_OFFER_CLOSED: Symbol = symbol_short!("offer_cls");
+const EVENT_OFFER_REMOVED: Symbol = symbol_short!("offer_rm");
+const EVENT_ISSUER_ADMIN_SET: Symbol = symbol_short!("admin_set");
+const EVENT_ISSUER_ADMIN_ADDED: Symbol = symbol_short!("admin_add");
+const EVENT_ISSUER_ADMIN_REVOKED: Symbol = symbol_short!("admin_rev");
+const EVENT_ROLE_GRANTED: Symbol = symbol_short!("role_gr");
+const EVENT_ROLE_REVOKED: Symbol = symbol_short!("role_rev");
+const EVENT_FEES_WITHDRAWN: Symbol = symbol_short!("fee_wd");
+
+/// Event symbols the contract emits itself; `emit_custom_event` rejects them as tags.
+const RESERVED_EVENT_TAGS: [Symbol; 79] = [
+    EVENT_REVENUE_REPORTED,
+    EVENT_REVENUE_REPORTED_ASSET,
+    EVENT_REVENUE_REPORT_INITIAL,
+    EVENT_REVENUE_REPORT_INITIAL_ASSET,
+    EVENT_REVENUE_REPORT_OVERRIDE,
+    EVENT_REVENUE_REPORT_OVERRIDE_ASSET,
+    EVENT_BL_ADD,
+    EVENT_BL_REM,
+    EVENT_BL_BATCH,
+    EVENT_BL_ADD_UNTIL,
+    EVENT_BL_ADD_REASON,
+    EVENT_PAGE_LIMIT_SET,
+    EVENT_METADATA_RENAMED,
+    EVENT_REPORTER_CONTRACT_SET,
+    EVENT_ISSUER_CLEARED,
+    EVENT_METADATA_HASH,
+    EVENT_OWNERSHIP_STARTED,
+    EVENT_OWNERSHIP_DONE,
+    EVENT_REPORT_MEMO,
+    EVENT_REPORT_FX,
+    EVENT_CLAIM_DEADLINE,
+    EVENT_SWEPT,
+    EVENT_RESCUE,
+    EVENT_REPORTER_SET,
+    EVENT_BL_FORCE_REMOVED,
+    EVENT_MIGRATED,
+    EVENT_COUNT_REPAIRED,
+    EVENT_TOKEN_FIXED,
+    EVENT_CONFIG_LOCKED,
+    EVENT_METADATA_DEFAULT,
+    EVENT_METADATA_TYPE,
+    EVENT_DEPRECATED,
+    EVENT_FEE_DEST_SET,
+    EVENT_FEE_DEST_DONE,
+    EVENT_OFFERING_PAUSED,
+    EVENT_OFFERING_UNPAUSED,
+    EVENT_BL_TRANSFERRED,
+    EVENT_BL_CLEARED,
+    EVENT_BL_CAP_SET,
+    EVENT_BL_APPEAL,
+    EVENT_WL_ADD,
+    EVENT_WL_REM,
+    EVENT_WL_MODE,
+    EVENT_OFFER_REG_V1,
+    EVENT_REV_INIT_V1,
+    EVENT_REV_INIA_V1,
+    EVENT_REV_REP_V1,
+    EVENT_REV_REPA_V1,
+    EVENT_CONCENTRATION_WARNING,
+    EVENT_REV_DEPOSIT,
+    EVENT_HOLDINGS_RECORDED,
+    EVENT_CLAIM,
+    EVENT_SHARE_SET,
+    EVENT_FREEZE,
+    EVENT_UPGRADED,
+    EVENT_CLAIM_DELAY_SET,
+    EVENT_ISSUER_TRANSFER_PROPOSED,
+    EVENT_ISSUER_TRANSFER_ACCEPTED,
+    EVENT_ISSUER_TRANSFER_CANCELLED,
+    EVENT_ISSUER_TRANSFERRED,
+    EVENT_TESTNET_MODE,
+    EVENT_INIT,
+    EVENT_PAUSED,
+    EVENT_UNPAUSED,
+    EVENT_DIST_CALC,
+    EVENT_METADATA_SET,
+    EVENT_METADATA_UPDATED,
+    EVENT_METADATA_DELETED,
+    EVENT_METADATA_CAP_SET,
+    EVENT_OFFER_UPDATED,
+    EVENT_OFFER_CLOSED,
+    EVENT_OFFER_REMOVED,
+    EVENT_ISSUER_ADMIN_SET,
+    EVENT_ISSUER_ADMIN_ADDED,
+    EVENT_ISSUER_ADMIN_REVOKED,
+    EVENT_ROLE_GRANTED,
+    EVENT_ROLE_REVOKED,
+    EVENT_FEES_WITHDRAWN,
+    symbol_short!("offer_reg"),
+];
 
 const BPS_DENOMINATOR: i128 = 10_000;
 
+/// Amount precision assumed for offerings registered without explicit decimals (XLM).
+const DEFAULT_DECIMALS: u32 = 7;
+
+/// Largest accepted offering decimals.
+const MAX_DECIMALS: u32 = 18;
+
+/// Lifecycle status of an offering. `Active` and `Paused` can switch back and forth;
+/// `Closed` is final. Only `Active` offerings accept revenue reports.
 #[contracttype]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OfferingStatus {
+    Active = 0,
+    Paused = 1,
+    Closed = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Offering {
     pub issuer: Address,
     pub token: Address,
     pub revenue_share_bps: u32,
     pub payout_asset: Address,
+    /// `Active` on registration; see `set_offering_status`.
+    pub status: OfferingStatus,
+    /// Decimal precision of reported amounts (DEFAULT_DECIMALS unless registered otherwise).
+    pub decimals: u32,
+    /// Upper bound on a holdings snapshot's summed balances; 0 means uncapped.
+    pub max_supply: i128,
+}
+
+/// `Offering` as stored before the `active` flag existed; read only by `migrate_storage`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyOffering {
+    pub issuer: Address,
+    pub token: Address,
+    pub revenue_share_bps: u32,
+    pub payout_asset: Address,
+}
+
+/// `Offering` as stored before `decimals` existed; read only by `migrate_storage`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OfferingV1 {
+    pub issuer: Address,
+    pub token: Address,
+    pub revenue_share_bps: u32,
+    pub payout_asset: Address,
+    pub active: bool,
+}
+
+/// `Offering` as stored before `max_supply` existed; read only by `migrate_storage`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OfferingV2 {
+    pub issuer: Address,
+    pub token: Address,
+    pub revenue_share_bps: u32,
+    pub payout_asset: Address,
+    pub active: bool,
+    pub decimals: u32,
+}
+
+/// `Offering` as stored before `status` replaced the `active` flag; read only by
+/// `migrate_storage`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OfferingV3 {
+    pub issuer: Address,
+    pub token: Address,
+    pub revenue_share_bps: u32,
+    pub payout_asset: Address,
+    pub active: bool,
+    pub decimals: u32,
+    pub max_supply: i128,
 }
 
 /// Per-offering concentration guardrail config (#26).
@@ -101,6 +400,17 @@ pub struct ConcentrationLimitConfig {
     pub enforce: bool,
 }
 
+/// Per-offering revenue period ordering guard.
+/// enforce: if true, report_revenue fails with `NonMonotonicPeriod` unless `period_id` is
+/// strictly greater than the highest period reported so far.
+/// allow_backdated: temporarily lets back-dated reports through for corrections.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeriodGuardConfig {
+    pub enforce: bool,
+    pub allow_backdated: bool,
+}
+
 /// Per-offering audit log summary (#34).
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -109,6 +419,39 @@ pub struct AuditSummary {
     pub report_count: u64,
 }
 
+/// Everything about one offering in a single read, for auditors (`snapshot_offering`).
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OfferingSnapshot {
+    pub offering: Offering,
+    pub metadata: Option<String>,
+    pub metadata_version: u32,
+    pub total_revenue: i128,
+    /// False once the offering is closed (paused offerings count as active here).
+    pub active: bool,
+    pub blacklist_count: u32,
+}
+
+/// Ledger timestamps of an offering's lifecycle changes (`get_offering_history`).
+/// `updated_at` is the last `update_offering`; `closed_at` is set by `close_offering`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OfferingHistory {
+    pub created_at: u64,
+    pub updated_at: Option<u64>,
+    pub closed_at: Option<u64>,
+}
+
+/// Contract-wide telemetry returned by `get_contract_stats`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractStats {
+    pub total_offerings: u32,
+    pub total_blacklist_entries: u32,
+    pub paused: bool,
+    pub initialized: bool,
+}
+
 /// Result of simulate_distribution (#29): per-holder payout and total.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -179,19 +522,192 @@ pub enum DataKey {
     Safety,
     /// Global pause flag; when true, state-mutating ops are disabled (#7).
     Paused,
+    /// Platform fee in basis points, set at initialization.
+    PlatformFeeBps,
     /// Feature flag: emit versioned events when present (v1 schema).
     EventVersioningEnabled,
     /// Per (issuer, token): metadata reference (IPFS hash, HTTPS URI, etc.)
     OfferingMetadata(Address, Address),
+    /// Per-offering whitelist: token -> map of allowed investors.
+    Whitelist(Address),
+    /// Per-offering whitelist mode flag; when true, only whitelisted investors are eligible.
+    WhitelistEnabled(Address),
+    /// Per-issuer delegated admin; may manage the issuer's offering metadata.
+    IssuerAdmin(Address),
+    /// Holdings snapshot for (offering_token, period_id): holder -> balance at period close.
+    Holdings(Address, u64),
+    /// Per (issuer, token): number of times metadata has been set (0 if never).
+    MetadataVersion(Address, Address),
+    /// Platform fees accrued from revenue reports, per payout asset.
+    AccruedFees(Address),
+    /// Blacklisted addresses for `token` in insertion order: Vec<Address>.
+    BlacklistOrder(Address),
+    /// Blacklist expiry timestamps for `token`: Map<Address, u64>. Entries without one are
+    /// permanent.
+    BlacklistExpiry(Address),
+    /// Admin override of the maximum metadata length in bytes.
+    MaxMetadataLength,
+    /// Idempotency key of an accepted report for (offering_token, key). Temporary storage.
+    ReportKey(Address, BytesN<32>),
+    /// Role bitflags granted by the admin: Map<Address, u32>.
+    Roles,
+    /// Offering token registered under (issuer, offering_id).
+    OfferingById(Address, String),
+    /// Total number of blacklist entries stored across all tokens.
+    BlacklistTotal,
+    /// Per (issuer, token): period ordering guard config.
+    PeriodGuard(Address, Address),
+    /// Per (issuer, token): highest period_id reported so far.
+    LastPeriod(Address, Address),
+    /// Admin override of the pagination page limit.
+    MaxPageLimit,
+    /// Total number of offerings registered across all issuers.
+    OfferingTotal,
+    /// Per (issuer, token): account allowed to report revenue in place of the issuer.
+    Reporter(Address, Address),
+    /// Deposited revenue not yet claimed by holders, per payment token.
+    ClaimableLiability(Address),
+    /// Per (issuer, token, period_id): memo attached with `report_revenue_with_memo`.
+    ReportMemo(Address, Address, u64),
+    /// Proposed new admin awaiting `accept_ownership`.
+    PendingAdmin,
+    /// Per (issuer, token): content hash committed alongside the metadata reference.
+    MetadataHash(Address, Address),
+    /// Per (issuer, token): contract allowed to report revenue in place of the issuer.
+    ReporterContract(Address, Address),
+    /// Number of times each tracked event has been emitted: Map<Symbol, u64>.
+    EventCounts,
+}
+
+/// Storage keys added once `DataKey` reached the `contracttype` limit of 50 variants.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKeyExt {
+    /// Per (issuer, token): smallest accepted non-negative revenue report amount.
+    MinReportAmount(Address, Address),
+    /// Per-offering pause flag (by token) from before `OfferingStatus`; `migrate_storage`
+    /// folds it into the offering's status.
+    OfferingPaused(Address),
+    /// Per-offering lifecycle timestamps (by token).
+    OfferingHistory(Address),
+    /// Per (issuer, token, period_id): quote token and FX rate of a `report_revenue_fx` report.
+    ReportFx(Address, Address, u64),
+    /// Per issuer: additional delegated admins (`add_admin`), in the order they were added.
+    IssuerAdmins(Address),
+    /// Per token: number of effective blacklist adds and removes so far.
+    BlacklistVersion(Address),
+    /// Per (token, version): the blacklist change that produced `version` (investor, added).
+    BlacklistChange(Address, u64),
+    /// Admin override for the per-token blacklist size cap.
+    MaxBlacklistSize,
+    /// Per (token, period_id): ledger timestamp after which unclaimed revenue may be swept.
+    ClaimDeadline(Address, u64),
+    /// Per (token, period_id): total claimed so far from the period's deposit.
+    PeriodClaimed(Address, u64),
+    /// Per (token, period_id): set once `sweep_expired` returned the unclaimed remainder.
+    PeriodSwept(Address, u64),
+    /// Per issuer: revenue reports accepted across all of the issuer's offerings.
+    IssuerReportCount(Address),
+    /// Per-offering irreversible parameter lock (by token), set by `lock_offering_config`.
+    ConfigLocked(Address),
+    /// Per issuer: metadata URI used by offerings without their own (`set_default_metadata`).
+    DefaultMetadata(Address),
+    /// Per-offering minimum seconds between revenue reports (by token).
+    ReportCooldown(Address),
+    /// Per-offering ledger timestamp of the latest accepted revenue report (by token).
+    LastReportAt(Address),
+    /// Issuers with at least one offering, in order of their first registration.
+    Issuers,
+    /// Per-offering content type of the stored metadata (by token), e.g. `json`.
+    MetadataContentType(Address),
+    /// Set while a token-moving call is in progress; see `enter_non_reentrant`.
+    Entered,
+    /// Per (token, period): number of holders whose claims paid out from the period.
+    PeriodClaimants(Address, u64),
+    /// Contract-level flag set by `mark_deprecated`.
+    Deprecated,
+    /// Only address `withdraw_fees` may send to, once registered (`set_fee_destination`).
+    FeeDestination,
+    /// Proposed fee destination and the timestamp from which it can be accepted.
+    PendingFeeDestination,
+    /// Per-token map: blacklisted investor -> (reason code, ledger timestamp when listed).
+    BlacklistDetails(Address),
+    /// Per token: issuers with an offering for it, in registration order. The first is the
+    /// token's current issuer (`OfferingIssuer`).
+    TokenIssuers(Address),
 }
 
 /// Maximum number of offerings returned in a single page.
 const MAX_PAGE_LIMIT: u32 = 20;
 
+/// Hard ceiling for the admin-configurable page limit (`set_max_page_limit`).
+const MAX_PAGE_LIMIT_CEILING: u32 = 100;
+
+/// Maximum number of investors accepted by a single blacklist batch call.
+const MAX_BLACKLIST_BATCH: u32 = 100;
+
+/// Default maximum number of blacklist entries per token (`set_max_blacklist_size`).
+const DEFAULT_MAX_BLACKLIST_SIZE: u32 = 500;
+
+/// Maximum number of blacklisted addresses embedded in revenue report events.
+/// Longer blacklists are cut to this size in every report event that carries one, but only
+/// `rev_init` / `rev_ovrd` flag it with `truncated`: the backward-compatible `rev_rep`, the
+/// asset-topic variants and the v1 events keep their shape, so consumers of those should
+/// read the flag from the `rev_init` / `rev_ovrd` event of the same report.
+const MAX_REPORT_BLACKLIST: u32 = 50;
+
+/// `get_offerings_page_sorted` modes.
+pub const SORT_INSERTION: u32 = 0;
+pub const SORT_BPS_ASC: u32 = 1;
+pub const SORT_BPS_DESC: u32 = 2;
+
+/// `get_my_eligibility` reason codes.
+pub const ELIGIBLE: u32 = 0;
+pub const INELIGIBLE_BLACKLISTED: u32 = 1;
+pub const INELIGIBLE_NOT_WHITELISTED: u32 = 2;
+
+/// Blacklist reason code for entries added without one (`blacklist_add` and friends).
+/// Other codes are defined by the integrator.
+pub const BLACKLIST_REASON_UNSPECIFIED: u32 = 0;
+
+/// `get_metadata_resolved` source codes.
+pub const METADATA_SOURCE_OFFERING: u32 = 0;
+pub const METADATA_SOURCE_DEFAULT: u32 = 1;
+
+/// Content type reported by `get_metadata_full` for metadata stored without one.
+pub const METADATA_TYPE_UNKNOWN: Symbol = symbol_short!("unknown");
+
+/// Seconds a proposed fee destination must wait before `accept_fee_destination`.
+pub const FEE_DESTINATION_DELAY_SECS: u64 = 2 * 24 * 60 * 60;
+
+/// Maximum length in bytes of a revenue report memo.
+const MAX_MEMO_LENGTH: u32 = 256;
+
 /// Maximum number of periods that can be claimed in a single transaction.
 /// Keeps compute costs predictable within Soroban limits.
 const MAX_CLAIM_PERIODS: u32 = 50;
 
+/// Role bitflag: may manage blacklists.
+pub const ROLE_BLACKLIST_MANAGER: u32 = 1;
+/// Role bitflag: may set and delete metadata for any offering.
+pub const ROLE_METADATA_MANAGER: u32 = 2;
+/// Role bitflag: may manage platform fees.
+pub const ROLE_FEE_MANAGER: u32 = 4;
+const ALL_ROLES: u32 = ROLE_BLACKLIST_MANAGER | ROLE_METADATA_MANAGER | ROLE_FEE_MANAGER;
+
+/// How long (ledgers) a report idempotency key is remembered (7 days).
+const REPORT_KEY_TTL: u32 = 7 * LEDGERS_PER_DAY;
+
+/// Approximate number of ledgers closed per day (5s close time).
+const LEDGERS_PER_DAY: u32 = 17_280;
+
+/// Persistent entries whose remaining TTL is below this many ledgers are extended
+/// by `extend_ttl` / `bump_blacklist_ttl` (30 days).
+pub const TTL_THRESHOLD: u32 = 30 * LEDGERS_PER_DAY;
+
+/// TTL, in ledgers, that `extend_ttl` / `bump_blacklist_ttl` extend entries to (120 days).
+pub const TTL_EXTEND_TO: u32 = 120 * LEDGERS_PER_DAY;
+
 #[contract]
 pub struct RevoraRevenueShare;
 
@@ -219,17 +735,144 @@ impl RevoraRevenueShare {
         Ok(())
     }
 
+    /// Reentrancy guard for functions that call out to token contracts: panics with
+    /// `RevoraErrorExt::Reentrancy` if one is already running, else marks one as running
+    /// until `exit_non_reentrant`. Error returns roll back the flag with the rest of the
+    /// state, so only successful paths need to clear it. The flag lives in temporary
+    /// storage: it never outlives a call, so it needs no persistent entry or rent.
+    fn enter_non_reentrant(env: &Env) {
+        let store = env.storage().temporary();
+        if store.has(&DataKeyExt::Entered) {
+            panic_with_error!(env, RevoraErrorExt::Reentrancy);
+        }
+        store.set(&DataKeyExt::Entered, &true);
+    }
+
+    fn exit_non_reentrant(env: &Env) {
+        env.storage().temporary().remove(&DataKeyExt::Entered);
+    }
+
+    /// Unwrap a `checked_*` accumulator update, panicking with `RevoraErrorExt::Overflow`
+    /// (a contract error rather than a host trap) if it overflowed.
+    fn checked_amount(env: &Env, value: Option<i128>) -> i128 {
+        value.unwrap_or_else(|| panic_with_error!(env, RevoraErrorExt::Overflow))
+    }
+
+    /// Panic with `RevoraErrorExt::ContractDeprecated` once `mark_deprecated` has run.
+    fn require_not_deprecated(env: &Env) {
+        if Self::is_deprecated(env.clone()) {
+            panic_with_error!(env, RevoraErrorExt::ContractDeprecated);
+        }
+    }
+
     /// Get the current issuer for an offering token (used for auth checks after transfers).
     fn get_current_issuer(env: &Env, token: &Address) -> Option<Address> {
         let key = DataKey::OfferingIssuer(token.clone());
         env.storage().persistent().get(&key)
     }
 
-    /// Initialize admin and optional safety role for emergency pause (#7).
-    /// Can only be called once; panics if already initialized.
-    pub fn initialize(env: Env, admin: Address, safety: Option<Address>) {
+    /// Issuers with an offering for `token`, current issuer first. Offerings registered
+    /// before the index existed are found through `OfferingIssuer` alone.
+    fn get_token_issuers(env: &Env, token: &Address) -> Vec<Address> {
+        let key = DataKeyExt::TokenIssuers(token.clone());
+        env.storage().persistent().get(&key).unwrap_or_else(|| {
+            let mut issuers = Vec::new(env);
+            if let Some(issuer) = Self::get_current_issuer(env, token) {
+                issuers.push_back(issuer);
+            }
+            issuers
+        })
+    }
+
+    /// Store `token`'s issuer index and point `OfferingIssuer` at its first entry, or drop
+    /// both once no issuer is left.
+    fn set_token_issuers(env: &Env, token: &Address, issuers: &Vec<Address>) {
+        let store = env.storage().persistent();
+        let index_key = DataKeyExt::TokenIssuers(token.clone());
+        let issuer_key = DataKey::OfferingIssuer(token.clone());
+        match issuers.first() {
+            Some(current) => {
+                store.set(&index_key, issuers);
+                store.set(&issuer_key, &current);
+            }
+            None => {
+                store.remove(&index_key);
+                store.remove(&issuer_key);
+            }
+        }
+    }
+
+    /// Drop `issuer` from `token`'s issuer index. Returns true if it was the token's
+    /// current issuer; the next issuer in the index, if any, takes over.
+    fn unindex_token_issuer(env: &Env, token: &Address, issuer: &Address) -> bool {
+        let mut issuers = Self::get_token_issuers(env, token);
+        let was_current = issuers.first().as_ref() == Some(issuer);
+        if let Some(i) = issuers.first_index_of(issuer) {
+            issuers.remove(i);
+        }
+        Self::set_token_issuers(env, token, &issuers);
+        was_current
+    }
+
+    /// Require auth from a caller allowed to edit `token`'s blacklist: the contract admin,
+    /// the offering's current issuer or one of its delegated admins, or a
+    /// `ROLE_BLACKLIST_MANAGER` holder. For a token with no offering only the admin and
+    /// role holders qualify. Fails with `Unauthorized` otherwise.
+    fn require_blacklist_manager(
+        env: &Env,
+        caller: &Address,
+        token: &Address,
+    ) -> Result<(), RevoraError> {
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() == Some(caller) {
+            caller.require_auth();
+            return Ok(());
+        }
+        match Self::get_current_issuer(env, token) {
+            Some(issuer) => {
+                Self::require_issuer_or_admin(env, caller, &issuer, ROLE_BLACKLIST_MANAGER)
+            }
+            None if Self::has_role(env.clone(), caller.clone(), ROLE_BLACKLIST_MANAGER) => {
+                caller.require_auth();
+                Ok(())
+            }
+            None => Err(RevoraError::Unauthorized),
+        }
+    }
+
+    /// Require auth from `caller`, which must be `issuer`, one of the issuer's delegated
+    /// admins (`get_admins`), or an account holding `role`.
+    fn require_issuer_or_admin(
+        env: &Env,
+        caller: &Address,
+        issuer: &Address,
+        role: u32,
+    ) -> Result<(), RevoraError> {
+        if caller != issuer
+            && !Self::get_admins(env.clone(), issuer.clone()).contains(caller)
+            && !Self::has_role(env.clone(), caller.clone(), role)
+        {
+            return Err(RevoraError::Unauthorized);
+        }
+        caller.require_auth();
+        Ok(())
+    }
+
+    /// Initialize admin (contract owner), optional safety role for emergency pause (#7),
+    /// and the platform fee in basis points.
+    /// Can only be called once; fails with `AlreadyInitialized` if an admin is already set
+    /// and `InvalidFeeBps` if platform_fee_bps > 10000.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        safety: Option<Address>,
+        platform_fee_bps: u32,
+    ) -> Result<(), RevoraError> {
         if env.storage().persistent().has(&DataKey::Admin) {
-            panic!("already initialized");
+            return Err(RevoraError::AlreadyInitialized);
+        }
+        if platform_fee_bps > 10_000 {
+            return Err(RevoraError::InvalidFeeBps);
         }
         env.storage()
             .persistent()
@@ -238,7 +881,194 @@ impl RevoraRevenueShare {
             env.storage().persistent().set(&DataKey::Safety, &s);
         }
         env.storage().persistent().set(&DataKey::Paused, &false);
-        env.events().publish((EVENT_INIT, admin.clone()), (safety,));
+        env.storage()
+            .persistent()
+            .set(&DataKey::PlatformFeeBps, &platform_fee_bps);
+        env.events()
+            .publish((EVENT_INIT, admin.clone()), (safety, platform_fee_bps));
+        Ok(())
+    }
+
+    /// Platform fee in basis points (0 if not initialized).
+    pub fn get_platform_fee_bps(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlatformFeeBps)
+            .unwrap_or(0)
+    }
+
+    /// Platform fees accrued in `asset` by revenue reports and not yet withdrawn.
+    pub fn get_accrued_fees(env: Env, asset: Address) -> i128 {
+        let key = DataKey::AccruedFees(asset);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Transfer accrued platform fees in `asset` from the contract to `to`, returning the
+    /// amount withdrawn. Only what the contract holds beyond unclaimed deposits
+    /// (`get_claimable_liability`) can leave, so holder money is never used to pay fees;
+    /// any remainder stays accrued. Caller must be the admin or hold `ROLE_FEE_MANAGER`, and
+    /// `to` must be the fee destination registered with `set_fee_destination`; with none
+    /// registered, or any other `to`, the call panics with
+    /// `RevoraErrorExt::UnregisteredDestination`.
+    pub fn withdraw_fees(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        to: Address,
+    ) -> Result<i128, RevoraError> {
+        Self::enter_non_reentrant(&env);
+        Self::require_not_frozen(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&caller)
+            && !Self::has_role(env.clone(), caller.clone(), ROLE_FEE_MANAGER)
+        {
+            return Err(RevoraError::Unauthorized);
+        }
+        caller.require_auth();
+        if Self::get_fee_destination(env.clone()).as_ref() != Some(&to) {
+            panic_with_error!(&env, RevoraErrorExt::UnregisteredDestination);
+        }
+
+        let key = DataKey::AccruedFees(asset.clone());
+        let accrued: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let mut withdrawn = 0;
+        if accrued > 0 {
+            let client = token::Client::new(&env, &asset);
+            let contract_addr = env.current_contract_address();
+            let liability = Self::get_claimable_liability(env.clone(), asset.clone());
+            let available = client.balance(&contract_addr).saturating_sub(liability);
+            withdrawn = accrued.min(available).max(0);
+            if withdrawn > 0 {
+                env.storage().persistent().set(&key, &(accrued - withdrawn));
+                client.transfer(&contract_addr, &to, &withdrawn);
+            }
+        }
+
+        env.events()
+            .publish((EVENT_FEES_WITHDRAWN, asset, to), withdrawn);
+        Self::exit_non_reentrant(&env);
+        Ok(withdrawn)
+    }
+
+    /// Register `dest` as the only address `withdraw_fees` may pay out to, so a leaked
+    /// owner or fee-manager key cannot send fees elsewhere at once. The first registration
+    /// takes effect immediately. Later changes are only proposed: they become active via
+    /// `accept_fee_destination` after `FEE_DESTINATION_DELAY_SECS`, and a new proposal
+    /// replaces a pending one and restarts the delay. Fails with `Unauthorized` unless
+    /// `owner` is the admin. Emits `fee_dest` with `(dest, effective_at)`.
+    pub fn set_fee_destination(env: Env, owner: Address, dest: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&owner) {
+            return Err(RevoraError::Unauthorized);
+        }
+        owner.require_auth();
+
+        let now = env.ledger().timestamp();
+        let effective_at = if env.storage().persistent().has(&DataKeyExt::FeeDestination) {
+            let effective_at = now.saturating_add(FEE_DESTINATION_DELAY_SECS);
+            env.storage().persistent().set(
+                &DataKeyExt::PendingFeeDestination,
+                &(dest.clone(), effective_at),
+            );
+            effective_at
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::FeeDestination, &dest);
+            now
+        };
+        env.events()
+            .publish((EVENT_FEE_DEST_SET, owner), (dest, effective_at));
+        Ok(())
+    }
+
+    /// Make the destination proposed by `set_fee_destination` the active one. Fails with
+    /// `Unauthorized` unless `owner` is the admin and `NoTransferPending` if nothing is
+    /// proposed; panics with `RevoraErrorExt::TimelockActive` before the delay elapsed.
+    /// Emits `fee_dacc` with the new destination.
+    pub fn accept_fee_destination(env: Env, owner: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&owner) {
+            return Err(RevoraError::Unauthorized);
+        }
+        owner.require_auth();
+
+        let (dest, effective_at) =
+            Self::get_pending_fee_destination(env.clone()).ok_or(RevoraError::NoTransferPending)?;
+        if env.ledger().timestamp() < effective_at {
+            panic_with_error!(&env, RevoraErrorExt::TimelockActive);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::FeeDestination, &dest);
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::PendingFeeDestination);
+        env.events().publish((EVENT_FEE_DEST_DONE, owner), dest);
+        Ok(())
+    }
+
+    /// Address `withdraw_fees` is restricted to, if one is registered.
+    pub fn get_fee_destination(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKeyExt::FeeDestination)
+    }
+
+    /// Proposed fee destination and the timestamp from which it can be accepted, if any.
+    pub fn get_pending_fee_destination(env: Env) -> Option<(Address, u64)> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::PendingFeeDestination)
+    }
+
+    /// Deposited revenue in `asset` that holders have not claimed yet.
+    pub fn get_claimable_liability(env: Env, asset: Address) -> i128 {
+        let key = DataKey::ClaimableLiability(asset);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    fn adjust_claimable_liability(env: &Env, asset: &Address, delta: i128) {
+        let key = DataKey::ClaimableLiability(asset.clone());
+        let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let updated = Self::checked_amount(env, current.checked_add(delta)).max(0);
+        env.storage().persistent().set(&key, &updated);
+    }
+
+    /// Owner rescue of `asset` tokens stranded in the contract (sent directly, or left
+    /// over after all periods are claimed). Only the surplus can leave: the contract's
+    /// balance minus unclaimed deposits (`get_claimable_liability`) and accrued platform
+    /// fees. Fails with `Unauthorized` unless `owner` is the admin and with
+    /// `InsufficientSurplus` if `amount` is not positive or exceeds the surplus.
+    /// Emits `rescue` with the amount.
+    pub fn withdraw_stuck_tokens(
+        env: Env,
+        owner: Address,
+        asset: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), RevoraError> {
+        Self::enter_non_reentrant(&env);
+        Self::require_not_frozen(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&owner) {
+            return Err(RevoraError::Unauthorized);
+        }
+        owner.require_auth();
+
+        let client = token::Client::new(&env, &asset);
+        let contract_addr = env.current_contract_address();
+        let earmarked = Self::get_claimable_liability(env.clone(), asset.clone())
+            .saturating_add(Self::get_accrued_fees(env.clone(), asset.clone()));
+        let surplus = client.balance(&contract_addr).saturating_sub(earmarked);
+        if amount <= 0 || amount > surplus {
+            return Err(RevoraError::InsufficientSurplus);
+        }
+        client.transfer(&contract_addr, &to, &amount);
+
+        env.events().publish((EVENT_RESCUE, asset, to), amount);
+        Self::exit_non_reentrant(&env);
+        Ok(())
     }
 
     /// Pause the contract (admin only). Idempotent.
@@ -309,20 +1139,23 @@ impl RevoraRevenueShare {
             .unwrap_or(false)
     }
 
-    /// Helper: panic if contract is paused. Used by state-mutating entrypoints.
-    fn require_not_paused(env: &Env) {
+    /// Helper: return error if contract is paused. Used by state-mutating entrypoints.
+    fn require_not_paused(env: &Env) -> Result<(), RevoraError> {
         if env
             .storage()
             .persistent()
             .get::<DataKey, bool>(&DataKey::Paused)
             .unwrap_or(false)
         {
-            panic!("contract is paused");
+            return Err(RevoraError::ContractPaused);
         }
+        Ok(())
     }
 
     /// Register a new revenue-share offering.
-    /// Returns `Err(RevoraError::InvalidRevenueShareBps)` if revenue_share_bps > 10000.
+    /// Returns `Err(RevoraError::InvalidRevenueShareBps)` if revenue_share_bps > 10000 and
+    /// `Err(RevoraError::DuplicateOffering)` if `issuer` already registered `token`.
+    /// Other issuers may register the same token; the first one stays its current issuer.
     /// In testnet mode, bps validation is skipped to allow flexible testing.
     pub fn register_offering(
         env: Env,
@@ -330,91 +1163,332 @@ impl RevoraRevenueShare {
         token: Address,
         revenue_share_bps: u32,
         payout_asset: Address,
+    ) -> Result<(), RevoraError> {
+        Self::do_register_offering(
+            env,
+            issuer,
+            token,
+            revenue_share_bps,
+            payout_asset,
+            None,
+            DEFAULT_DECIMALS,
+            0,
+        )
+    }
+
+    /// Register a new offering whose amounts use `decimals` places of precision
+    /// (`register_offering` assumes 7). Revenue events carry the value so consumers can
+    /// render amounts. Same validation as `register_offering`, plus
+    /// `Err(RevoraError::InvalidDecimals)` if `decimals` > 18.
+    pub fn register_offering_with_decimals(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        revenue_share_bps: u32,
+        payout_asset: Address,
+        decimals: u32,
+    ) -> Result<(), RevoraError> {
+        Self::do_register_offering(
+            env,
+            issuer,
+            token,
+            revenue_share_bps,
+            payout_asset,
+            None,
+            decimals,
+            0,
+        )
+    }
+
+    /// Register a new offering whose holdings snapshots may not sum to more than
+    /// `max_supply` (0 = uncapped, as for `register_offering`); `record_holdings` enforces
+    /// it. Same validation as `register_offering`; a negative `max_supply` panics with
+    /// `RevoraErrorExt::InvalidSupplyCap`.
+    pub fn register_offering_with_cap(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        revenue_share_bps: u32,
+        payout_asset: Address,
+        max_supply: i128,
+    ) -> Result<(), RevoraError> {
+        Self::do_register_offering(
+            env,
+            issuer,
+            token,
+            revenue_share_bps,
+            payout_asset,
+            None,
+            DEFAULT_DECIMALS,
+            max_supply,
+        )
+    }
+
+    /// Supply cap of the offering for `token` (0 if uncapped or not registered).
+    pub fn get_offering_supply_cap(env: Env, token: Address) -> i128 {
+        Self::get_current_issuer(&env, &token)
+            .and_then(|issuer| Self::find_offering(env, issuer, token))
+            .map(|offering| offering.max_supply)
+            .unwrap_or(0)
+    }
+
+    /// Register a new offering under an issuer-chosen `offering_id`.
+    /// Same validation as `register_offering`; the `offer_reg` event additionally carries
+    /// `offering_id` as a third topic. Re-using an id re-points it to the new token.
+    pub fn register_offering_with_id(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        revenue_share_bps: u32,
+        payout_asset: Address,
+        offering_id: String,
+    ) -> Result<(), RevoraError> {
+        Self::do_register_offering(
+            env,
+            issuer,
+            token,
+            revenue_share_bps,
+            payout_asset,
+            Some(offering_id),
+            DEFAULT_DECIMALS,
+            0,
+        )
+    }
+
+    /// `register_offering_with_id` and `set_offering_metadata` in one call, so an offering
+    /// is never visible without its metadata. The URI is validated before anything is
+    /// written and bps is checked before registration, so a failure leaves neither behind.
+    /// Emits `offer_reg` then `meta_set`. Errors are those of the two calls combined.
+    pub fn register_offering_with_metadata(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        revenue_share_bps: u32,
+        payout_asset: Address,
+        offering_id: String,
+        metadata_uri: String,
+    ) -> Result<(), RevoraError> {
+        Self::validate_metadata(&env, &metadata_uri)?;
+        Self::do_register_offering(
+            env.clone(),
+            issuer.clone(),
+            token.clone(),
+            revenue_share_bps,
+            payout_asset,
+            Some(offering_id),
+            DEFAULT_DECIMALS,
+            0,
+        )?;
+        Self::write_metadata(&env, issuer, token, metadata_uri, None, None);
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn do_register_offering(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        revenue_share_bps: u32,
+        payout_asset: Address,
+        offering_id: Option<String>,
+        decimals: u32,
+        max_supply: i128,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
-        Self::require_not_paused(&env);
+        Self::require_not_paused(&env)?;
+        Self::require_not_deprecated(&env);
         issuer.require_auth();
 
+        if decimals > MAX_DECIMALS {
+            return Err(RevoraError::InvalidDecimals);
+        }
+        if max_supply < 0 {
+            panic_with_error!(&env, RevoraErrorExt::InvalidSupplyCap);
+        }
+
         // Skip bps validation in testnet mode
         let testnet_mode = Self::is_testnet_mode(env.clone());
         if !testnet_mode && revenue_share_bps > 10_000 {
             return Err(RevoraError::InvalidRevenueShareBps);
         }
 
+        if Self::get_token_issuers(&env, &token).contains(&issuer) {
+            return Err(RevoraError::DuplicateOffering);
+        }
+
         let count_key = DataKey::OfferCount(issuer.clone());
         let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
 
-        let offering = Offering {
-            issuer: issuer.clone(),
-            token: token.clone(),
+        Self::store_offering(
+            &env,
+            &issuer,
+            count,
+            token,
             revenue_share_bps,
-            payout_asset: payout_asset.clone(),
-        };
+            payout_asset,
+            offering_id,
+            decimals,
+            max_supply,
+        );
+        Self::set_offering_count(&env, &issuer, count + 1);
+        Self::adjust_offering_total(&env, 1, 0);
+        Ok(())
+    }
+
+    /// Register several offerings for `issuer` in one call, appended in order.
+    /// Every bps is validated before anything is written, so the batch is atomic.
+    /// Emits one `offer_reg` per entry.
+    /// Returns `Err(RevoraError::BatchTooLarge)` for more than MAX_PAGE_LIMIT entries,
+    /// `Err(RevoraError::InvalidRevenueShareBps)` if any entry's bps > 10000
+    /// (skipped in testnet mode) and `Err(RevoraError::DuplicateOffering)` if `issuer`
+    /// already registered a token or it appears twice in the batch.
+    pub fn register_offerings(
+        env: Env,
+        issuer: Address,
+        entries: Vec<(Address, u32, Address)>,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        Self::require_not_deprecated(&env);
+        issuer.require_auth();
+
+        if entries.len() > MAX_PAGE_LIMIT {
+            return Err(RevoraError::BatchTooLarge);
+        }
+        let testnet_mode = Self::is_testnet_mode(env.clone());
+        if !testnet_mode
+            && entries
+                .iter()
+                .any(|(_token, revenue_share_bps, _payout_asset)| revenue_share_bps > 10_000)
+        {
+            return Err(RevoraError::InvalidRevenueShareBps);
+        }
+
+        for (i, (token, _revenue_share_bps, _payout_asset)) in entries.iter().enumerate() {
+            let repeated = entries
+                .iter()
+                .take(i)
+                .any(|(earlier, _bps, _asset)| earlier == token);
+            if repeated || Self::get_token_issuers(&env, &token).contains(&issuer) {
+                return Err(RevoraError::DuplicateOffering);
+            }
+        }
+
+        let count_key = DataKey::OfferCount(issuer.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        for (i, (token, revenue_share_bps, payout_asset)) in entries.iter().enumerate() {
+            Self::store_offering(
+                &env,
+                &issuer,
+                count + i as u32,
+                token,
+                revenue_share_bps,
+                payout_asset,
+                None,
+                DEFAULT_DECIMALS,
+                0,
+            );
+        }
+        Self::set_offering_count(&env, &issuer, count + entries.len());
+        Self::adjust_offering_total(&env, entries.len(), 0);
+        Ok(())
+    }
+
+    /// Write a new active offering at `index` of the issuer's list, maintain lookups and
+    /// emit the registration event(s). The caller updates the offering count.
+    #[allow(clippy::too_many_arguments)]
+    fn store_offering(
+        env: &Env,
+        issuer: &Address,
+        index: u32,
+        token: Address,
+        revenue_share_bps: u32,
+        payout_asset: Address,
+        offering_id: Option<String>,
+        decimals: u32,
+        max_supply: i128,
+    ) {
+        let offering = Offering {
+            issuer: issuer.clone(),
+            token: token.clone(),
+            revenue_share_bps,
+            payout_asset: payout_asset.clone(),
+            status: OfferingStatus::Active,
+            decimals,
+            max_supply,
+        };
 
-        let item_key = DataKey::OfferItem(issuer.clone(), count);
+        let item_key = DataKey::OfferItem(issuer.clone(), index);
         env.storage().persistent().set(&item_key, &offering);
-        env.storage().persistent().set(&count_key, &(count + 1));
 
-        // Maintain reverse lookup: token -> issuer
-        let issuer_lookup_key = DataKey::OfferingIssuer(token.clone());
-        env.storage().persistent().set(&issuer_lookup_key, &issuer);
+        // Maintain reverse lookup: token -> issuers
+        let mut issuers = Self::get_token_issuers(env, &token);
+        issuers.push_back(issuer.clone());
+        Self::set_token_issuers(env, &token, &issuers);
+        if issuers.len() == 1 {
+            let history = OfferingHistory {
+                created_at: env.ledger().timestamp(),
+                updated_at: None,
+                closed_at: None,
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::OfferingHistory(token.clone()), &history);
+        }
 
-        env.events().publish(
-            (symbol_short!("offer_reg"), issuer.clone()),
-            (token.clone(), revenue_share_bps, payout_asset.clone()),
-        );
+        match offering_id {
+            Some(id) => {
+                let id_key = DataKey::OfferingById(issuer.clone(), id.clone());
+                env.storage().persistent().set(&id_key, &token);
+                env.events().publish(
+                    (symbol_short!("offer_reg"), issuer.clone(), id),
+                    (token.clone(), revenue_share_bps, payout_asset.clone()),
+                );
+            }
+            None => {
+                env.events().publish(
+                    (symbol_short!("offer_reg"), issuer.clone()),
+                    (token.clone(), revenue_share_bps, payout_asset.clone()),
+                );
+            }
+        }
+        Self::count_event(env, symbol_short!("offer_reg"));
         // Optionally emit a versioned v1 event with explicit version field
         if Self::is_event_versioning_enabled(env.clone()) {
             env.events().publish(
                 (EVENT_OFFER_REG_V1, issuer.clone()),
-                (
-                    EVENT_SCHEMA_VERSION,
-                    token.clone(),
-                    revenue_share_bps,
-                    payout_asset.clone(),
-                ),
+                (EVENT_SCHEMA_VERSION, token, revenue_share_bps, payout_asset),
             );
         }
-        Ok(())
     }
 
-    /// Fetch a single offering by issuer and token (scans issuer's offerings).
-    pub fn get_offering(env: Env, issuer: Address, token: Address) -> Option<Offering> {
+    /// Index of `token` in `issuer`'s offering list, if registered (scans issuer's offerings).
+    fn find_offering_index(env: &Env, issuer: &Address, token: &Address) -> Option<u32> {
         let count = Self::get_offering_count(env.clone(), issuer.clone());
         for i in 0..count {
             let item_key = DataKey::OfferItem(issuer.clone(), i);
             let offering: Offering = env.storage().persistent().get(&item_key).unwrap();
-            if offering.token == token {
-                return Some(offering);
+            if offering.token == *token {
+                return Some(i);
             }
         }
         None
     }
 
-    /// List all offering tokens for an issuer.
-    pub fn list_offerings(env: Env, issuer: Address) -> Vec<Address> {
-        let (page, _) = Self::get_offerings_page(env.clone(), issuer.clone(), 0, MAX_PAGE_LIMIT);
-        let mut tokens = Vec::new(&env);
-        for i in 0..page.len() {
-            tokens.push_back(page.get(i).unwrap().token);
-        }
-        tokens
-    }
-
-    /// Record a revenue report for an offering. Updates audit summary (#34).
-    /// Fails with `ConcentrationLimitExceeded` (#26) if concentration enforcement is on and current concentration exceeds limit.
-    /// In testnet mode, concentration enforcement is skipped.
-    /// `override_existing`: if true, allows overwriting a previously reported period.
-    pub fn report_revenue(
+    /// Update the revenue share of an existing offering in place.
+    /// The offering keeps its index, so pagination cursors remain valid.
+    /// Returns `Err(RevoraError::InvalidRevenueShareBps)` if new_revenue_share_bps > 10000
+    /// (skipped in testnet mode), `Err(RevoraError::OfferingNotFound)` if the offering
+    /// does not exist or `issuer` is not its current issuer, and
+    /// `Err(RevoraError::OfferingLocked)` after `lock_offering_config`.
+    pub fn update_offering(
         env: Env,
         issuer: Address,
         token: Address,
-        payout_asset: Address,
-        amount: i128,
-        period_id: u64,
-        override_existing: bool,
+        new_revenue_share_bps: u32,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
         // Verify offering exists and issuer is current
         let current_issuer =
@@ -424,219 +1498,2675 @@ impl RevoraRevenueShare {
             return Err(RevoraError::OfferingNotFound);
         }
 
-        Self::require_not_paused(&env);
-        issuer.require_auth();
+        Self::require_config_unlocked(&env, &token)?;
 
-        let offering = Self::get_offering(env.clone(), issuer.clone(), token.clone())
-            .ok_or(RevoraError::OfferingNotFound)?;
-        if offering.payout_asset != payout_asset {
-            return Err(RevoraError::PayoutAssetMismatch);
-        }
+        issuer.require_auth();
 
-        // Skip concentration enforcement in testnet mode
         let testnet_mode = Self::is_testnet_mode(env.clone());
-        if !testnet_mode {
-            // Holder concentration guardrail (#26): reject if enforce and over limit
-            let limit_key = DataKey::ConcentrationLimit(issuer.clone(), token.clone());
-            if let Some(config) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, ConcentrationLimitConfig>(&limit_key)
-            {
-                if config.enforce && config.max_bps > 0 {
-                    let curr_key = DataKey::CurrentConcentration(issuer.clone(), token.clone());
-                    let current: u32 = env.storage().persistent().get(&curr_key).unwrap_or(0);
-                    if current > config.max_bps {
-                        return Err(RevoraError::ConcentrationLimitExceeded);
-                    }
-                }
-            }
-        }
-
-        let blacklist = Self::get_blacklist(env.clone(), token.clone());
-
-        let key = DataKey::RevenueReports(issuer.clone(), token.clone());
-        let mut reports: Map<u64, (i128, u64)> = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| Map::new(&env));
-        let current_timestamp = env.ledger().timestamp();
-
-        match reports.get(period_id) {
-            Some((existing_amount, _timestamp)) => {
-                if override_existing {
-                    reports.set(period_id, (amount, current_timestamp));
-                    env.storage().persistent().set(&key, &reports);
-
-                    env.events().publish(
-                        (EVENT_REVENUE_REPORT_OVERRIDE, issuer.clone(), token.clone()),
-                        (amount, period_id, existing_amount, blacklist.clone()),
-                    );
-
-                    env.events().publish(
-                        (
-                            EVENT_REVENUE_REPORT_OVERRIDE_ASSET,
-                            issuer.clone(),
-                            token.clone(),
-                            payout_asset.clone(),
-                        ),
-                        (amount, period_id, existing_amount, blacklist.clone()),
-                    );
-                } else {
-                    env.events().publish(
-                        (EVENT_REVENUE_REPORT_REJECTED, issuer.clone(), token.clone()),
-                        (amount, period_id, existing_amount, blacklist.clone()),
-                    );
-
-                    env.events().publish(
-                        (
-                            EVENT_REVENUE_REPORT_REJECTED_ASSET,
-                            issuer.clone(),
-                            token.clone(),
-                            payout_asset.clone(),
-                        ),
-                        (amount, period_id, existing_amount, blacklist.clone()),
-                    );
-                }
-            }
-            None => {
-                reports.set(period_id, (amount, current_timestamp));
-                env.storage().persistent().set(&key, &reports);
-
-                env.events().publish(
-                    (EVENT_REVENUE_REPORT_INITIAL, issuer.clone(), token.clone()),
-                    (amount, period_id, blacklist.clone()),
-                );
-
-                env.events().publish(
-                    (
-                        EVENT_REVENUE_REPORT_INITIAL_ASSET,
-                        issuer.clone(),
-                        token.clone(),
-                        payout_asset.clone(),
-                    ),
-                    (amount, period_id, blacklist.clone()),
-                );
-            }
+        if !testnet_mode && new_revenue_share_bps > 10_000 {
+            return Err(RevoraError::InvalidRevenueShareBps);
         }
 
-        // Backward-compatible event (preserve `blacklist` for additional publishes)
-        env.events().publish(
-            (EVENT_REVENUE_REPORTED, issuer.clone(), token.clone()),
-            (amount, period_id, blacklist.clone()),
-        );
+        let index = Self::find_offering_index(&env, &issuer, &token)
+            .ok_or(RevoraError::OfferingNotFound)?;
+        let item_key = DataKey::OfferItem(issuer.clone(), index);
+        let mut offering: Offering = env.storage().persistent().get(&item_key).unwrap();
+        let old_bps = offering.revenue_share_bps;
+        offering.revenue_share_bps = new_revenue_share_bps;
+        env.storage().persistent().set(&item_key, &offering);
+        Self::record_history(&env, &token, |h, now| h.updated_at = Some(now));
 
         env.events().publish(
-            (
-                EVENT_REVENUE_REPORTED_ASSET,
-                issuer.clone(),
-                token.clone(),
-                payout_asset.clone(),
-            ),
-            (amount, period_id),
+            (EVENT_OFFER_UPDATED, issuer),
+            (token, old_bps, new_revenue_share_bps),
         );
+        Ok(())
+    }
 
-        // Optionally emit versioned v1 events for forward-compatible consumers
-        if Self::is_event_versioning_enabled(env.clone()) {
-            env.events().publish(
-                (EVENT_REV_INIT_V1, issuer.clone(), token.clone()),
-                (EVENT_SCHEMA_VERSION, amount, period_id, blacklist.clone()),
-            );
+    /// Correct a mistyped token address: the offering keeps its index and terms, and its
+    /// configuration moves from `old_token` to `new_token` (metadata with version and
+    /// hash, reporters, period guard, concentration limit, rounding mode, minimum report
+    /// amount, report cooldown and its window, pause flag and history). Revenue reports,
+    /// deposits, blacklist and whitelist
+    /// recorded under `old_token` stay there (see `blacklist_transfer`); a pending issuer
+    /// transfer is discarded, and an id from `register_offering_with_id` keeps pointing at
+    /// `old_token`. Emits `tok_fixed` with `(old_token, new_token)`.
+    ///
+    /// # Errors
+    /// - `OfferingNotFound`: `old_token` has no offering of `issuer`
+    /// - `DuplicateOffering`: `new_token` already has an offering
+    pub fn set_offering_token(
+        env: Env,
+        issuer: Address,
+        old_token: Address,
+        new_token: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
-            env.events().publish(
-                (
-                    EVENT_REV_INIA_V1,
-                    issuer.clone(),
-                    token.clone(),
-                    payout_asset.clone(),
-                ),
-                (EVENT_SCHEMA_VERSION, amount, period_id, blacklist.clone()),
-            );
+        let current_issuer =
+            Self::get_current_issuer(&env, &old_token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        Self::require_config_unlocked(&env, &old_token)?;
+        issuer.require_auth();
+        if Self::get_current_issuer(&env, &new_token).is_some() {
+            return Err(RevoraError::DuplicateOffering);
+        }
 
-            env.events().publish(
-                (EVENT_REV_REP_V1, issuer.clone(), token.clone()),
-                (EVENT_SCHEMA_VERSION, amount, period_id, blacklist.clone()),
-            );
+        let index = Self::find_offering_index(&env, &issuer, &old_token)
+            .ok_or(RevoraError::OfferingNotFound)?;
+        let item_key = DataKey::OfferItem(issuer.clone(), index);
+        let mut offering: Offering = env.storage().persistent().get(&item_key).unwrap();
+        offering.token = new_token.clone();
+        env.storage().persistent().set(&item_key, &offering);
 
-            env.events().publish(
-                (
-                    EVENT_REV_REPA_V1,
-                    issuer.clone(),
-                    token.clone(),
-                    payout_asset.clone(),
-                ),
-                (EVENT_SCHEMA_VERSION, amount, period_id),
+        let store = env.storage().persistent();
+        Self::unindex_token_issuer(&env, &old_token, &issuer);
+        Self::set_token_issuers(&env, &new_token, &Vec::from_array(&env, [issuer.clone()]));
+        store.remove(&DataKey::PendingIssuerTransfer(old_token.clone()));
+
+        let per_offering: [fn(Address, Address) -> DataKey; 8] = [
+            DataKey::OfferingMetadata,
+            DataKey::MetadataVersion,
+            DataKey::MetadataHash,
+            DataKey::Reporter,
+            DataKey::ReporterContract,
+            DataKey::PeriodGuard,
+            DataKey::ConcentrationLimit,
+            DataKey::RoundingMode,
+        ];
+        for key in per_offering {
+            Self::move_entry(
+                &env,
+                key(issuer.clone(), old_token.clone()),
+                key(issuer.clone(), new_token.clone()),
             );
         }
+        Self::move_entry(
+            &env,
+            DataKeyExt::MetadataContentType(old_token.clone()),
+            DataKeyExt::MetadataContentType(new_token.clone()),
+        );
+        Self::move_entry(
+            &env,
+            DataKeyExt::MinReportAmount(issuer.clone(), old_token.clone()),
+            DataKeyExt::MinReportAmount(issuer.clone(), new_token.clone()),
+        );
+        Self::move_entry(
+            &env,
+            DataKeyExt::OfferingHistory(old_token.clone()),
+            DataKeyExt::OfferingHistory(new_token.clone()),
+        );
+        Self::move_entry(
+            &env,
+            DataKeyExt::ReportCooldown(old_token.clone()),
+            DataKeyExt::ReportCooldown(new_token.clone()),
+        );
+        Self::move_entry(
+            &env,
+            DataKeyExt::LastReportAt(old_token.clone()),
+            DataKeyExt::LastReportAt(new_token.clone()),
+        );
 
-        // Audit log summary (#34): maintain per-offering total revenue and report count
-        let summary_key = DataKey::AuditSummary(issuer.clone(), token.clone());
-        let mut summary: AuditSummary =
-            env.storage()
-                .persistent()
-                .get(&summary_key)
-                .unwrap_or(AuditSummary {
-                    total_revenue: 0,
-                    report_count: 0,
-                });
-        summary.total_revenue = summary.total_revenue.saturating_add(amount);
-        summary.report_count = summary.report_count.saturating_add(1);
-        env.storage().persistent().set(&summary_key, &summary);
-
+        env.events()
+            .publish((EVENT_TOKEN_FIXED, issuer), (old_token, new_token));
         Ok(())
     }
 
-    /// Return the total number of offerings registered by `issuer`.
-    pub fn get_offering_count(env: Env, issuer: Address) -> u32 {
-        let count_key = DataKey::OfferCount(issuer);
-        env.storage().persistent().get(&count_key).unwrap_or(0)
+    /// Move a persistent entry (of any value type) from `from` to `to`, if present.
+    fn move_entry<K: IntoVal<Env, Val>>(env: &Env, from: K, to: K) {
+        let store = env.storage().persistent();
+        if let Some(value) = store.get::<K, Val>(&from) {
+            store.remove(&from);
+            store.set(&to, &value);
+        }
     }
 
-    /// Return a page of offerings for `issuer`. Limit capped at MAX_PAGE_LIMIT (20).
-    pub fn get_offerings_page(
-        env: Env,
-        issuer: Address,
-        start: u32,
-        limit: u32,
-    ) -> (Vec<Offering>, Option<u32>) {
-        let count = Self::get_offering_count(env.clone(), issuer.clone());
+    /// Close an offering so no further revenue can be reported against it.
+    /// The offering stays in the issuer's list (with status `Closed`) for historical queries.
+    /// Returns `Err(RevoraError::OfferingClosed)` if it is already closed.
+    pub fn close_offering(env: Env, issuer: Address, token: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
-        let effective_limit = if limit == 0 || limit > MAX_PAGE_LIMIT {
-            MAX_PAGE_LIMIT
-        } else {
-            limit
-        };
+        // Verify offering exists and issuer is current
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
 
-        if start >= count {
-            return (Vec::new(&env), None);
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
         }
 
-        let end = core::cmp::min(start + effective_limit, count);
-        let mut results = Vec::new(&env);
+        issuer.require_auth();
 
-        for i in start..end {
-            let item_key = DataKey::OfferItem(issuer.clone(), i);
-            let offering: Offering = env.storage().persistent().get(&item_key).unwrap();
-            results.push_back(offering);
+        if Self::get_offering_status(env.clone(), token.clone()) == Some(OfferingStatus::Closed) {
+            return Err(RevoraError::OfferingClosed);
         }
+        Self::apply_offering_status(&env, &issuer, &issuer, &token, OfferingStatus::Closed)
+    }
 
-        let next_cursor = if end < count { Some(end) } else { None };
-        (results, next_cursor)
+    /// Stamp the current ledger time into `token`'s history, if it has one.
+    fn record_history(env: &Env, token: &Address, stamp: impl FnOnce(&mut OfferingHistory, u64)) {
+        let key = DataKeyExt::OfferingHistory(token.clone());
+        if let Some(mut history) = env
+            .storage()
+            .persistent()
+            .get::<DataKeyExt, OfferingHistory>(&key)
+        {
+            stamp(&mut history, env.ledger().timestamp());
+            env.storage().persistent().set(&key, &history);
+        }
     }
 
-    /// Add `investor` to the per-offering blacklist for `token`. Idempotent.
-    pub fn blacklist_add(
+    /// Lifecycle timestamps (registered, last updated, closed) of an offering.
+    /// Returns `None` if `issuer` is not the offering's current issuer.
+    pub fn get_offering_history(
         env: Env,
-        caller: Address,
+        issuer: Address,
         token: Address,
-        investor: Address,
-    ) -> Result<(), RevoraError> {
-        Self::require_not_frozen(&env)?;
-        Self::require_not_paused(&env);
-        caller.require_auth();
+    ) -> Option<OfferingHistory> {
+        if Self::get_current_issuer(&env, &token)? != issuer {
+            return None;
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::OfferingHistory(token))
+    }
 
+    /// Irreversibly lock the offering's parameters so investors can rely on them: afterwards
+    /// `update_offering`, `set_offering_token`, `set_min_report_amount`, `set_report_cooldown`,
+    /// `set_concentration_limit`, `set_period_guard`, `set_rounding_mode` and `set_claim_delay`
+    /// fail with `OfferingLocked`. Revenue reporting, metadata, pausing and closing stay
+    /// available. Locking again is a no-op. Emits `cfg_lock`.
+    /// Fails with `OfferingNotFound` if `issuer` is not the current issuer.
+    pub fn lock_offering_config(
+        env: Env,
+        issuer: Address,
+        token: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        issuer.require_auth();
+
+        if Self::is_offering_config_locked(env.clone(), token.clone()) {
+            return Ok(());
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::ConfigLocked(token.clone()), &true);
+        env.events().publish((EVENT_CONFIG_LOCKED, issuer), token);
+        Ok(())
+    }
+
+    /// Returns `true` once `token`'s offering parameters are locked (`lock_offering_config`).
+    pub fn is_offering_config_locked(env: Env, token: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::ConfigLocked(token))
+            .unwrap_or(false)
+    }
+
+    fn require_config_unlocked(env: &Env, token: &Address) -> Result<(), RevoraError> {
+        if Self::is_offering_config_locked(env.clone(), token.clone()) {
+            return Err(RevoraError::OfferingLocked);
+        }
+        Ok(())
+    }
+
+    /// Pause revenue reporting for a single offering; the issuer's other offerings are
+    /// unaffected. Unlike `close_offering` this is reversible with `unpause_offering`.
+    /// Caller must be the offering's current issuer or the admin, otherwise
+    /// `Unauthorized`. Fails with `OfferingNotFound` for unknown tokens and panics with
+    /// `RevoraErrorExt::InvalidStatusTransition` if the offering is closed. Emits `off_pause`.
+    pub fn pause_offering(env: Env, caller: Address, token: Address) -> Result<(), RevoraError> {
+        Self::set_offering_status(env, caller, token, OfferingStatus::Paused)
+    }
+
+    /// Lift a `pause_offering`. Same authorization and errors as `pause_offering`.
+    /// Emits `off_unpse`.
+    pub fn unpause_offering(env: Env, caller: Address, token: Address) -> Result<(), RevoraError> {
+        Self::set_offering_status(env, caller, token, OfferingStatus::Active)
+    }
+
+    /// Move `token`'s offering to `status`. `Active` and `Paused` switch freely (setting
+    /// the current status again is allowed and re-emits its event); either can move to
+    /// `Closed`, which is final. Pausing and unpausing may be done by the offering's current
+    /// issuer or the admin, closing only by the issuer; anyone else gets `Unauthorized`.
+    /// Fails with `OfferingNotFound` for unknown tokens and panics with
+    /// `RevoraErrorExt::InvalidStatusTransition` for any change out of `Closed`.
+    /// Emits `off_pause`, `off_unpse` or `offer_cls` like the dedicated calls.
+    pub fn set_offering_status(
+        env: Env,
+        caller: Address,
+        token: Address,
+        status: OfferingStatus,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let issuer = Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        let may_act = caller == issuer
+            || (status != OfferingStatus::Closed && admin.as_ref() == Some(&caller));
+        if !may_act {
+            return Err(RevoraError::Unauthorized);
+        }
+        caller.require_auth();
+
+        if Self::get_offering_status(env.clone(), token.clone()) == Some(OfferingStatus::Closed) {
+            panic_with_error!(&env, RevoraErrorExt::InvalidStatusTransition);
+        }
+        Self::apply_offering_status(&env, &caller, &issuer, &token, status)
+    }
+
+    /// Store an already validated `status` on the offering and emit its event.
+    fn apply_offering_status(
+        env: &Env,
+        caller: &Address,
+        issuer: &Address,
+        token: &Address,
+        status: OfferingStatus,
+    ) -> Result<(), RevoraError> {
+        let index =
+            Self::find_offering_index(env, issuer, token).ok_or(RevoraError::OfferingNotFound)?;
+        let item_key = DataKey::OfferItem(issuer.clone(), index);
+        let mut offering: Offering = env.storage().persistent().get(&item_key).unwrap();
+        offering.status = status;
+        env.storage().persistent().set(&item_key, &offering);
+
+        match status {
+            OfferingStatus::Closed => {
+                Self::record_history(env, token, |h, now| h.closed_at = Some(now));
+                env.events()
+                    .publish((EVENT_OFFER_CLOSED, issuer.clone()), token.clone());
+            }
+            OfferingStatus::Paused => env.events().publish(
+                (EVENT_OFFERING_PAUSED, issuer.clone(), token.clone()),
+                caller.clone(),
+            ),
+            OfferingStatus::Active => env.events().publish(
+                (EVENT_OFFERING_UNPAUSED, issuer.clone(), token.clone()),
+                caller.clone(),
+            ),
+        }
+        Ok(())
+    }
+
+    /// Status of `token`'s offering, or `None` if it is not registered.
+    pub fn get_offering_status(env: Env, token: Address) -> Option<OfferingStatus> {
+        let issuer = Self::get_current_issuer(&env, &token)?;
+        Self::find_offering(env, issuer, token).map(|offering| offering.status)
+    }
+
+    /// Returns `true` if `token`'s offering is paused via `pause_offering`.
+    pub fn is_offering_paused(env: Env, token: Address) -> bool {
+        Self::get_offering_status(env, token) == Some(OfferingStatus::Paused)
+    }
+
+    /// Fail with `OfferingClosed` or `OfferingPaused` unless the offering accepts reports.
+    fn require_offering_active(offering: &Offering) -> Result<(), RevoraError> {
+        match offering.status {
+            OfferingStatus::Active => Ok(()),
+            OfferingStatus::Paused => Err(RevoraError::OfferingPaused),
+            OfferingStatus::Closed => Err(RevoraError::OfferingClosed),
+        }
+    }
+
+    /// Delete an offering registered by mistake.
+    /// Later offerings shift down one index so pagination stays contiguous, and the
+    /// issuer's offering count drops by one. The offering's metadata, config lock,
+    /// delegated reporters and any pending issuer transfer are deleted with it; revenue
+    /// reports, audit summary and blacklist for `token` are left in place. The per-token
+    /// state (content type, history, config lock, pending transfer) is only deleted when
+    /// `issuer` is the token's current issuer; the next issuer of the token takes over.
+    /// Returns `Err(RevoraError::OfferingNotFound)` if `issuer` has no offering for `token`.
+    pub fn remove_offering(env: Env, issuer: Address, token: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        if !Self::get_token_issuers(&env, &token).contains(&issuer) {
+            return Err(RevoraError::OfferingNotFound);
+        }
+
+        issuer.require_auth();
+
+        let index = Self::find_offering_index(&env, &issuer, &token)
+            .ok_or(RevoraError::OfferingNotFound)?;
+        let count = Self::get_offering_count(env.clone(), issuer.clone());
+
+        // Compact: shift every later offering down by one
+        for i in index + 1..count {
+            let from_key = DataKey::OfferItem(issuer.clone(), i);
+            let offering: Offering = env.storage().persistent().get(&from_key).unwrap();
+            let to_key = DataKey::OfferItem(issuer.clone(), i - 1);
+            env.storage().persistent().set(&to_key, &offering);
+        }
+        let last_key = DataKey::OfferItem(issuer.clone(), count - 1);
+        env.storage().persistent().remove(&last_key);
+
+        Self::set_offering_count(&env, &issuer, count - 1);
+        Self::adjust_offering_total(&env, 0, 1);
+
+        let was_current = Self::unindex_token_issuer(&env, &token, &issuer);
+        let meta_key = DataKey::OfferingMetadata(issuer.clone(), token.clone());
+        env.storage().persistent().remove(&meta_key);
+        let hash_key = DataKey::MetadataHash(issuer.clone(), token.clone());
+        env.storage().persistent().remove(&hash_key);
+        if was_current {
+            let type_key = DataKeyExt::MetadataContentType(token.clone());
+            env.storage().persistent().remove(&type_key);
+            let history_key = DataKeyExt::OfferingHistory(token.clone());
+            env.storage().persistent().remove(&history_key);
+            // A stale proposal would let its recipient take over a later registration
+            let pending_key = DataKey::PendingIssuerTransfer(token.clone());
+            env.storage().persistent().remove(&pending_key);
+            // The lock belongs to this offering, not to whoever registers the token next
+            let lock_key = DataKeyExt::ConfigLocked(token.clone());
+            env.storage().persistent().remove(&lock_key);
+        }
+        // A re-registration starts without the old delegated reporters
+        let reporter_key = DataKey::Reporter(issuer.clone(), token.clone());
+        env.storage().persistent().remove(&reporter_key);
+        let contract_key = DataKey::ReporterContract(issuer.clone(), token.clone());
+        env.storage().persistent().remove(&contract_key);
+
+        env.events().publish((EVENT_OFFER_REMOVED, issuer), token);
+        Self::count_event(&env, EVENT_OFFER_REMOVED);
+        Ok(())
+    }
+
+    /// Delete every offering of `issuer` in one call, for an issuer winding down: the
+    /// offering list and count plus each offering's metadata, content hash, designated
+    /// reporters, pending issuer transfer and config lock. Revenue reports, audit summaries
+    /// and blacklists are left in place, as in `remove_offering`. Returns the number removed
+    /// (0 if none) and emits `iss_clear` with it. Fails with `ConfirmationRequired` unless
+    /// `confirm` is true.
+    pub fn remove_all_offerings(
+        env: Env,
+        issuer: Address,
+        confirm: bool,
+    ) -> Result<u32, RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        issuer.require_auth();
+        if !confirm {
+            return Err(RevoraError::ConfirmationRequired);
+        }
+
+        let store = env.storage().persistent();
+        let count = Self::get_offering_count(env.clone(), issuer.clone());
+        for i in 0..count {
+            let item_key = DataKey::OfferItem(issuer.clone(), i);
+            let offering: Offering = store.get(&item_key).unwrap();
+            let token = offering.token;
+            store.remove(&item_key);
+            store.remove(&DataKey::OfferingMetadata(issuer.clone(), token.clone()));
+            store.remove(&DataKey::MetadataHash(issuer.clone(), token.clone()));
+            store.remove(&DataKey::Reporter(issuer.clone(), token.clone()));
+            store.remove(&DataKey::ReporterContract(issuer.clone(), token.clone()));
+            if Self::unindex_token_issuer(&env, &token, &issuer) {
+                store.remove(&DataKeyExt::MetadataContentType(token.clone()));
+                store.remove(&DataKeyExt::OfferingHistory(token.clone()));
+                store.remove(&DataKey::PendingIssuerTransfer(token.clone()));
+                store.remove(&DataKeyExt::ConfigLocked(token));
+            }
+        }
+        store.remove(&DataKey::OfferCount(issuer.clone()));
+        Self::sync_issuer_index(&env, &issuer, 0);
+        Self::adjust_offering_total(&env, 0, count);
+
+        env.events().publish((EVENT_ISSUER_CLEARED, issuer), count);
+        Ok(count)
+    }
+
+    /// Bump the persistent emission counter for `event` (see `get_events_summary`).
+    fn count_event(env: &Env, event: Symbol) {
+        let mut counts: Map<Symbol, u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EventCounts)
+            .unwrap_or_else(|| Map::new(env));
+        let current = counts.get(event.clone()).unwrap_or(0);
+        counts.set(event, current.saturating_add(1));
+        env.storage()
+            .persistent()
+            .set(&DataKey::EventCounts, &counts);
+    }
+
+    /// How many times each offering, revenue and blacklist event has been emitted, keyed
+    /// by event symbol, so indexers can bootstrap without replaying the log. Tracked:
+    /// `offer_reg` (one per offering, batches included), `offer_rm`, `rev_rep`, `bl_add`,
+    /// `bl_add_u`, `bl_batch`, `bl_rem`, `bl_frc_rm` and `bl_clear`. Events never
+    /// emitted are absent.
+    pub fn get_events_summary(env: Env) -> Map<Symbol, u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventCounts)
+            .unwrap_or_else(|| Map::new(&env))
+    }
+
+    /// Apply a net change to the global offering counter.
+    fn adjust_offering_total(env: &Env, added: u32, removed: u32) {
+        let key = DataKey::OfferingTotal;
+        let total: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        let total = total.saturating_add(added).saturating_sub(removed);
+        env.storage().persistent().set(&key, &total);
+    }
+
+    /// Contract-wide telemetry: offerings across all issuers, blacklist entries across
+    /// all tokens, and whether the contract is paused and initialized.
+    pub fn get_contract_stats(env: Env) -> ContractStats {
+        ContractStats {
+            total_offerings: env
+                .storage()
+                .persistent()
+                .get(&DataKey::OfferingTotal)
+                .unwrap_or(0),
+            total_blacklist_entries: Self::get_blacklist_count_all(env.clone()),
+            paused: Self::is_paused(env.clone()),
+            initialized: env.storage().persistent().has(&DataKey::Admin),
+        }
+    }
+
+    /// Extend the TTL of the issuer's offering count, offering entries and their
+    /// metadata so they are not archived. Callable by anyone; entries that do not
+    /// exist are skipped. See `TTL_THRESHOLD` / `TTL_EXTEND_TO`.
+    pub fn extend_ttl(env: Env, issuer: Address) {
+        let store = env.storage().persistent();
+        let count_key = DataKey::OfferCount(issuer.clone());
+        if !store.has(&count_key) {
+            return;
+        }
+        store.extend_ttl(&count_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+        let count: u32 = store.get(&count_key).unwrap_or(0);
+        for i in 0..count {
+            let item_key = DataKey::OfferItem(issuer.clone(), i);
+            let offering: Offering = store.get(&item_key).unwrap();
+            store.extend_ttl(&item_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+            let meta_key = DataKey::OfferingMetadata(issuer.clone(), offering.token);
+            if store.has(&meta_key) {
+                store.extend_ttl(&meta_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+            }
+        }
+    }
+
+    /// Extend the TTL of the blacklist (and blacklist expiries and reasons) for `token`,
+    /// if present. Callable by anyone.
+    pub fn bump_blacklist_ttl(env: Env, token: Address) {
+        for key in [
+            DataKey::Blacklist(token.clone()),
+            DataKey::BlacklistOrder(token.clone()),
+            DataKey::BlacklistExpiry(token.clone()),
+        ] {
+            if env.storage().persistent().has(&key) {
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+            }
+        }
+        let details_key = DataKeyExt::BlacklistDetails(token);
+        if env.storage().persistent().has(&details_key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&details_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        }
+    }
+
+    /// Fetch a single offering by issuer and token (scans issuer's offerings).
+    /// Returns `Err(RevoraError::OfferingNotFound)` if `issuer` has no offering for `token`.
+    pub fn get_offering(
+        env: Env,
+        issuer: Address,
+        token: Address,
+    ) -> Result<Offering, RevoraError> {
+        Self::find_offering(env, issuer, token).ok_or(RevoraError::OfferingNotFound)
+    }
+
+    /// Rewrite `issuer`'s offerings stored in an older `Offering` layout (`LegacyOffering`,
+    /// `OfferingV1`, `OfferingV2`, `OfferingV3`) in the current one, so they can be read
+    /// after an in-place upgrade. Missing fields get their defaults: `status = Active`,
+    /// `decimals = DEFAULT_DECIMALS`, `max_supply = 0` (uncapped). An old `active = false`
+    /// becomes `Closed`, and an old per-offering pause flag becomes `Paused`.
+    /// Also lists the issuer in `get_issuers_page` if it predates that index.
+    /// Admin only. Returns the number of entries rewritten and emits `migrated` with it;
+    /// running it again is harmless (returns 0).
+    pub fn migrate_storage(env: Env, issuer: Address) -> Result<u32, RevoraError> {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(RevoraError::Unauthorized)?;
+        admin.require_auth();
+
+        let count = Self::get_offering_count(env.clone(), issuer.clone());
+        let mut migrated = 0u32;
+        for i in 0..count {
+            let item_key = DataKey::OfferItem(issuer.clone(), i);
+            // Offerings are stored as field-name maps; tell the layouts apart by their fields
+            let fields: Map<Symbol, Val> = match env.storage().persistent().get(&item_key) {
+                Some(fields) => fields,
+                None => continue,
+            };
+            if fields.contains_key(symbol_short!("status")) {
+                continue;
+            }
+            let raw = fields.to_val();
+            let offering = if fields.contains_key(Symbol::new(&env, "max_supply")) {
+                let Ok(v3) = OfferingV3::try_from_val(&env, &raw) else {
+                    continue;
+                };
+                Offering {
+                    status: Self::take_legacy_status(&env, &v3.token, v3.active),
+                    issuer: v3.issuer,
+                    token: v3.token,
+                    revenue_share_bps: v3.revenue_share_bps,
+                    payout_asset: v3.payout_asset,
+                    decimals: v3.decimals,
+                    max_supply: v3.max_supply,
+                }
+            } else if fields.contains_key(symbol_short!("decimals")) {
+                let Ok(v2) = OfferingV2::try_from_val(&env, &raw) else {
+                    continue;
+                };
+                Offering {
+                    status: Self::take_legacy_status(&env, &v2.token, v2.active),
+                    issuer: v2.issuer,
+                    token: v2.token,
+                    revenue_share_bps: v2.revenue_share_bps,
+                    payout_asset: v2.payout_asset,
+                    decimals: v2.decimals,
+                    max_supply: 0,
+                }
+            } else if fields.contains_key(symbol_short!("active")) {
+                let Ok(v1) = OfferingV1::try_from_val(&env, &raw) else {
+                    continue;
+                };
+                Offering {
+                    status: Self::take_legacy_status(&env, &v1.token, v1.active),
+                    issuer: v1.issuer,
+                    token: v1.token,
+                    revenue_share_bps: v1.revenue_share_bps,
+                    payout_asset: v1.payout_asset,
+                    decimals: DEFAULT_DECIMALS,
+                    max_supply: 0,
+                }
+            } else {
+                let Ok(legacy) = LegacyOffering::try_from_val(&env, &raw) else {
+                    continue;
+                };
+                Offering {
+                    status: Self::take_legacy_status(&env, &legacy.token, true),
+                    issuer: legacy.issuer,
+                    token: legacy.token,
+                    revenue_share_bps: legacy.revenue_share_bps,
+                    payout_asset: legacy.payout_asset,
+                    decimals: DEFAULT_DECIMALS,
+                    max_supply: 0,
+                }
+            };
+            env.storage().persistent().set(&item_key, &offering);
+            migrated += 1;
+        }
+        Self::sync_issuer_index(&env, &issuer, count);
+
+        env.events().publish((EVENT_MIGRATED, issuer), migrated);
+        Ok(migrated)
+    }
+
+    /// `OfferingStatus` for an offering stored with the old `active` flag, consuming its old
+    /// separate pause flag.
+    fn take_legacy_status(env: &Env, token: &Address, active: bool) -> OfferingStatus {
+        let pause_key = DataKeyExt::OfferingPaused(token.clone());
+        let paused: bool = env.storage().persistent().get(&pause_key).unwrap_or(false);
+        env.storage().persistent().remove(&pause_key);
+        if !active {
+            OfferingStatus::Closed
+        } else if paused {
+            OfferingStatus::Paused
+        } else {
+            OfferingStatus::Active
+        }
+    }
+
+    /// Option-returning lookup behind `get_offering`, for internal callers.
+    fn find_offering(env: Env, issuer: Address, token: Address) -> Option<Offering> {
+        let count = Self::get_offering_count(env.clone(), issuer.clone());
+        for i in 0..count {
+            let item_key = DataKey::OfferItem(issuer.clone(), i);
+            let offering: Offering = env.storage().persistent().get(&item_key).unwrap();
+            if offering.token == token {
+                return Some(offering);
+            }
+        }
+        None
+    }
+
+    /// Assemble an `OfferingSnapshot` (offering, metadata and version, cumulative revenue,
+    /// active flag, blacklist count) in one call. Fails with `OfferingNotFound`.
+    pub fn snapshot_offering(
+        env: Env,
+        issuer: Address,
+        token: Address,
+    ) -> Result<OfferingSnapshot, RevoraError> {
+        let offering = Self::find_offering(env.clone(), issuer.clone(), token.clone())
+            .ok_or(RevoraError::OfferingNotFound)?;
+        Ok(OfferingSnapshot {
+            active: offering.status != OfferingStatus::Closed,
+            metadata: Self::get_offering_metadata(env.clone(), issuer.clone(), token.clone()),
+            metadata_version: Self::get_metadata_version(
+                env.clone(),
+                issuer.clone(),
+                token.clone(),
+            ),
+            total_revenue: Self::get_total_revenue(env.clone(), issuer, token.clone()),
+            blacklist_count: Self::count_blacklisted(env, token),
+            offering,
+        })
+    }
+
+    /// Decimal precision of the offering's amounts; 7 if the offering is not found.
+    pub fn get_offering_decimals(env: Env, issuer: Address, token: Address) -> u32 {
+        Self::find_offering(env, issuer, token)
+            .map(|offering| offering.decimals)
+            .unwrap_or(DEFAULT_DECIMALS)
+    }
+
+    /// Zero-based position of `token` in the issuer's offering list, usable directly as
+    /// the `start` cursor of `get_offerings_page`. `None` if not registered.
+    pub fn get_offering_index(env: Env, issuer: Address, token: Address) -> Option<u32> {
+        Self::find_offering_index(&env, &issuer, &token)
+    }
+
+    /// Return true if `issuer` has registered an offering for `token`.
+    pub fn offering_exists(env: Env, issuer: Address, token: Address) -> bool {
+        Self::find_offering_index(&env, &issuer, &token).is_some()
+    }
+
+    /// Offerings referencing `token` across issuers, in registration order with the token's
+    /// current issuer first. Returns the first page only (see `get_offerings_for_token_page`).
+    pub fn get_offerings_for_token(env: Env, token: Address) -> Vec<Offering> {
+        let (page, _) = Self::get_offerings_for_token_page(env, token, 0, 0);
+        page
+    }
+
+    /// Return a page of the offerings referencing `token`, one per issuer. Limit capped at
+    /// the page limit (`get_max_page_limit`, 20 by default).
+    pub fn get_offerings_for_token_page(
+        env: Env,
+        token: Address,
+        start: u32,
+        limit: u32,
+    ) -> (Vec<Offering>, Option<u32>) {
+        let issuers = Self::get_token_issuers(&env, &token);
+        let count = issuers.len();
+        let effective_limit = Self::effective_page_limit(&env, limit);
+        if start >= count {
+            return (Vec::new(&env), None);
+        }
+
+        let end = core::cmp::min(start + effective_limit, count);
+        let mut results = Vec::new(&env);
+        for i in start..end {
+            let issuer = issuers.get(i).unwrap();
+            if let Some(offering) = Self::find_offering(env.clone(), issuer, token.clone()) {
+                results.push_back(offering);
+            }
+        }
+
+        let next_cursor = if end < count { Some(end) } else { None };
+        (results, next_cursor)
+    }
+
+    /// Fetch an offering by the `offering_id` given at `register_offering_with_id`.
+    pub fn get_offering_by_id(env: Env, issuer: Address, offering_id: String) -> Option<Offering> {
+        let id_key = DataKey::OfferingById(issuer.clone(), offering_id);
+        let token: Address = env.storage().persistent().get(&id_key)?;
+        Self::find_offering(env, issuer, token)
+    }
+
+    /// List all offering tokens for an issuer.
+    pub fn list_offerings(env: Env, issuer: Address) -> Vec<Address> {
+        let (page, _) = Self::get_offerings_page(env.clone(), issuer.clone(), 0, 0);
+        let mut tokens = Vec::new(&env);
+        for i in 0..page.len() {
+            tokens.push_back(page.get(i).unwrap().token);
+        }
+        tokens
+    }
+
+    /// Record a revenue report for an offering. Updates audit summary (#34).
+    /// `issuer` may be the offering's current issuer, its designated reporter
+    /// (`set_reporter`) or reporter contract (`set_reporter_contract`); either way the
+    /// report is recorded under the current issuer.
+    /// Fails with `OfferingClosed` if the offering has been closed via `close_offering`.
+    /// Fails with `ConcentrationLimitExceeded` (#26) if concentration enforcement is on and current concentration exceeds limit.
+    /// In testnet mode, concentration enforcement is skipped.
+    /// `override_existing`: if true, allows overwriting a previously reported period;
+    /// otherwise a repeated `period_id` fails with `DuplicatePeriod`.
+    /// Panics with `RevoraErrorExt::Overflow` if the offering's cumulative revenue or the
+    /// accrued platform fees would overflow `i128`.
+    pub fn report_revenue(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+        override_existing: bool,
+    ) -> Result<(), RevoraError> {
+        Self::do_report_revenue(
+            env,
+            issuer,
+            token,
+            payout_asset,
+            amount,
+            period_id,
+            override_existing,
+            false,
+        )
+    }
+
+    /// Same as `report_revenue`, but the `rev_rep` event carries `period_id` as a fourth
+    /// topic `(rev_rep, issuer, token, period_id)` so subscribers can filter by period at
+    /// the RPC level. Event data and all other events are unchanged.
+    pub fn report_revenue_indexed(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+        override_existing: bool,
+    ) -> Result<(), RevoraError> {
+        Self::do_report_revenue(
+            env,
+            issuer,
+            token,
+            payout_asset,
+            amount,
+            period_id,
+            override_existing,
+            true,
+        )
+    }
+
+    /// `report_revenue` with a short accounting note (e.g. "Q1 SaaS subscriptions") stored
+    /// for the period and emitted as `rev_memo` with `(period_id, memo)` after the report
+    /// events. A repeated period fails with `DuplicatePeriod` like a non-overriding
+    /// `report_revenue`; the memo only annotates, it never replaces a report.
+    /// Fails with `MemoTooLong` if `memo` exceeds MAX_MEMO_LENGTH (256) bytes.
+    pub fn report_revenue_with_memo(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+        memo: String,
+    ) -> Result<(), RevoraError> {
+        if memo.len() > MAX_MEMO_LENGTH {
+            return Err(RevoraError::MemoTooLong);
+        }
+        Self::report_revenue(
+            env.clone(),
+            issuer,
+            token.clone(),
+            payout_asset,
+            amount,
+            period_id,
+            false,
+        )?;
+
+        // Stored under the current issuer, which may differ from a delegated reporter.
+        let issuer = Self::get_current_issuer(&env, &token).unwrap();
+        let key = DataKey::ReportMemo(issuer.clone(), token.clone(), period_id);
+        env.storage().persistent().set(&key, &memo);
+        env.events()
+            .publish((EVENT_REPORT_MEMO, issuer, token), (period_id, memo));
+        Ok(())
+    }
+
+    /// Memo attached to a period's revenue report, if any.
+    pub fn get_report_memo(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+    ) -> Option<String> {
+        let key = DataKey::ReportMemo(issuer, token, period_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Publish an application-defined event tied to an offering, with topics
+    /// `(tag, issuer, token)` and `data` as payload. Nothing is stored.
+    ///
+    /// # Errors
+    /// - `OfferingNotFound`: `issuer` is not the current issuer of `token`
+    /// - `ReservedEventTag`: `tag` is one of the contract's own event symbols
+    /// - `MemoTooLong`: `data` exceeds MAX_MEMO_LENGTH (256) bytes
+    pub fn emit_custom_event(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        tag: Symbol,
+        data: String,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        issuer.require_auth();
+
+        if RESERVED_EVENT_TAGS.contains(&tag) {
+            return Err(RevoraError::ReservedEventTag);
+        }
+        if data.len() > MAX_MEMO_LENGTH {
+            return Err(RevoraError::MemoTooLong);
+        }
+        env.events().publish((tag, issuer, token), data);
+        Ok(())
+    }
+
+    /// `report_revenue` for revenue earned in a quote currency: `fx = (quote_token, fx_rate)`
+    /// is stored for the period and emitted as `rev_fx` with `(period_id, quote_token,
+    /// fx_rate)` after the report events, so off-chain distribution can convert. `fx_rate`
+    /// is scaled by the payout asset's decimals. A repeated period fails with
+    /// `DuplicatePeriod`, leaving the earlier report and rate in place. Fails with
+    /// `InvalidFxRate` unless `fx_rate > 0`.
+    pub fn report_revenue_fx(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+        fx: (Address, i128),
+    ) -> Result<(), RevoraError> {
+        let (quote_token, fx_rate) = fx;
+        if fx_rate <= 0 {
+            return Err(RevoraError::InvalidFxRate);
+        }
+        Self::report_revenue(
+            env.clone(),
+            issuer,
+            token.clone(),
+            payout_asset,
+            amount,
+            period_id,
+            false,
+        )?;
+
+        // Stored under the current issuer, which may differ from a delegated reporter.
+        let issuer = Self::get_current_issuer(&env, &token).unwrap();
+        let key = DataKeyExt::ReportFx(issuer.clone(), token.clone(), period_id);
+        env.storage()
+            .persistent()
+            .set(&key, &(quote_token.clone(), fx_rate));
+        env.events().publish(
+            (EVENT_REPORT_FX, issuer, token),
+            (period_id, quote_token, fx_rate),
+        );
+        Ok(())
+    }
+
+    /// `report_revenue` with a claim deadline (ledger timestamp) for the period, stored and
+    /// emitted as `rev_dl` with `(period_id, claim_deadline)` after the report events. Once
+    /// it passes, `sweep_expired` returns the period's unclaimed deposit to the issuer.
+    /// A repeated period fails with `DuplicatePeriod`, so a paid-out period's deadline cannot
+    /// be replaced. Fails with `InvalidDeadline` unless `claim_deadline` is after the current
+    /// ledger timestamp and no earlier than a deadline already stored for the period.
+    pub fn report_revenue_with_deadline(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+        claim_deadline: u64,
+    ) -> Result<(), RevoraError> {
+        if claim_deadline <= env.ledger().timestamp() {
+            return Err(RevoraError::InvalidDeadline);
+        }
+        let key = DataKeyExt::ClaimDeadline(token.clone(), period_id);
+        let existing: Option<u64> = env.storage().persistent().get(&key);
+        if existing.is_some_and(|deadline| claim_deadline < deadline) {
+            return Err(RevoraError::InvalidDeadline);
+        }
+        Self::report_revenue(
+            env.clone(),
+            issuer,
+            token.clone(),
+            payout_asset,
+            amount,
+            period_id,
+            false,
+        )?;
+
+        let issuer = Self::get_current_issuer(&env, &token).unwrap();
+        env.storage().persistent().set(&key, &claim_deadline);
+        env.events().publish(
+            (EVENT_CLAIM_DEADLINE, issuer, token),
+            (period_id, claim_deadline),
+        );
+        Ok(())
+    }
+
+    /// Claim deadline set for a period by `report_revenue_with_deadline`, if any.
+    pub fn get_claim_deadline(env: Env, token: Address, period_id: u64) -> Option<u64> {
+        let key = DataKeyExt::ClaimDeadline(token, period_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Quote token and FX rate of a period's `report_revenue_fx` report, if any.
+    pub fn get_report_fx(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+    ) -> Option<(Address, i128)> {
+        let key = DataKeyExt::ReportFx(issuer, token, period_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Report several `(amount, period_id)` entries for one offering in a single call, e.g.
+    /// to catch up on missed periods. Each entry is a non-overriding `report_revenue` in the
+    /// offering's payout asset and emits the usual report events (`rev_rep`, ...). The whole
+    /// batch is validated before anything is written, so it succeeds or fails as a unit.
+    ///
+    /// # Errors
+    /// - `BatchTooLarge`: more than MAX_PAGE_LIMIT (20) entries
+    /// - `OfferingNotFound`: `issuer` is not the current issuer of `token`
+    /// - `DuplicatePeriod`: a period repeats within the batch or is already reported
+    /// - `NonMonotonicPeriod`: the period guard forbids back-dating and the batch is not
+    ///   strictly increasing (or starts at or before the last reported period)
+    /// - any other error `report_revenue` would return for an entry
+    pub fn batch_report_revenue(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        reports: Vec<(i128, u64)>,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_deprecated(&env);
+        if reports.len() > MAX_PAGE_LIMIT {
+            return Err(RevoraError::BatchTooLarge);
+        }
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        issuer.require_auth();
+
+        let offering = Self::find_offering(env.clone(), issuer.clone(), token.clone())
+            .ok_or(RevoraError::OfferingNotFound)?;
+        let strictly_increasing = env
+            .storage()
+            .persistent()
+            .get::<DataKey, PeriodGuardConfig>(&DataKey::PeriodGuard(issuer.clone(), token.clone()))
+            .is_some_and(|guard| guard.enforce && !guard.allow_backdated);
+
+        let mut seen: Vec<u64> = Vec::new(&env);
+        for (amount, period_id) in reports.iter() {
+            if seen.contains(period_id) {
+                return Err(RevoraError::DuplicatePeriod);
+            }
+            if strictly_increasing && seen.last().is_some_and(|prev| period_id <= prev) {
+                return Err(RevoraError::NonMonotonicPeriod);
+            }
+            Self::check_report(&env, &issuer, &token, &offering, amount, period_id)?;
+            seen.push_back(period_id);
+        }
+
+        for (amount, period_id) in reports.iter() {
+            Self::record_report(
+                env.clone(),
+                issuer.clone(),
+                token.clone(),
+                offering.payout_asset.clone(),
+                amount,
+                period_id,
+                false,
+                false,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Preview `report_revenue(issuer, token, ..., amount, period_id, false)` without
+    /// writing or emitting anything. Returns `(distributable, blacklist_count, error_code)`:
+    /// the holders' pot after the platform fee, the number of blacklisted addresses the
+    /// report would exclude, and the `RevoraError` code the report would fail with (0 if it
+    /// would succeed). Authorization is not checked. Unknown offerings return
+    /// `(0, 0, OfferingNotFound)`.
+    pub fn dry_run_report(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        amount: i128,
+        period_id: u64,
+    ) -> (i128, u32, u32) {
+        let offering = match Self::get_current_issuer(&env, &token)
+            .filter(|current| *current == issuer)
+            .and_then(|_| Self::find_offering(env.clone(), issuer.clone(), token.clone()))
+        {
+            Some(offering) => offering,
+            None => return (0, 0, RevoraError::OfferingNotFound as u32),
+        };
+
+        let fee_bps = Self::get_platform_fee_bps(env.clone());
+        let net_amount = amount - Self::bps_share_floor(amount, fee_bps);
+        let distributable = Self::bps_share_floor(net_amount, offering.revenue_share_bps);
+        let blacklist_count = Self::get_blacklist(env.clone(), token.clone()).len();
+
+        let error_code = if Self::is_deprecated(env.clone()) {
+            RevoraErrorExt::ContractDeprecated as u32
+        } else {
+            match Self::check_report(&env, &issuer, &token, &offering, amount, period_id) {
+                Ok(()) => 0,
+                Err(err) => err as u32,
+            }
+        };
+        (distributable, blacklist_count, error_code)
+    }
+
+    /// Every check a non-overriding report of `offering` would run after authorization:
+    /// contract and offering state, `check_report_limits`, the report cooldown and
+    /// duplicate periods.
+    fn check_report(
+        env: &Env,
+        issuer: &Address,
+        token: &Address,
+        offering: &Offering,
+        amount: i128,
+        period_id: u64,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(env)?;
+        Self::require_not_paused(env)?;
+        Self::require_offering_active(offering)?;
+        Self::check_report_limits(env, issuer, token, amount, period_id)?;
+        Self::check_report_cooldown(env, token, amount)?;
+        let reports: Map<u64, (i128, u64)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RevenueReports(issuer.clone(), token.clone()))
+            .unwrap_or_else(|| Map::new(env));
+        if reports.contains_key(period_id) {
+            return Err(RevoraError::DuplicatePeriod);
+        }
+        Ok(())
+    }
+
+    /// Minimum amount, concentration limit and period ordering checks shared by
+    /// `do_report_revenue` and `dry_run_report`.
+    fn check_report_limits(
+        env: &Env,
+        issuer: &Address,
+        token: &Address,
+        amount: i128,
+        period_id: u64,
+    ) -> Result<(), RevoraError> {
+        // Dust guard; negative amounts are clawback corrections and always pass.
+        if amount >= 0
+            && amount < Self::get_min_report_amount(env.clone(), issuer.clone(), token.clone())
+        {
+            return Err(RevoraError::AmountBelowMinimum);
+        }
+
+        // Skip concentration enforcement in testnet mode
+        let testnet_mode = Self::is_testnet_mode(env.clone());
+        if !testnet_mode {
+            // Holder concentration guardrail (#26): reject if enforce and over limit
+            let limit_key = DataKey::ConcentrationLimit(issuer.clone(), token.clone());
+            if let Some(config) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, ConcentrationLimitConfig>(&limit_key)
+            {
+                if config.enforce && config.max_bps > 0 {
+                    let curr_key = DataKey::CurrentConcentration(issuer.clone(), token.clone());
+                    let current: u32 = env.storage().persistent().get(&curr_key).unwrap_or(0);
+                    if current > config.max_bps {
+                        return Err(RevoraError::ConcentrationLimitExceeded);
+                    }
+                }
+            }
+        }
+
+        // Period ordering guard: reject repeats and back-dated periods unless overridden.
+        let last_key = DataKey::LastPeriod(issuer.clone(), token.clone());
+        let last_period: Option<u64> = env.storage().persistent().get(&last_key);
+        let guard_key = DataKey::PeriodGuard(issuer.clone(), token.clone());
+        if let Some(guard) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, PeriodGuardConfig>(&guard_key)
+        {
+            if guard.enforce
+                && !guard.allow_backdated
+                && last_period.is_some_and(|last| period_id <= last)
+            {
+                return Err(RevoraError::NonMonotonicPeriod);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn do_report_revenue(
+        env: Env,
+        caller: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+        override_existing: bool,
+        period_in_topics: bool,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_deprecated(&env);
+
+        // Verify offering exists and caller is its current issuer or designated reporter
+        let issuer = Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+
+        if caller != issuer {
+            let reporter_key = DataKey::Reporter(issuer.clone(), token.clone());
+            let reporter: Option<Address> = env.storage().persistent().get(&reporter_key);
+            let contract_key = DataKey::ReporterContract(issuer.clone(), token.clone());
+            let reporter_contract: Option<Address> = env.storage().persistent().get(&contract_key);
+            if reporter.as_ref() != Some(&caller) && reporter_contract.as_ref() != Some(&caller) {
+                return Err(RevoraError::OfferingNotFound);
+            }
+        }
+
+        Self::require_not_paused(&env)?;
+        caller.require_auth();
+        Self::check_report_cooldown(&env, &token, amount)?;
+
+        Self::record_report(
+            env,
+            issuer,
+            token,
+            payout_asset,
+            amount,
+            period_id,
+            override_existing,
+            period_in_topics,
+        )
+    }
+
+    /// Validate and store one revenue report for `issuer` (already authorized) and emit
+    /// its events. The body of `do_report_revenue`, also used by `batch_report_revenue`.
+    #[allow(clippy::too_many_arguments)]
+    fn record_report(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+        override_existing: bool,
+        period_in_topics: bool,
+    ) -> Result<(), RevoraError> {
+        let offering = Self::find_offering(env.clone(), issuer.clone(), token.clone())
+            .ok_or(RevoraError::OfferingNotFound)?;
+        Self::require_offering_active(&offering)?;
+        if offering.payout_asset != payout_asset {
+            return Err(RevoraError::PayoutAssetMismatch);
+        }
+        Self::check_report_limits(&env, &issuer, &token, amount, period_id)?;
+
+        // Point-in-time exclusion list embedded in the report events, capped in size.
+        let mut blacklist = Self::get_blacklist(env.clone(), token.clone());
+        let truncated = blacklist.len() > MAX_REPORT_BLACKLIST;
+        if truncated {
+            blacklist = blacklist.slice(0..MAX_REPORT_BLACKLIST);
+        }
+
+        let key = DataKey::RevenueReports(issuer.clone(), token.clone());
+        let mut reports: Map<u64, (i128, u64)> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(&env));
+        let current_timestamp = env.ledger().timestamp();
+
+        // Platform fee skimmed from this report; the event carries the net-of-fee amount.
+        let fee_bps = Self::get_platform_fee_bps(env.clone());
+        let fee = Self::bps_share_floor(amount, fee_bps);
+        let net_amount = amount - fee;
+        // Holders' pot for this report: the offering's share of the net-of-fee amount.
+        let distributable = Self::bps_share_floor(net_amount, offering.revenue_share_bps);
+        let previous_fee = reports
+            .get(period_id)
+            .map(|(existing_amount, _timestamp)| Self::bps_share_floor(existing_amount, fee_bps))
+            .unwrap_or(0);
+
+        if !override_existing && reports.contains_key(period_id) {
+            return Err(RevoraError::DuplicatePeriod);
+        }
+
+        // Change to the offering's cumulative revenue caused by this report. Overrides
+        // replace the period's previous amount, so only the difference is applied. The
+        // new totals are computed before anything is written or emitted, so an overflow
+        // leaves no trace.
+        let revenue_delta = match reports.get(period_id) {
+            Some((existing_amount, _timestamp)) => {
+                Self::checked_amount(&env, amount.checked_sub(existing_amount))
+            }
+            None => amount,
+        };
+        let summary_key = DataKey::AuditSummary(issuer.clone(), token.clone());
+        let mut summary: AuditSummary =
+            env.storage()
+                .persistent()
+                .get(&summary_key)
+                .unwrap_or(AuditSummary {
+                    total_revenue: 0,
+                    report_count: 0,
+                });
+        summary.total_revenue =
+            Self::checked_amount(&env, summary.total_revenue.checked_add(revenue_delta));
+        summary.report_count = summary.report_count.saturating_add(1);
+        // Overrides only accrue the fee difference against the replaced amount.
+        let fees_key = DataKey::AccruedFees(payout_asset.clone());
+        let accrued_fees = if fee != previous_fee {
+            let accrued: i128 = env.storage().persistent().get(&fees_key).unwrap_or(0);
+            Some(Self::checked_amount(&env, accrued.checked_add(fee - previous_fee)).max(0))
+        } else {
+            None
+        };
+
+        match reports.get(period_id) {
+            Some((existing_amount, _timestamp)) => {
+                reports.set(period_id, (amount, current_timestamp));
+                env.storage().persistent().set(&key, &reports);
+
+                env.events().publish(
+                    (EVENT_REVENUE_REPORT_OVERRIDE, issuer.clone(), token.clone()),
+                    (
+                        amount,
+                        period_id,
+                        existing_amount,
+                        blacklist.clone(),
+                        truncated,
+                    ),
+                );
+
+                env.events().publish(
+                    (
+                        EVENT_REVENUE_REPORT_OVERRIDE_ASSET,
+                        issuer.clone(),
+                        token.clone(),
+                        payout_asset.clone(),
+                    ),
+                    (amount, period_id, existing_amount, blacklist.clone()),
+                );
+            }
+            None => {
+                reports.set(period_id, (amount, current_timestamp));
+                env.storage().persistent().set(&key, &reports);
+
+                env.events().publish(
+                    (EVENT_REVENUE_REPORT_INITIAL, issuer.clone(), token.clone()),
+                    (amount, period_id, blacklist.clone(), truncated),
+                );
+
+                env.events().publish(
+                    (
+                        EVENT_REVENUE_REPORT_INITIAL_ASSET,
+                        issuer.clone(),
+                        token.clone(),
+                        payout_asset.clone(),
+                    ),
+                    (amount, period_id, blacklist.clone()),
+                );
+            }
+        }
+
+        if amount >= 0 {
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::LastReportAt(token.clone()), &current_timestamp);
+        }
+        let last_key = DataKey::LastPeriod(issuer.clone(), token.clone());
+        let last_period: Option<u64> = env.storage().persistent().get(&last_key);
+        if last_period.is_none_or(|last| period_id > last) {
+            env.storage().persistent().set(&last_key, &period_id);
+        }
+
+        // Backward-compatible event (preserve `blacklist` for additional publishes)
+        if period_in_topics {
+            env.events().publish(
+                (
+                    EVENT_REVENUE_REPORTED,
+                    issuer.clone(),
+                    token.clone(),
+                    period_id,
+                ),
+                (amount, period_id, blacklist.clone()),
+            );
+        } else {
+            env.events().publish(
+                (EVENT_REVENUE_REPORTED, issuer.clone(), token.clone()),
+                (amount, period_id, blacklist.clone()),
+            );
+        }
+        Self::count_event(&env, EVENT_REVENUE_REPORTED);
+
+        env.events().publish(
+            (
+                EVENT_REVENUE_REPORTED_ASSET,
+                issuer.clone(),
+                token.clone(),
+                payout_asset.clone(),
+            ),
+            (
+                amount,
+                period_id,
+                net_amount,
+                distributable,
+                offering.decimals,
+            ),
+        );
+
+        // Optionally emit versioned v1 events for forward-compatible consumers
+        if Self::is_event_versioning_enabled(env.clone()) {
+            env.events().publish(
+                (EVENT_REV_INIT_V1, issuer.clone(), token.clone()),
+                (EVENT_SCHEMA_VERSION, amount, period_id, blacklist.clone()),
+            );
+
+            env.events().publish(
+                (
+                    EVENT_REV_INIA_V1,
+                    issuer.clone(),
+                    token.clone(),
+                    payout_asset.clone(),
+                ),
+                (EVENT_SCHEMA_VERSION, amount, period_id, blacklist.clone()),
+            );
+
+            env.events().publish(
+                (EVENT_REV_REP_V1, issuer.clone(), token.clone()),
+                (EVENT_SCHEMA_VERSION, amount, period_id, blacklist.clone()),
+            );
+
+            env.events().publish(
+                (
+                    EVENT_REV_REPA_V1,
+                    issuer.clone(),
+                    token.clone(),
+                    payout_asset.clone(),
+                ),
+                (EVENT_SCHEMA_VERSION, amount, period_id),
+            );
+        }
+
+        // Audit log summary (#34): maintain per-offering total revenue and report count.
+        env.storage().persistent().set(&summary_key, &summary);
+
+        let count_key = DataKeyExt::IssuerReportCount(issuer.clone());
+        let issuer_reports: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&count_key, &issuer_reports.saturating_add(1));
+
+        if let Some(accrued) = accrued_fees {
+            env.storage().persistent().set(&fees_key, &accrued);
+        }
+
+        Ok(())
+    }
+
+    /// Number of revenue reports accepted for `issuer` across all its offerings (overrides
+    /// included). Rejected reports, such as duplicate periods or replayed idempotency
+    /// keys, are not counted. Counted under the issuer at the time of each report.
+    pub fn get_issuer_report_count(env: Env, issuer: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::IssuerReportCount(issuer))
+            .unwrap_or(0)
+    }
+
+    /// `report_revenue` guarded by a caller-chosen idempotency key, for relayers that may
+    /// resubmit after a timeout. A key already accepted for `token` fails with
+    /// `DuplicateReport` and emits nothing.
+    ///
+    /// Keys are kept in temporary storage for about 7 days (`REPORT_KEY_TTL`) and then
+    /// expire, so storage does not grow without bound; a replay after that window is not
+    /// detected by the key (the period check in `report_revenue` still applies).
+    /// Keyed reports never override an existing period; use `report_revenue` for that.
+    pub fn report_revenue_idempotent(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+        idempotency_key: BytesN<32>,
+    ) -> Result<(), RevoraError> {
+        let key = DataKey::ReportKey(token.clone(), idempotency_key);
+        if env.storage().temporary().has(&key) {
+            return Err(RevoraError::DuplicateReport);
+        }
+
+        Self::report_revenue(
+            env.clone(),
+            issuer,
+            token,
+            payout_asset,
+            amount,
+            period_id,
+            false,
+        )?;
+
+        env.storage().temporary().set(&key, &true);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, REPORT_KEY_TTL, REPORT_KEY_TTL);
+        Ok(())
+    }
+
+    /// Let `reporter` (e.g. an oracle account) submit revenue reports for the offering in
+    /// place of the issuer. The issuer can still report directly; setting the issuer
+    /// itself as reporter revokes the delegation. The delegation is keyed by issuer, so an
+    /// issuer transfer drops it.
+    /// Fails with `OfferingNotFound` if `issuer` is not the current issuer.
+    /// Emits `rep_set` with the reporter.
+    pub fn set_reporter(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        reporter: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        issuer.require_auth();
+
+        let key = DataKey::Reporter(issuer.clone(), token.clone());
+        if reporter == issuer {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &reporter);
+        }
+
+        env.events()
+            .publish((EVENT_REPORTER_SET, issuer, token), reporter);
+        Ok(())
+    }
+
+    /// Let an on-chain settlement contract drive revenue reporting for the offering: the
+    /// contract calls `report_revenue` passing its own address as `issuer`, and its
+    /// invocation satisfies `require_auth`. Independent of `set_reporter`; setting the
+    /// issuer itself revokes it. Fails with `OfferingNotFound` if `issuer` is not the
+    /// current issuer. Emits `rep_c_set` with the contract address.
+    pub fn set_reporter_contract(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        contract_id: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        issuer.require_auth();
+
+        let key = DataKey::ReporterContract(issuer.clone(), token.clone());
+        if contract_id == issuer {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &contract_id);
+        }
+
+        env.events()
+            .publish((EVENT_REPORTER_CONTRACT_SET, issuer, token), contract_id);
+        Ok(())
+    }
+
+    /// Reporter contract registered for the offering, if any.
+    pub fn get_reporter_contract(env: Env, issuer: Address, token: Address) -> Option<Address> {
+        let key = DataKey::ReporterContract(issuer, token);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Designated revenue reporter for the offering, if any.
+    pub fn get_reporter(env: Env, issuer: Address, token: Address) -> Option<Address> {
+        let key = DataKey::Reporter(issuer, token);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Set the smallest revenue report amount accepted for the offering (0 disables the
+    /// check). Reports with `0 <= amount < min` fail with `AmountBelowMinimum`; negative
+    /// (clawback) amounts are corrections and bypass it. Fails with `OfferingNotFound`
+    /// if `issuer` is not the current issuer.
+    pub fn set_min_report_amount(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        min: i128,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        Self::require_config_unlocked(&env, &token)?;
+        issuer.require_auth();
+
+        let key = DataKeyExt::MinReportAmount(issuer, token);
+        env.storage().persistent().set(&key, &min);
+        Ok(())
+    }
+
+    /// Set the minimum number of seconds between revenue reports for the offering (0
+    /// disables the check), guarding against a faulty reporter flooding reports. A report
+    /// sooner than `min_report_interval` after the previous accepted one fails with
+    /// `CooldownActive`; negative (clawback) amounts are corrections that bypass it and
+    /// do not open a new window. A
+    /// `batch_report_revenue` call counts as a single report. Fails with `OfferingNotFound`
+    /// if `issuer` is not the current issuer.
+    pub fn set_report_cooldown(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        min_report_interval: u64,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        Self::require_config_unlocked(&env, &token)?;
+        issuer.require_auth();
+
+        let key = DataKeyExt::ReportCooldown(token);
+        env.storage().persistent().set(&key, &min_report_interval);
+        Ok(())
+    }
+
+    /// Minimum seconds between revenue reports for the offering (0 if unset).
+    pub fn get_report_cooldown(env: Env, token: Address) -> u64 {
+        let key = DataKeyExt::ReportCooldown(token);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Fail with `CooldownActive` if a non-clawback report for `token` would land inside
+    /// the cooldown window opened by the previous accepted report.
+    fn check_report_cooldown(env: &Env, token: &Address, amount: i128) -> Result<(), RevoraError> {
+        if amount < 0 {
+            return Ok(());
+        }
+        let cooldown = Self::get_report_cooldown(env.clone(), token.clone());
+        if cooldown == 0 {
+            return Ok(());
+        }
+        let last: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::LastReportAt(token.clone()));
+        if last.is_some_and(|at| env.ledger().timestamp() < at.saturating_add(cooldown)) {
+            return Err(RevoraError::CooldownActive);
+        }
+        Ok(())
+    }
+
+    /// Minimum revenue report amount for the offering (0 if unset).
+    pub fn get_min_report_amount(env: Env, issuer: Address, token: Address) -> i128 {
+        let key = DataKeyExt::MinReportAmount(issuer, token);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Return the stored revenue amount reported for `period_id`, if any.
+    pub fn get_revenue_report(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+    ) -> Option<i128> {
+        let key = DataKey::RevenueReports(issuer, token);
+        env.storage()
+            .persistent()
+            .get::<DataKey, Map<u64, (i128, u64)>>(&key)
+            .and_then(|reports| reports.get(period_id))
+            .map(|(amount, _timestamp)| amount)
+    }
+
+    /// Holders' share of the revenue reported for `period_id`:
+    /// `floor(stored_amount * revenue_share_bps / 10_000)`, rounded down (toward zero),
+    /// with non-positive amounts yielding 0. Computed on the gross reported amount, before
+    /// any platform fee (`rev_repa` carries the net-of-fee figure).
+    /// Fails with `OfferingNotFound` or `ReportNotFound`.
+    pub fn get_period_distributable(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+    ) -> Result<i128, RevoraError> {
+        let offering = Self::find_offering(env.clone(), issuer.clone(), token.clone())
+            .ok_or(RevoraError::OfferingNotFound)?;
+        let amount = Self::get_revenue_report(env, issuer, token, period_id)
+            .ok_or(RevoraError::ReportNotFound)?;
+        Ok(Self::bps_share_floor(amount, offering.revenue_share_bps))
+    }
+
+    /// Return `(period_id, amount)` for every reported period in the inclusive range
+    /// `from_period..=to_period`, in ascending period order. Periods without a report are
+    /// skipped; an inverted range yields an empty list.
+    /// Returns `Err(RevoraError::RangeTooWide)` if the range spans more than
+    /// MAX_PAGE_LIMIT periods.
+    pub fn get_reports_in_range(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        from_period: u64,
+        to_period: u64,
+    ) -> Result<Vec<(u64, i128)>, RevoraError> {
+        let mut results = Vec::new(&env);
+        if from_period > to_period {
+            return Ok(results);
+        }
+        if to_period - from_period >= MAX_PAGE_LIMIT as u64 {
+            return Err(RevoraError::RangeTooWide);
+        }
+
+        let key = DataKey::RevenueReports(issuer, token);
+        let reports: Map<u64, (i128, u64)> = match env.storage().persistent().get(&key) {
+            Some(r) => r,
+            None => return Ok(results),
+        };
+        for period_id in from_period..=to_period {
+            if let Some((amount, _timestamp)) = reports.get(period_id) {
+                results.push_back((period_id, amount));
+            }
+        }
+        Ok(results)
+    }
+
+    /// Store `issuer`'s offering count. Every write of the count goes through here, right
+    /// after the offering list itself was updated; debug builds check that the count
+    /// matches the list (entries `0..count` present, `count` absent).
+    fn set_offering_count(env: &Env, issuer: &Address, count: u32) {
+        debug_assert_eq!(count, Self::offering_list_len(env, issuer));
+        env.storage()
+            .persistent()
+            .set(&DataKey::OfferCount(issuer.clone()), &count);
+        Self::sync_issuer_index(env, issuer, count);
+    }
+
+    /// Keep `issuer` in the `get_issuers_page` list exactly while it has offerings.
+    fn sync_issuer_index(env: &Env, issuer: &Address, count: u32) {
+        let mut issuers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::Issuers)
+            .unwrap_or_else(|| Vec::new(env));
+        match (issuers.first_index_of(issuer), count) {
+            (None, 1..) => issuers.push_back(issuer.clone()),
+            (Some(index), 0) => {
+                issuers.remove(index);
+            }
+            _ => return,
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::Issuers, &issuers);
+    }
+
+    /// Page through every issuer that currently has at least one offering, in order of
+    /// their first registration. Issuers drop out when their last offering is removed or
+    /// transferred away. Limit capped at the page limit (`get_max_page_limit`).
+    pub fn get_issuers_page(env: Env, start: u32, limit: u32) -> (Vec<Address>, Option<u32>) {
+        let issuers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKeyExt::Issuers)
+            .unwrap_or_else(|| Vec::new(&env));
+        let count = issuers.len();
+        if start >= count {
+            return (Vec::new(&env), None);
+        }
+
+        let end = core::cmp::min(start + Self::effective_page_limit(&env, limit), count);
+        let next_cursor = if end < count { Some(end) } else { None };
+        (issuers.slice(start..end), next_cursor)
+    }
+
+    /// Number of contiguous offering entries stored for `issuer`, starting at index 0.
+    fn offering_list_len(env: &Env, issuer: &Address) -> u32 {
+        let mut len = 0u32;
+        while env
+            .storage()
+            .persistent()
+            .has(&DataKey::OfferItem(issuer.clone(), len))
+        {
+            len += 1;
+        }
+        len
+    }
+
+    /// Reset `issuer`'s offering count from the offering entries actually stored, repairing
+    /// a count that drifted from the list. Admin only. Returns the recomputed count and
+    /// emits `cnt_fix` with `(old_count, new_count)`.
+    pub fn recompute_offering_count(env: Env, issuer: Address) -> Result<u32, RevoraError> {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(RevoraError::Unauthorized)?;
+        admin.require_auth();
+
+        let old_count = Self::get_offering_count(env.clone(), issuer.clone());
+        let new_count = Self::offering_list_len(&env, &issuer);
+        Self::set_offering_count(&env, &issuer, new_count);
+
+        env.events()
+            .publish((EVENT_COUNT_REPAIRED, issuer), (old_count, new_count));
+        Ok(new_count)
+    }
+
+    /// Return the total number of offerings registered by `issuer`.
+    pub fn get_offering_count(env: Env, issuer: Address) -> u32 {
+        let count_key = DataKey::OfferCount(issuer);
+        env.storage().persistent().get(&count_key).unwrap_or(0)
+    }
+
+    /// Override the page limit used by the pagination getters (default MAX_PAGE_LIMIT, 20);
+    /// a `limit` of 0 then means this value. Only admin may call. Fails with
+    /// `InvalidPageLimit` if `new_limit` is zero or above 100. Emits `page_cfg`.
+    pub fn set_max_page_limit(env: Env, new_limit: u32) -> Result<(), RevoraError> {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(RevoraError::Unauthorized)?;
+        admin.require_auth();
+        if new_limit == 0 || new_limit > MAX_PAGE_LIMIT_CEILING {
+            return Err(RevoraError::InvalidPageLimit);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::MaxPageLimit, &new_limit);
+        env.events()
+            .publish((EVENT_PAGE_LIMIT_SET, admin), new_limit);
+        Ok(())
+    }
+
+    /// Page limit used by pagination: the admin override if set, else 20.
+    pub fn get_max_page_limit(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MaxPageLimit)
+            .unwrap_or(MAX_PAGE_LIMIT)
+    }
+
+    /// Clamp a requested page size: 0 or above the page limit yields the page limit.
+    fn effective_page_limit(env: &Env, limit: u32) -> u32 {
+        let max = Self::get_max_page_limit(env.clone());
+        if limit == 0 || limit > max {
+            max
+        } else {
+            limit
+        }
+    }
+
+    /// Return a page of offerings for `issuer`. Limit capped at the page limit
+    /// (`get_max_page_limit`, 20 by default).
+    pub fn get_offerings_page(
+        env: Env,
+        issuer: Address,
+        start: u32,
+        limit: u32,
+    ) -> (Vec<Offering>, Option<u32>) {
+        let count = Self::get_offering_count(env.clone(), issuer.clone());
+
+        let effective_limit = Self::effective_page_limit(&env, limit);
+
+        if start >= count {
+            return (Vec::new(&env), None);
+        }
+
+        let end = core::cmp::min(start + effective_limit, count);
+        let mut results = Vec::new(&env);
+
+        for i in start..end {
+            let item_key = DataKey::OfferItem(issuer.clone(), i);
+            let offering: Offering = env.storage().persistent().get(&item_key).unwrap();
+            results.push_back(offering);
+        }
+
+        let next_cursor = if end < count { Some(end) } else { None };
+        (results, next_cursor)
+    }
+
+    /// `get_offerings_page` that rejects a cursor beyond the issuer's offering count (e.g.
+    /// one taken from another issuer's pagination) instead of returning an empty page.
+    /// A cursor equal to the count is the end of the list and yields an empty page.
+    /// Returns `Err(RevoraErrorExt::InvalidCursor)` if `cursor` > count.
+    pub fn get_offerings_page_checked(
+        env: Env,
+        issuer: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<(Vec<Offering>, Option<u32>), RevoraErrorExt> {
+        if cursor > Self::get_offering_count(env.clone(), issuer.clone()) {
+            return Err(RevoraErrorExt::InvalidCursor);
+        }
+        Ok(Self::get_offerings_page(env, issuer, cursor, limit))
+    }
+
+    /// `get_offerings_page` plus the issuer's total offering count, read in the same call
+    /// so "showing 1-20 of N" views need one round-trip and the count matches the page.
+    pub fn get_offerings_page_with_total(
+        env: Env,
+        issuer: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> (Vec<Offering>, Option<u32>, u32) {
+        let total = Self::get_offering_count(env.clone(), issuer.clone());
+        let (page, next_cursor) = Self::get_offerings_page(env, issuer, cursor, limit);
+        (page, next_cursor, total)
+    }
+
+    /// Like `get_offerings_page`, but ordered by `sort`: `SORT_INSERTION` (0, same as
+    /// `get_offerings_page`), `SORT_BPS_ASC` (1) or `SORT_BPS_DESC` (2). Ties keep
+    /// registration order, so the ordering is total and `cursor` is a position in the
+    /// sorted list that stays meaningful across calls while offerings are unchanged.
+    ///
+    /// Cost: the bps modes read every offering of the issuer and sort them (quadratic in
+    /// the offering count) on each call; prefer mode 0 for large issuers.
+    /// Returns `Err(RevoraError::InvalidSortMode)` for any other `sort`.
+    pub fn get_offerings_page_sorted(
+        env: Env,
+        issuer: Address,
+        cursor: u32,
+        limit: u32,
+        sort: u32,
+    ) -> Result<(Vec<Offering>, Option<u32>), RevoraError> {
+        let descending = match sort {
+            SORT_INSERTION => return Ok(Self::get_offerings_page(env, issuer, cursor, limit)),
+            SORT_BPS_ASC => false,
+            SORT_BPS_DESC => true,
+            _ => return Err(RevoraError::InvalidSortMode),
+        };
+
+        let count = Self::get_offering_count(env.clone(), issuer.clone());
+        let effective_limit = Self::effective_page_limit(&env, limit);
+        if cursor >= count {
+            return Ok((Vec::new(&env), None));
+        }
+
+        // Stable insertion sort: each offering goes after every offering it does not
+        // strictly precede.
+        let mut sorted: Vec<Offering> = Vec::new(&env);
+        for i in 0..count {
+            let item_key = DataKey::OfferItem(issuer.clone(), i);
+            let offering: Offering = env.storage().persistent().get(&item_key).unwrap();
+            let mut pos = sorted.len();
+            while pos > 0 {
+                let prev = sorted.get(pos - 1).unwrap().revenue_share_bps;
+                let precedes = if descending {
+                    offering.revenue_share_bps > prev
+                } else {
+                    offering.revenue_share_bps < prev
+                };
+                if !precedes {
+                    break;
+                }
+                pos -= 1;
+            }
+            sorted.insert(pos, offering);
+        }
+
+        let end = core::cmp::min(cursor + effective_limit, count);
+        let next_cursor = if end < count { Some(end) } else { None };
+        Ok((sorted.slice(cursor..end), next_cursor))
+    }
+
+    /// Paginate the issuer's offerings, keeping only those with `revenue_share_bps` in
+    /// `[min_bps, max_bps]`. `start`/`limit` window the underlying list exactly like
+    /// `get_offerings_page`, so cursors stay stable; a page may hold fewer than `limit`
+    /// (even zero) matches while `next_cursor` is still `Some`.
+    /// Returns `Err(RevoraError::InvalidBpsRange)` if `min_bps > max_bps`.
+    pub fn get_offerings_by_bps_range(
+        env: Env,
+        issuer: Address,
+        min_bps: u32,
+        max_bps: u32,
+        start: u32,
+        limit: u32,
+    ) -> Result<(Vec<Offering>, Option<u32>), RevoraError> {
+        if min_bps > max_bps {
+            return Err(RevoraError::InvalidBpsRange);
+        }
+        let (page, next_cursor) = Self::get_offerings_page(env.clone(), issuer, start, limit);
+        let mut results = Vec::new(&env);
+        for offering in page.iter() {
+            if offering.revenue_share_bps >= min_bps && offering.revenue_share_bps <= max_bps {
+                results.push_back(offering);
+            }
+        }
+        Ok((results, next_cursor))
+    }
+
+    /// Add `investor` to the per-offering blacklist for `token`. Idempotent.
+    /// Caller must be the admin, the offering's issuer or one of its admins, or a
+    /// `ROLE_BLACKLIST_MANAGER` holder (the same check guards every blacklist edit);
+    /// otherwise fails with `Unauthorized`.
+    /// Fails with `BlacklistFull` if the token is at `get_max_blacklist_size`.
+    pub fn blacklist_add(
+        env: Env,
+        caller: Address,
+        token: Address,
+        investor: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        Self::require_blacklist_manager(&env, &caller, &token)?;
+
+        Self::insert_blacklist_entries(
+            &env,
+            &token,
+            &vec![&env, investor.clone()],
+            BLACKLIST_REASON_UNSPECIFIED,
+        )?;
+        Self::set_blacklist_expiry(&env, &token, &investor, None);
+
+        env.events()
+            .publish((EVENT_BL_ADD, token, caller), investor);
+        Self::count_event(&env, EVENT_BL_ADD);
+        Ok(())
+    }
+
+    /// Blacklist `investor` for `token` until ledger timestamp `expiry`. The entry stops
+    /// counting (for `is_blacklisted`, `get_blacklist` and friends) once the ledger
+    /// timestamp reaches `expiry`; no cleanup call is needed. Re-adding with
+    /// `blacklist_add` makes the entry permanent. Emits `bl_add_u` with the expiry.
+    pub fn blacklist_add_until(
+        env: Env,
+        caller: Address,
+        token: Address,
+        investor: Address,
+        expiry: u64,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        Self::require_blacklist_manager(&env, &caller, &token)?;
+
+        Self::insert_blacklist_entries(
+            &env,
+            &token,
+            &vec![&env, investor.clone()],
+            BLACKLIST_REASON_UNSPECIFIED,
+        )?;
+        Self::set_blacklist_expiry(&env, &token, &investor, Some(expiry));
+
+        env.events()
+            .publish((EVENT_BL_ADD_UNTIL, token, caller), (investor, expiry));
+        Self::count_event(&env, EVENT_BL_ADD_UNTIL);
+        Ok(())
+    }
+
+    /// `blacklist_add` recording why: `reason` is an integrator-defined code
+    /// (`BLACKLIST_REASON_UNSPECIFIED` is what the other add paths store). The reason and
+    /// listing time are kept from the investor's first listing, so re-adding an investor
+    /// who is already listed leaves them unchanged. Emits `bl_add_r` with `(investor, reason)`.
+    pub fn blacklist_add_with_reason(
+        env: Env,
+        caller: Address,
+        token: Address,
+        investor: Address,
+        reason: u32,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        Self::require_blacklist_manager(&env, &caller, &token)?;
+
+        Self::insert_blacklist_entries(&env, &token, &vec![&env, investor.clone()], reason)?;
+        Self::set_blacklist_expiry(&env, &token, &investor, None);
+
+        env.events()
+            .publish((EVENT_BL_ADD_REASON, token, caller), (investor, reason));
+        Self::count_event(&env, EVENT_BL_ADD_REASON);
+        Ok(())
+    }
+
+    /// Apply a net change to the global blacklist entry counter.
+    fn adjust_blacklist_total(env: &Env, added: u32, removed: u32) {
+        if added == removed {
+            return;
+        }
+        let key = DataKey::BlacklistTotal;
+        let total: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        let total = total.saturating_add(added).saturating_sub(removed);
+        env.storage().persistent().set(&key, &total);
+    }
+
+    /// Total number of blacklist entries stored across every token, for compliance
+    /// reporting. Expired `blacklist_add_until` entries still count until removed.
+    pub fn get_blacklist_count_all(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BlacklistTotal)
+            .unwrap_or(0)
+    }
+
+    /// Set or clear (`None`) the blacklist expiry of `investor` for `token`.
+    fn set_blacklist_expiry(env: &Env, token: &Address, investor: &Address, expiry: Option<u64>) {
+        let key = DataKey::BlacklistExpiry(token.clone());
+        let mut expiries: Map<Address, u64> = match env.storage().persistent().get(&key) {
+            Some(m) => m,
+            None if expiry.is_none() => return,
+            None => Map::new(env),
+        };
+        match expiry {
+            Some(ts) => expiries.set(investor.clone(), ts),
+            None => {
+                if expiries.remove(investor.clone()).is_none() {
+                    return;
+                }
+            }
+        }
+        env.storage().persistent().set(&key, &expiries);
+    }
+
+    /// Blacklist expiries for `token`, if any entry was added with `blacklist_add_until`.
+    fn blacklist_expiries(env: &Env, token: &Address) -> Option<Map<Address, u64>> {
+        let key = DataKey::BlacklistExpiry(token.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Add many investors to the per-offering blacklist for `token` in one call.
+    /// Addresses already blacklisted (or repeated within the batch) are skipped.
+    /// Emits a single `bl_batch` event with the number actually added. `caller` is checked
+    /// against the stored admin (or issuer / `ROLE_BLACKLIST_MANAGER`) like `blacklist_add`.
+    /// Fails with `BatchTooLarge` if more than MAX_BLACKLIST_BATCH (100) investors are given
+    /// and with `BlacklistFull` (adding none) if the new entries would exceed the size cap.
+    pub fn blacklist_add_batch(
+        env: Env,
+        caller: Address,
+        token: Address,
+        investors: Vec<Address>,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        Self::require_blacklist_manager(&env, &caller, &token)?;
+
+        if investors.len() > MAX_BLACKLIST_BATCH {
+            return Err(RevoraError::BatchTooLarge);
+        }
+
+        let added =
+            Self::insert_blacklist_entries(&env, &token, &investors, BLACKLIST_REASON_UNSPECIFIED)?;
+
+        env.events().publish((EVENT_BL_BATCH, token, caller), added);
+        Self::count_event(&env, EVENT_BL_BATCH);
+        Ok(())
+    }
+
+    /// Preview `blacklist_add_batch(token, investors)` without writing: returns
+    /// `(would_add, already_present)`, where addresses repeated within the batch count as
+    /// already present since the batch would skip them. The size cap is not checked.
+    /// Fails with `BatchTooLarge` above MAX_BLACKLIST_BATCH (100) investors.
+    pub fn preview_blacklist_batch(
+        env: Env,
+        token: Address,
+        investors: Vec<Address>,
+    ) -> Result<(u32, u32), RevoraError> {
+        if investors.len() > MAX_BLACKLIST_BATCH {
+            return Err(RevoraError::BatchTooLarge);
+        }
+
+        let mut map: Map<Address, bool> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Blacklist(token.clone()))
+            .unwrap_or_else(|| Map::new(&env));
+        // Expired entries would be pruned by the batch, so they count as absent.
+        if let Some(expiries) = Self::blacklist_expiries(&env, &token) {
+            let now = env.ledger().timestamp();
+            for (investor, expiry) in expiries.iter() {
+                if expiry <= now {
+                    map.remove(investor);
+                }
+            }
+        }
+        let mut would_add: u32 = 0;
+        for investor in investors.iter() {
+            if !map.contains_key(investor.clone()) {
+                map.set(investor, true);
+                would_add += 1;
+            }
+        }
+        Ok((would_add, investors.len() - would_add))
+    }
+
+    /// Remove `investor` from the per-offering blacklist for `token`. Idempotent.
+    pub fn blacklist_remove(
+        env: Env,
+        caller: Address,
+        token: Address,
+        investor: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        Self::require_blacklist_manager(&env, &caller, &token)?;
+
+        Self::remove_blacklist_entry(&env, &token, &investor);
+
+        env.events()
+            .publish((EVENT_BL_REM, token, caller), investor);
+        Self::count_event(&env, EVENT_BL_REM);
+        Ok(())
+    }
+
+    /// Owner (contract admin) emergency removal of a blacklist entry, e.g. after a
+    /// compromised manager key mass-blacklisted investors. Works while paused but not
+    /// while frozen. Fails with `Unauthorized` unless `owner` is the admin. Emits
+    /// `bl_frc_rm` (distinct from `bl_rem`) so the owner action is visible on-chain.
+    pub fn force_remove_blacklist_entry(
+        env: Env,
+        owner: Address,
+        token: Address,
+        investor: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&owner) {
+            return Err(RevoraError::Unauthorized);
+        }
+        owner.require_auth();
+
+        Self::remove_blacklist_entry(&env, &token, &investor);
+
+        env.events()
+            .publish((EVENT_BL_FORCE_REMOVED, token, owner), investor);
+        Self::count_event(&env, EVENT_BL_FORCE_REMOVED);
+        Ok(())
+    }
+
+    /// Append the investors not yet listed to `token`'s blacklist, keeping insertion
+    /// order (repeats within `investors` are skipped) and recording `reason` and the
+    /// current ledger timestamp for each. Returns how many were added, or
+    /// `BlacklistFull` once the token would hold more than `get_max_blacklist_size` entries.
+    fn insert_blacklist_entries(
+        env: &Env,
+        token: &Address,
+        investors: &Vec<Address>,
+        reason: u32,
+    ) -> Result<u32, RevoraError> {
+        Self::prune_expired_blacklist(env, token);
+        let key = DataKey::Blacklist(token.clone());
+        let mut map: Map<Address, bool> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(env));
+        let mut order = Self::blacklist_order(env, token);
+        let mut details = Self::blacklist_details(env, token);
+        let now = env.ledger().timestamp();
+
+        let cap = Self::get_max_blacklist_size(env.clone());
+        let mut added: u32 = 0;
+        for investor in investors.iter() {
+            if !map.contains_key(investor.clone()) {
+                if map.len() >= cap {
+                    return Err(RevoraError::BlacklistFull);
+                }
+                map.set(investor.clone(), true);
+                Self::record_blacklist_change(env, token, &investor, true);
+                details.set(investor.clone(), (reason, now));
+                order.push_back(investor);
+                added += 1;
+            }
+        }
+        env.storage().persistent().set(&key, &map);
+        env.storage()
+            .persistent()
+            .set(&DataKey::BlacklistOrder(token.clone()), &order);
+        if added > 0 {
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::BlacklistDetails(token.clone()), &details);
+        }
+        Self::adjust_blacklist_total(env, added, 0);
+        Ok(added)
+    }
+
+    /// Override the maximum number of blacklist entries per token (default 500). Only
+    /// admin may call. Lowering it below a token's current size keeps existing entries
+    /// but rejects further additions until enough are removed. Emits `bl_cap`.
+    pub fn set_max_blacklist_size(env: Env, new_max: u32) -> Result<(), RevoraError> {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(RevoraError::Unauthorized)?;
+        admin.require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::MaxBlacklistSize, &new_max);
+        env.events().publish((EVENT_BL_CAP_SET, admin), new_max);
+        Ok(())
+    }
+
+    /// Maximum number of blacklist entries per token: the admin override if set, else 500.
+    pub fn get_max_blacklist_size(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::MaxBlacklistSize)
+            .unwrap_or(DEFAULT_MAX_BLACKLIST_SIZE)
+    }
+
+    /// Delete `token`'s `blacklist_add_until` entries whose expiry has passed, so writes
+    /// treat those investors as absent (re-listable, and not counted against the size cap).
+    /// Each one is logged as a removal.
+    fn prune_expired_blacklist(env: &Env, token: &Address) {
+        let expiries = match Self::blacklist_expiries(env, token) {
+            Some(expiries) => expiries,
+            None => return,
+        };
+        let now = env.ledger().timestamp();
+        for (investor, expiry) in expiries.iter() {
+            if expiry <= now {
+                Self::remove_blacklist_entry(env, token, &investor);
+            }
+        }
+    }
+
+    /// Drop `investor` (and any expiry) from `token`'s blacklist, keeping the order
+    /// list compact. No-op if absent.
+    fn remove_blacklist_entry(env: &Env, token: &Address, investor: &Address) {
+        let key = DataKey::Blacklist(token.clone());
+        let mut map: Map<Address, bool> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(env));
+
+        if map.remove(investor.clone()).is_some() {
+            Self::adjust_blacklist_total(env, 0, 1);
+            Self::record_blacklist_change(env, token, investor, false);
+            let mut order = Self::blacklist_order(env, token);
+            if let Some(i) = order.first_index_of(investor) {
+                order.remove(i);
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::BlacklistOrder(token.clone()), &order);
+            let mut details = Self::blacklist_details(env, token);
+            if details.remove(investor.clone()).is_some() {
+                env.storage()
+                    .persistent()
+                    .set(&DataKeyExt::BlacklistDetails(token.clone()), &details);
+            }
+        }
+        env.storage().persistent().set(&key, &map);
+        Self::set_blacklist_expiry(env, token, investor, None);
+    }
+
+    /// Bump `token`'s blacklist version and log the change under the new version.
+    fn record_blacklist_change(env: &Env, token: &Address, investor: &Address, added: bool) {
+        let version = Self::get_blacklist_version(env.clone(), token.clone()) + 1;
+        let store = env.storage().persistent();
+        store.set(&DataKeyExt::BlacklistVersion(token.clone()), &version);
+        store.set(
+            &DataKeyExt::BlacklistChange(token.clone(), version),
+            &(investor.clone(), added),
+        );
+    }
+
+    /// Number of effective blacklist changes (adds and removes) for `token` so far.
+    /// Increases by one per change; re-adding a listed investor does not count.
+    pub fn get_blacklist_version(env: Env, token: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::BlacklistVersion(token))
+            .unwrap_or(0)
+    }
+
+    /// Blacklist changes for `token` after `since_version`, oldest first, as
+    /// `(investor, true)` for adds and `(investor, false)` for removes. At most the page
+    /// limit (`get_max_page_limit`) is returned; call again with `since_version` advanced
+    /// by the number returned until it reaches `get_blacklist_version`.
+    pub fn get_blacklist_since(
+        env: Env,
+        token: Address,
+        since_version: u64,
+    ) -> Vec<(Address, bool)> {
+        let version = Self::get_blacklist_version(env.clone(), token.clone());
+        let limit = u64::from(Self::get_max_page_limit(env.clone()));
+        let end = core::cmp::min(version, since_version.saturating_add(limit));
+        let mut changes = Vec::new(&env);
+        for v in since_version.saturating_add(1)..=end {
+            let key = DataKeyExt::BlacklistChange(token.clone(), v);
+            if let Some(change) = env.storage().persistent().get(&key) {
+                changes.push_back(change);
+            }
+        }
+        changes
+    }
+
+    /// Reason code and listing timestamp of each blacklisted investor of `token`. Entries
+    /// listed before these were recorded are missing.
+    fn blacklist_details(env: &Env, token: &Address) -> Map<Address, (u32, u64)> {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::BlacklistDetails(token.clone()))
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    /// Blacklisted addresses for `token` in insertion order. Blacklists written before
+    /// the order list existed fall back to the map's key order.
+    fn blacklist_order(env: &Env, token: &Address) -> Vec<Address> {
+        let store = env.storage().persistent();
+        if let Some(order) = store.get(&DataKey::BlacklistOrder(token.clone())) {
+            return order;
+        }
+        store
+            .get::<DataKey, Map<Address, bool>>(&DataKey::Blacklist(token.clone()))
+            .map(|m| m.keys())
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Delete every blacklist entry (and expiry) for `token`, returning how many entries
+    /// were removed. Caller must be the admin or a `ROLE_BLACKLIST_MANAGER` holder; the
+    /// issuer cannot wipe the list and otherwise fails with `Unauthorized`. Clearing an
+    /// empty blacklist returns 0. Emits `bl_clear` with the count.
+    pub fn clear_blacklist(env: Env, caller: Address, token: Address) -> Result<u32, RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&caller)
+            && !Self::has_role(env.clone(), caller.clone(), ROLE_BLACKLIST_MANAGER)
+        {
+            return Err(RevoraError::Unauthorized);
+        }
+        caller.require_auth();
+
+        let key = DataKey::Blacklist(token.clone());
+        let removed = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Map<Address, bool>>(&key)
+            .map(|map| map.len())
+            .unwrap_or(0);
+        for investor in Self::blacklist_order(&env, &token).iter() {
+            Self::record_blacklist_change(&env, &token, &investor, false);
+        }
+        env.storage().persistent().remove(&key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BlacklistOrder(token.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BlacklistExpiry(token.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::BlacklistDetails(token.clone()));
+        Self::adjust_blacklist_total(&env, 0, removed);
+
+        env.events()
+            .publish((EVENT_BL_CLEARED, token, caller), removed);
+        Self::count_event(&env, EVENT_BL_CLEARED);
+        Ok(removed)
+    }
+
+    /// Returns `true` if `investor` is blacklisted for `token`'s offering.
+    /// Entries added with `blacklist_add_until` stop counting once their expiry passes.
+    pub fn is_blacklisted(env: Env, token: Address, investor: Address) -> bool {
         let key = DataKey::Blacklist(token.clone());
+        let listed = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Map<Address, bool>>(&key)
+            .map(|m| m.get(investor.clone()).unwrap_or(false))
+            .unwrap_or(false);
+        if !listed {
+            return false;
+        }
+        match Self::blacklist_expiries(&env, &token).and_then(|e| e.get(investor)) {
+            Some(expiry) => env.ledger().timestamp() < expiry,
+            None => true,
+        }
+    }
+
+    /// Copy every active blacklist entry of `from_token` to `to_token`, e.g. when an
+    /// offering is re-issued under a new token. Entries keep their order, any expiry and
+    /// their reason code and listing time;
+    /// investors already listed on `to_token` are left as they are (merge, not overwrite).
+    /// Caller must be the admin or a `ROLE_BLACKLIST_MANAGER` holder, otherwise
+    /// `Unauthorized`. Returns the number of entries added to `to_token` and emits
+    /// `bl_xfer` with it. Fails with `BlacklistFull` if `to_token` cannot take them all.
+    pub fn blacklist_transfer(
+        env: Env,
+        caller: Address,
+        from_token: Address,
+        to_token: Address,
+    ) -> Result<u32, RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&caller)
+            && !Self::has_role(env.clone(), caller.clone(), ROLE_BLACKLIST_MANAGER)
+        {
+            return Err(RevoraError::Unauthorized);
+        }
+        caller.require_auth();
+
+        Self::prune_expired_blacklist(&env, &to_token);
+        let target_key = DataKey::Blacklist(to_token.clone());
+        let existing: Map<Address, bool> = env
+            .storage()
+            .persistent()
+            .get(&target_key)
+            .unwrap_or_else(|| Map::new(&env));
+        let mut incoming = Vec::new(&env);
+        for investor in Self::get_blacklist(env.clone(), from_token.clone()).iter() {
+            if !existing.contains_key(investor.clone()) {
+                incoming.push_back(investor);
+            }
+        }
+        let migrated = Self::insert_blacklist_entries(
+            &env,
+            &to_token,
+            &incoming,
+            BLACKLIST_REASON_UNSPECIFIED,
+        )?;
+
+        if let Some(expiries) = Self::blacklist_expiries(&env, &from_token) {
+            for investor in incoming.iter() {
+                if let Some(expiry) = expiries.get(investor.clone()) {
+                    Self::set_blacklist_expiry(&env, &to_token, &investor, Some(expiry));
+                }
+            }
+        }
+        let source_details = Self::blacklist_details(&env, &from_token);
+        let mut details = Self::blacklist_details(&env, &to_token);
+        for investor in incoming.iter() {
+            if let Some(entry) = source_details.get(investor.clone()) {
+                details.set(investor, entry);
+            }
+        }
+        if !incoming.is_empty() {
+            env.storage()
+                .persistent()
+                .set(&DataKeyExt::BlacklistDetails(to_token.clone()), &details);
+        }
+
+        env.events()
+            .publish((EVENT_BL_TRANSFERRED, from_token, to_token), migrated);
+        Ok(migrated)
+    }
+
+    /// `is_blacklisted` for each of `investors`, as a parallel vector, in one call.
+    /// Returns `Err(RevoraError::BatchTooLarge)` for more than MAX_PAGE_LIMIT investors.
+    pub fn is_blacklisted_batch(
+        env: Env,
+        token: Address,
+        investors: Vec<Address>,
+    ) -> Result<Vec<bool>, RevoraError> {
+        if investors.len() > MAX_PAGE_LIMIT {
+            return Err(RevoraError::BatchTooLarge);
+        }
+        let mut results = Vec::new(&env);
+        for investor in investors.iter() {
+            results.push_back(Self::is_blacklisted(env.clone(), token.clone(), investor));
+        }
+        Ok(results)
+    }
+
+    /// File an appeal against `investor`'s blacklisting for `token`: emits `bl_appeal` with
+    /// `reason_uri` (a reference to the investor's statement) for admins to review off-chain.
+    /// Blacklist state is not changed. `reason_uri` follows the metadata length rules
+    /// (`EmptyMetadata`, `MetadataTooLarge`); fails with `NotBlacklisted` unless `investor`
+    /// is currently blacklisted.
+    pub fn request_blacklist_appeal(
+        env: Env,
+        investor: Address,
+        token: Address,
+        reason_uri: String,
+    ) -> Result<(), RevoraError> {
+        investor.require_auth();
+        Self::validate_metadata(&env, &reason_uri)?;
+        if !Self::is_blacklisted(env.clone(), token.clone(), investor.clone()) {
+            return Err(RevoraError::NotBlacklisted);
+        }
+        env.events()
+            .publish((EVENT_BL_APPEAL, token, investor), reason_uri);
+        Ok(())
+    }
+
+    /// Return all currently blacklisted addresses for `token`'s offering in the order
+    /// they were added (expired `blacklist_add_until` entries are left out). Removals
+    /// keep the remaining order, so lists compare cleanly across ledgers.
+    pub fn get_blacklist(env: Env, token: Address) -> Vec<Address> {
+        let all = Self::blacklist_order(&env, &token);
+        let expiries = match Self::blacklist_expiries(&env, &token) {
+            Some(e) => e,
+            None => return all,
+        };
+        let now = env.ledger().timestamp();
+        let mut active = Vec::new(&env);
+        for investor in all.iter() {
+            if expiries
+                .get(investor.clone())
+                .is_none_or(|expiry| now < expiry)
+            {
+                active.push_back(investor);
+            }
+        }
+        active
+    }
+
+    /// Number of currently blacklisted investors for `token`.
+    pub fn count_blacklisted(env: Env, token: Address) -> u32 {
+        if Self::blacklist_expiries(&env, &token).is_some() {
+            return Self::get_blacklist(env, token).len();
+        }
+        let key = DataKey::Blacklist(token);
+        env.storage()
+            .persistent()
+            .get::<DataKey, Map<Address, bool>>(&key)
+            .map(|map| map.len())
+            .unwrap_or(0)
+    }
+
+    /// Return a page of blacklisted addresses for `token`. Limit capped at the page limit
+    /// (`get_max_page_limit`, 20 by default).
+    /// Cursor semantics match `get_offerings_page`; entries follow `get_blacklist` order.
+    pub fn get_blacklist_page(
+        env: Env,
+        token: Address,
+        start: u32,
+        limit: u32,
+    ) -> (Vec<Address>, Option<u32>) {
+        let blacklist = Self::get_blacklist(env.clone(), token);
+        let count = blacklist.len();
+
+        let effective_limit = Self::effective_page_limit(&env, limit);
+
+        if start >= count {
+            return (Vec::new(&env), None);
+        }
+
+        let end = core::cmp::min(start + effective_limit, count);
+        let results = blacklist.slice(start..end);
+
+        let next_cursor = if end < count { Some(end) } else { None };
+        (results, next_cursor)
+    }
+
+    /// `get_blacklist_page` with each entry's reason code and the ledger timestamp it was
+    /// listed at, as `(investor, reason, added_at)`, for compliance exports. Same cursor
+    /// semantics, order and page-limit capping. Entries listed before reasons were
+    /// recorded read as `(investor, BLACKLIST_REASON_UNSPECIFIED, 0)`.
+    pub fn get_blacklist_reasons_page(
+        env: Env,
+        token: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> (Vec<(Address, u32, u64)>, Option<u32>) {
+        let details = Self::blacklist_details(&env, &token);
+        let (page, next_cursor) = Self::get_blacklist_page(env.clone(), token, cursor, limit);
+        let mut results = Vec::new(&env);
+        for investor in page.iter() {
+            let (reason, added_at) = details
+                .get(investor.clone())
+                .unwrap_or((BLACKLIST_REASON_UNSPECIFIED, 0));
+            results.push_back((investor, reason, added_at));
+        }
+        (results, next_cursor)
+    }
+
+    // ── Whitelist mode ─────────────────────────────────────────
+
+    /// Add `investor` to the per-offering whitelist for `token`. Idempotent. Callers are
+    /// checked as for blacklist edits (admin, issuer or its admins, `ROLE_BLACKLIST_MANAGER`);
+    /// fails with `OfferingNotFound` if `token` has no offering and `Unauthorized` otherwise.
+    pub fn whitelist_add(
+        env: Env,
+        caller: Address,
+        token: Address,
+        investor: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        Self::require_blacklist_manager(&env, &caller, &token)?;
+
+        let key = DataKey::Whitelist(token.clone());
         let mut map: Map<Address, bool> = env
             .storage()
             .persistent()
@@ -647,22 +4177,24 @@ impl RevoraRevenueShare {
         env.storage().persistent().set(&key, &map);
 
         env.events()
-            .publish((EVENT_BL_ADD, token, caller), investor);
+            .publish((EVENT_WL_ADD, token, caller), investor);
         Ok(())
     }
 
-    /// Remove `investor` from the per-offering blacklist for `token`. Idempotent.
-    pub fn blacklist_remove(
+    /// Remove `investor` from the per-offering whitelist for `token`. Idempotent.
+    /// Same checks as `whitelist_add`.
+    pub fn whitelist_remove(
         env: Env,
         caller: Address,
         token: Address,
         investor: Address,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
-        Self::require_not_paused(&env);
-        caller.require_auth();
+        Self::require_not_paused(&env)?;
+        Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        Self::require_blacklist_manager(&env, &caller, &token)?;
 
-        let key = DataKey::Blacklist(token.clone());
+        let key = DataKey::Whitelist(token.clone());
         let mut map: Map<Address, bool> = env
             .storage()
             .persistent()
@@ -673,13 +4205,13 @@ impl RevoraRevenueShare {
         env.storage().persistent().set(&key, &map);
 
         env.events()
-            .publish((EVENT_BL_REM, token, caller), investor);
+            .publish((EVENT_WL_REM, token, caller), investor);
         Ok(())
     }
 
-    /// Returns `true` if `investor` is blacklisted for `token`'s offering.
-    pub fn is_blacklisted(env: Env, token: Address, investor: Address) -> bool {
-        let key = DataKey::Blacklist(token);
+    /// Returns `true` if `investor` is whitelisted for `token`'s offering.
+    pub fn is_whitelisted(env: Env, token: Address, investor: Address) -> bool {
+        let key = DataKey::Whitelist(token);
         env.storage()
             .persistent()
             .get::<DataKey, Map<Address, bool>>(&key)
@@ -687,14 +4219,61 @@ impl RevoraRevenueShare {
             .unwrap_or(false)
     }
 
-    /// Return all blacklisted addresses for `token`'s offering.
-    pub fn get_blacklist(env: Env, token: Address) -> Vec<Address> {
-        let key = DataKey::Blacklist(token);
+    /// Enable or disable whitelist mode for `token`'s offering. Same checks as `whitelist_add`.
+    pub fn set_whitelist_enabled(
+        env: Env,
+        caller: Address,
+        token: Address,
+        enabled: bool,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        Self::require_blacklist_manager(&env, &caller, &token)?;
+
+        let key = DataKey::WhitelistEnabled(token.clone());
+        env.storage().persistent().set(&key, &enabled);
+
+        env.events()
+            .publish((EVENT_WL_MODE, token, caller), enabled);
+        Ok(())
+    }
+
+    /// Returns `true` if whitelist mode is enabled for `token`'s offering (default false).
+    pub fn is_whitelist_enabled(env: Env, token: Address) -> bool {
+        let key = DataKey::WhitelistEnabled(token);
         env.storage()
             .persistent()
-            .get::<DataKey, Map<Address, bool>>(&key)
-            .map(|m| m.keys())
-            .unwrap_or_else(|| Vec::new(&env))
+            .get::<DataKey, bool>(&key)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if `investor` may receive distributions for `token`'s offering.
+    /// The blacklist always wins; when whitelist mode is enabled the investor must
+    /// also be whitelisted.
+    pub fn is_eligible(env: Env, token: Address, investor: Address) -> bool {
+        if Self::is_blacklisted(env.clone(), token.clone(), investor.clone()) {
+            return false;
+        }
+        if Self::is_whitelist_enabled(env.clone(), token.clone()) {
+            return Self::is_whitelisted(env, token, investor);
+        }
+        true
+    }
+
+    /// `is_eligible` with the reason: `(true, ELIGIBLE)`, `(false, INELIGIBLE_BLACKLISTED)`
+    /// for an active (unexpired) blacklist entry, or `(false, INELIGIBLE_NOT_WHITELISTED)`
+    /// when whitelist mode is enabled and `investor` is not on the whitelist.
+    pub fn get_my_eligibility(env: Env, token: Address, investor: Address) -> (bool, u32) {
+        if Self::is_blacklisted(env.clone(), token.clone(), investor.clone()) {
+            return (false, INELIGIBLE_BLACKLISTED);
+        }
+        if Self::is_whitelist_enabled(env.clone(), token.clone())
+            && !Self::is_whitelisted(env, token, investor)
+        {
+            return (false, INELIGIBLE_NOT_WHITELISTED);
+        }
+        (true, ELIGIBLE)
     }
 
     // ── Holder concentration guardrail (#26) ───────────────────
@@ -710,6 +4289,7 @@ impl RevoraRevenueShare {
         enforce: bool,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
         // Verify offering exists and issuer is current
         let current_issuer =
@@ -719,6 +4299,8 @@ impl RevoraRevenueShare {
             return Err(RevoraError::LimitReached);
         }
 
+        Self::require_config_unlocked(&env, &token)?;
+
         issuer.require_auth();
         let key = DataKey::ConcentrationLimit(issuer, token);
         env.storage()
@@ -735,6 +4317,7 @@ impl RevoraRevenueShare {
         concentration_bps: u32,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
         // Verify offering exists and issuer is current
         let current_issuer =
@@ -766,6 +4349,61 @@ impl RevoraRevenueShare {
         Ok(())
     }
 
+    /// Configure the period ordering guard for an offering. Caller must be the offering's
+    /// current issuer or the contract admin; otherwise fails with `Unauthorized`.
+    /// With `enforce` on, reports must use a `period_id` strictly greater than the highest
+    /// reported so far (`NonMonotonicPeriod` otherwise); `allow_backdated` lifts that for
+    /// corrections. Fails with `OfferingNotFound` if `issuer` is not the current issuer.
+    pub fn set_period_guard(
+        env: Env,
+        caller: Address,
+        issuer: Address,
+        token: Address,
+        enforce: bool,
+        allow_backdated: bool,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if caller != issuer && admin.as_ref() != Some(&caller) {
+            return Err(RevoraError::Unauthorized);
+        }
+        Self::require_config_unlocked(&env, &token)?;
+        caller.require_auth();
+
+        let key = DataKey::PeriodGuard(issuer, token);
+        env.storage().persistent().set(
+            &key,
+            &PeriodGuardConfig {
+                enforce,
+                allow_backdated,
+            },
+        );
+        Ok(())
+    }
+
+    /// Period ordering guard config for an offering, if set.
+    pub fn get_period_guard(
+        env: Env,
+        issuer: Address,
+        token: Address,
+    ) -> Option<PeriodGuardConfig> {
+        let key = DataKey::PeriodGuard(issuer, token);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Highest `period_id` reported for an offering, if any.
+    pub fn get_last_reported_period(env: Env, issuer: Address, token: Address) -> Option<u64> {
+        let key = DataKey::LastPeriod(issuer, token);
+        env.storage().persistent().get(&key)
+    }
+
     /// Get concentration limit config for an offering.
     pub fn get_concentration_limit(
         env: Env,
@@ -790,6 +4428,15 @@ impl RevoraRevenueShare {
         env.storage().persistent().get(&key)
     }
 
+    /// Get cumulative reported revenue for an offering (0 if never reported).
+    /// Negative reports are clawbacks and are applied as-is: the total does not
+    /// saturate at zero and may go negative if clawbacks exceed reported revenue.
+    pub fn get_total_revenue(env: Env, issuer: Address, token: Address) -> i128 {
+        Self::get_audit_summary(env, issuer, token)
+            .map(|summary| summary.total_revenue)
+            .unwrap_or(0)
+    }
+
     // ── Configurable rounding (#44) ───────────────────────────
 
     /// Set rounding mode for an offering's share calculations. Caller must be issuer.
@@ -800,6 +4447,7 @@ impl RevoraRevenueShare {
         mode: RoundingMode,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
         // Verify offering exists and issuer is current
         let current_issuer =
@@ -809,6 +4457,8 @@ impl RevoraRevenueShare {
             return Err(RevoraError::LimitReached);
         }
 
+        Self::require_config_unlocked(&env, &token)?;
+
         issuer.require_auth();
         let key = DataKey::RoundingMode(issuer, token);
         env.storage().persistent().set(&key, &mode);
@@ -861,7 +4511,8 @@ impl RevoraRevenueShare {
     ///
     /// Transfers `amount` of `payment_token` from `issuer` to the contract.
     /// The payment token is locked per offering on first deposit; subsequent
-    /// deposits must use the same payment token.
+    /// deposits must use the same payment token. The platform fee on `amount` stays in
+    /// the contract to fund `withdraw_fees`; holders claim from the net remainder.
     pub fn deposit_revenue(
         env: Env,
         issuer: Address,
@@ -870,7 +4521,9 @@ impl RevoraRevenueShare {
         amount: i128,
         period_id: u64,
     ) -> Result<(), RevoraError> {
+        Self::enter_non_reentrant(&env);
         Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
         // Verify offering exists and issuer is current
         let current_issuer =
@@ -881,7 +4534,7 @@ impl RevoraRevenueShare {
         }
 
         // Verify offering exists
-        let offering = Self::get_offering(env.clone(), issuer.clone(), token.clone())
+        let offering = Self::find_offering(env.clone(), issuer.clone(), token.clone())
             .ok_or(RevoraError::OfferingNotFound)?;
         if offering.payout_asset != payment_token {
             return Err(RevoraError::PayoutAssetMismatch);
@@ -909,8 +4562,11 @@ impl RevoraRevenueShare {
         let contract_addr = env.current_contract_address();
         token::Client::new(&env, &payment_token).transfer(&issuer, &contract_addr, &amount);
 
-        // Store period revenue
-        env.storage().persistent().set(&rev_key, &amount);
+        // Store period revenue, net of the platform fee
+        let fee = Self::bps_share_floor(amount, Self::get_platform_fee_bps(env.clone()));
+        let claimable = amount - fee;
+        env.storage().persistent().set(&rev_key, &claimable);
+        Self::adjust_claimable_liability(&env, &payment_token, claimable);
 
         // Store deposit timestamp for time-delayed claims (#27)
         let deposit_time = env.ledger().timestamp();
@@ -928,6 +4584,7 @@ impl RevoraRevenueShare {
             (EVENT_REV_DEPOSIT, issuer, token),
             (payment_token, amount, period_id),
         );
+        Self::exit_non_reentrant(&env);
         Ok(())
     }
 
@@ -942,6 +4599,7 @@ impl RevoraRevenueShare {
         share_bps: u32,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
         // Verify offering exists and issuer is current
         let current_issuer =
@@ -965,6 +4623,59 @@ impl RevoraRevenueShare {
         Ok(())
     }
 
+    /// Record the holdings snapshot (holder -> balance) for a period of an offering.
+    /// Overwrites any previous snapshot for the same period. Emits `hold_rec` with the
+    /// summed total supply. Fails with `EmptyHoldings` if the holdings sum to zero or less;
+    /// panics with `RevoraErrorExt::SupplyCapExceeded` if they sum to more than the
+    /// offering's `max_supply` (see `register_offering_with_cap`).
+    pub fn record_holdings(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+        holdings: Map<Address, i128>,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        // Verify offering exists and issuer is current
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+
+        issuer.require_auth();
+
+        let mut total_supply: i128 = 0;
+        for balance in holdings.values().iter() {
+            total_supply = total_supply.saturating_add(balance);
+        }
+        if total_supply <= 0 {
+            return Err(RevoraError::EmptyHoldings);
+        }
+        let max_supply = Self::get_offering_supply_cap(env.clone(), token.clone());
+        if max_supply > 0 && total_supply > max_supply {
+            panic_with_error!(&env, RevoraErrorExt::SupplyCapExceeded);
+        }
+
+        let key = DataKey::Holdings(token.clone(), period_id);
+        env.storage().persistent().set(&key, &holdings);
+
+        env.events().publish(
+            (EVENT_HOLDINGS_RECORDED, issuer, token),
+            (period_id, total_supply),
+        );
+        Ok(())
+    }
+
+    /// Return the holdings snapshot recorded for a period, if any.
+    pub fn get_holdings(env: Env, token: Address, period_id: u64) -> Option<Map<Address, i128>> {
+        let key = DataKey::Holdings(token, period_id);
+        env.storage().persistent().get(&key)
+    }
+
     /// Return a holder's share in basis points for an offering (0 if unset).
     pub fn get_holder_share(env: Env, token: Address, holder: Address) -> u32 {
         let key = DataKey::HolderShare(token, holder);
@@ -980,6 +4691,7 @@ impl RevoraRevenueShare {
     /// - Periods are processed in deposit order (sequential index).
     /// - Each holder's payout per period = `period_revenue * share_bps / 10000`.
     /// - The holder's claim index advances regardless of zero-value periods.
+    /// - Periods swept with `sweep_expired` pay nothing.
     /// - Capped at MAX_CLAIM_PERIODS (50) per transaction for gas safety.
     pub fn claim(
         env: Env,
@@ -987,6 +4699,7 @@ impl RevoraRevenueShare {
         token: Address,
         max_periods: u32,
     ) -> Result<i128, RevoraError> {
+        Self::enter_non_reentrant(&env);
         holder.require_auth();
 
         if Self::is_blacklisted(env.clone(), token.clone(), holder.clone()) {
@@ -1031,10 +4744,21 @@ impl RevoraRevenueShare {
             if delay_secs > 0 && now < deposit_time.saturating_add(delay_secs) {
                 break;
             }
-            let rev_key = DataKey::PeriodRevenue(token.clone(), period_id);
-            let revenue: i128 = env.storage().persistent().get(&rev_key).unwrap();
-            let payout = revenue * (share_bps as i128) / 10_000;
-            total_payout += payout;
+            let payout = Self::period_payout(&env, &token, period_id, share_bps);
+            if payout != 0 {
+                let claimed_key = DataKeyExt::PeriodClaimed(token.clone(), period_id);
+                let claimed: i128 = env.storage().persistent().get(&claimed_key).unwrap_or(0);
+                env.storage().persistent().set(
+                    &claimed_key,
+                    &Self::checked_amount(&env, claimed.checked_add(payout)),
+                );
+                let claimants_key = DataKeyExt::PeriodClaimants(token.clone(), period_id);
+                let claimants: u32 = env.storage().persistent().get(&claimants_key).unwrap_or(0);
+                env.storage()
+                    .persistent()
+                    .set(&claimants_key, &(claimants + 1));
+            }
+            total_payout = Self::checked_amount(&env, total_payout.checked_add(payout));
             claimed_periods.push_back(period_id);
             last_claimed_idx = i + 1;
         }
@@ -1053,6 +4777,7 @@ impl RevoraRevenueShare {
                 &holder,
                 &total_payout,
             );
+            Self::adjust_claimable_liability(&env, &payment_token, -total_payout);
         }
 
         // Advance claim index only for periods actually claimed (respecting delay)
@@ -1063,9 +4788,102 @@ impl RevoraRevenueShare {
             (total_payout, claimed_periods),
         );
 
+        Self::exit_non_reentrant(&env);
         Ok(total_payout)
     }
 
+    /// Claim progress of a deposited period: `(total_pool, claimed_so_far, num_claimants)`,
+    /// where `total_pool` is the deposited amount and `num_claimants` counts holders whose
+    /// claims paid out from it. All zero for a period with no deposit.
+    pub fn get_period_claim_status(env: Env, token: Address, period_id: u64) -> (i128, i128, u32) {
+        let store = env.storage().persistent();
+        let total_pool: i128 = store
+            .get(&DataKey::PeriodRevenue(token.clone(), period_id))
+            .unwrap_or(0);
+        let claimed: i128 = store
+            .get(&DataKeyExt::PeriodClaimed(token.clone(), period_id))
+            .unwrap_or(0);
+        let claimants: u32 = store
+            .get(&DataKeyExt::PeriodClaimants(token, period_id))
+            .unwrap_or(0);
+        (total_pool, claimed, claimants)
+    }
+
+    /// A holder's payout for one deposited period; 0 once the period has been swept.
+    fn period_payout(env: &Env, token: &Address, period_id: u64, share_bps: u32) -> i128 {
+        let swept_key = DataKeyExt::PeriodSwept(token.clone(), period_id);
+        if env.storage().persistent().has(&swept_key) {
+            return 0;
+        }
+        let rev_key = DataKey::PeriodRevenue(token.clone(), period_id);
+        let revenue: i128 = env.storage().persistent().get(&rev_key).unwrap();
+        revenue * (share_bps as i128) / 10_000
+    }
+
+    /// Return a period's unclaimed deposit to the issuer once its claim deadline
+    /// (`report_revenue_with_deadline`) has passed. Holders who have not claimed the
+    /// period by then get nothing for it. Returns the amount swept (0 if nothing was
+    /// left or the period was already swept) and emits `swept` with it.
+    ///
+    /// # Errors
+    /// - `OfferingNotFound`: `issuer` is not the offering's current issuer
+    /// - `DeadlineNotReached`: no deadline is set or the ledger has not reached it
+    /// - `ReportNotFound`: no revenue was deposited for the period
+    pub fn sweep_expired(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+    ) -> Result<i128, RevoraError> {
+        Self::enter_non_reentrant(&env);
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        let current_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if current_issuer != issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+        issuer.require_auth();
+
+        let deadline = Self::get_claim_deadline(env.clone(), token.clone(), period_id)
+            .ok_or(RevoraError::DeadlineNotReached)?;
+        if env.ledger().timestamp() < deadline {
+            return Err(RevoraError::DeadlineNotReached);
+        }
+        let rev_key = DataKey::PeriodRevenue(token.clone(), period_id);
+        let revenue: i128 = env
+            .storage()
+            .persistent()
+            .get(&rev_key)
+            .ok_or(RevoraError::ReportNotFound)?;
+
+        let swept_key = DataKeyExt::PeriodSwept(token.clone(), period_id);
+        if env.storage().persistent().has(&swept_key) {
+            Self::exit_non_reentrant(&env);
+            return Ok(0);
+        }
+        env.storage().persistent().set(&swept_key, &true);
+
+        let claimed_key = DataKeyExt::PeriodClaimed(token.clone(), period_id);
+        let claimed: i128 = env.storage().persistent().get(&claimed_key).unwrap_or(0);
+        let unclaimed = revenue.saturating_sub(claimed).max(0);
+        if unclaimed > 0 {
+            let pt_key = DataKey::PaymentToken(token.clone());
+            let payment_token: Address = env.storage().persistent().get(&pt_key).unwrap();
+            token::Client::new(&env, &payment_token).transfer(
+                &env.current_contract_address(),
+                &issuer,
+                &unclaimed,
+            );
+            Self::adjust_claimable_liability(&env, &payment_token, -unclaimed);
+        }
+
+        env.events()
+            .publish((EVENT_SWEPT, issuer, token), (period_id, unclaimed));
+        Self::exit_non_reentrant(&env);
+        Ok(unclaimed)
+    }
+
     /// Return unclaimed period IDs for a holder on an offering.
     pub fn get_pending_periods(env: Env, token: Address, holder: Address) -> Vec<u64> {
         let count_key = DataKey::PeriodCount(token.clone());
@@ -1110,9 +4928,7 @@ impl RevoraRevenueShare {
             if delay_secs > 0 && now < deposit_time.saturating_add(delay_secs) {
                 break;
             }
-            let rev_key = DataKey::PeriodRevenue(token.clone(), period_id);
-            let revenue: i128 = env.storage().persistent().get(&rev_key).unwrap();
-            total += revenue * (share_bps as i128) / 10_000;
+            total += Self::period_payout(&env, &token, period_id, share_bps);
         }
         total
     }
@@ -1127,6 +4943,7 @@ impl RevoraRevenueShare {
         delay_secs: u64,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
         // Verify offering exists and issuer is current
         let current_issuer =
@@ -1136,6 +4953,8 @@ impl RevoraRevenueShare {
             return Err(RevoraError::OfferingNotFound);
         }
 
+        Self::require_config_unlocked(&env, &token)?;
+
         issuer.require_auth();
         let key = DataKey::ClaimDelaySecs(token.clone());
         env.storage().persistent().set(&key, &delay_secs);
@@ -1206,6 +5025,128 @@ impl RevoraRevenueShare {
         env.storage().persistent().get(&key)
     }
 
+    /// Start handing the admin (owner) role to `pending_owner`. Nothing changes until
+    /// `pending_owner` calls `accept_ownership`; a new call replaces the pending owner.
+    /// Fails with `Unauthorized` unless `current_owner` is the admin.
+    /// Emits `own_start` with the pending owner.
+    pub fn transfer_ownership(
+        env: Env,
+        current_owner: Address,
+        pending_owner: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&current_owner) {
+            return Err(RevoraError::Unauthorized);
+        }
+        current_owner.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingAdmin, &pending_owner);
+        env.events()
+            .publish((EVENT_OWNERSHIP_STARTED, current_owner), pending_owner);
+        Ok(())
+    }
+
+    /// Complete an ownership transfer; `pending_owner` must sign. Fails with
+    /// `NoTransferPending` if none is pending and `UnauthorizedTransferAccept` if
+    /// `pending_owner` is not the proposed owner. Emits `own_done` with the new owner.
+    pub fn accept_ownership(env: Env, pending_owner: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        let pending: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(RevoraError::NoTransferPending)?;
+        if pending != pending_owner {
+            return Err(RevoraError::UnauthorizedTransferAccept);
+        }
+        pending_owner.require_auth();
+
+        let previous: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Admin, &pending_owner);
+        env.storage().persistent().remove(&DataKey::PendingAdmin);
+        env.events()
+            .publish((EVENT_OWNERSHIP_DONE, previous), pending_owner);
+        Ok(())
+    }
+
+    /// Owner proposed by `transfer_ownership` and not yet accepted, if any.
+    pub fn get_pending_owner(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::PendingAdmin)
+    }
+
+    // ── Roles ──────────────────────────────────────────────────
+
+    /// Grant `role` bitflags (`ROLE_*`) to `account`, keeping roles it already holds.
+    /// Only admin may call. Fails with `InvalidRole` if `role` is zero or has unknown bits.
+    pub fn grant_role(env: Env, account: Address, role: u32) -> Result<(), RevoraError> {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(RevoraError::Unauthorized)?;
+        admin.require_auth();
+        if role == 0 || role & !ALL_ROLES != 0 {
+            return Err(RevoraError::InvalidRole);
+        }
+
+        let mut roles: Map<Address, u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Roles)
+            .unwrap_or_else(|| Map::new(&env));
+        let current = roles.get(account.clone()).unwrap_or(0);
+        roles.set(account.clone(), current | role);
+        env.storage().persistent().set(&DataKey::Roles, &roles);
+
+        env.events().publish((EVENT_ROLE_GRANTED, account), role);
+        Ok(())
+    }
+
+    /// Revoke `role` bitflags from `account`. Only admin may call.
+    /// Fails with `InvalidRole` if `role` is zero or has unknown bits.
+    pub fn revoke_role(env: Env, account: Address, role: u32) -> Result<(), RevoraError> {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(RevoraError::Unauthorized)?;
+        admin.require_auth();
+        if role == 0 || role & !ALL_ROLES != 0 {
+            return Err(RevoraError::InvalidRole);
+        }
+
+        let mut roles: Map<Address, u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Roles)
+            .unwrap_or_else(|| Map::new(&env));
+        let remaining = roles.get(account.clone()).unwrap_or(0) & !role;
+        if remaining == 0 {
+            roles.remove(account.clone());
+        } else {
+            roles.set(account.clone(), remaining);
+        }
+        env.storage().persistent().set(&DataKey::Roles, &roles);
+
+        env.events().publish((EVENT_ROLE_REVOKED, account), role);
+        Ok(())
+    }
+
+    /// Return true if `account` holds every bit in `role` (false for `role == 0`).
+    pub fn has_role(env: Env, account: Address, role: u32) -> bool {
+        if role == 0 {
+            return false;
+        }
+        let roles: Option<Map<Address, u32>> = env.storage().persistent().get(&DataKey::Roles);
+        let granted = roles.and_then(|r| r.get(account)).unwrap_or(0);
+        granted & role == role
+    }
+
     /// Freeze the contract: no further state-changing operations allowed. Only admin may call.
     /// Emits event. Claim and read-only functions remain allowed.
     pub fn freeze(env: Env) -> Result<(), RevoraError> {
@@ -1222,6 +5163,65 @@ impl RevoraRevenueShare {
         Ok(())
     }
 
+    /// Retire the contract ahead of a migration: afterwards every offering registration
+    /// and revenue report fails with `RevoraErrorExt::ContractDeprecated`, while reads,
+    /// deposits, claims and withdrawals keep working so holders can be paid out.
+    /// Irreversible; calling again is a no-op. Fails with `Unauthorized` unless `owner`
+    /// is the admin. Emits `deprecate`.
+    pub fn mark_deprecated(env: Env, owner: Address) -> Result<(), RevoraError> {
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&owner) {
+            return Err(RevoraError::Unauthorized);
+        }
+        owner.require_auth();
+
+        if Self::is_deprecated(env.clone()) {
+            return Ok(());
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::Deprecated, &true);
+        env.events().publish((EVENT_DEPRECATED, owner), true);
+        Ok(())
+    }
+
+    /// Return true once the contract has been deprecated (`mark_deprecated`).
+    pub fn is_deprecated(env: Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKeyExt::Deprecated)
+            .unwrap_or(false)
+    }
+
+    /// Replace the contract's Wasm with `new_wasm_hash`, keeping storage and address.
+    /// Only the admin may call; fails with `Unauthorized` otherwise (including when no
+    /// admin is set). Blocked once the contract is frozen. Emits `upgraded` with
+    /// `(new_wasm_hash, CONTRACT_VERSION)`, the version of the code being replaced.
+    pub fn upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        let admin: Option<Address> = env.storage().persistent().get(&DataKey::Admin);
+        if admin.as_ref() != Some(&caller) {
+            return Err(RevoraError::Unauthorized);
+        }
+        caller.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        env.events()
+            .publish((EVENT_UPGRADED, caller), (new_wasm_hash, CONTRACT_VERSION));
+        Ok(())
+    }
+
+    /// Interface version of the deployed code (`CONTRACT_VERSION`), so integrators can
+    /// branch on it across deployments.
+    pub fn get_contract_version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
     /// Return true if the contract is frozen.
     pub fn is_frozen(env: Env) -> bool {
         env.storage()
@@ -1265,44 +5265,25 @@ impl RevoraRevenueShare {
         Ok(())
     }
 
-    /// Accept a pending issuer transfer. Only the proposed new issuer may call this.
-    /// Completes the two-step transfer and grants full issuer control to the new address.
-    pub fn accept_issuer_transfer(env: Env, token: Address) -> Result<(), RevoraError> {
-        Self::require_not_frozen(&env)?;
-
-        // Get pending transfer
-        let pending_key = DataKey::PendingIssuerTransfer(token.clone());
-        let new_issuer: Address = env
-            .storage()
-            .persistent()
-            .get(&pending_key)
-            .ok_or(RevoraError::NoTransferPending)?;
-
-        // Only the proposed new issuer can accept
-        new_issuer.require_auth();
-
-        // Get current issuer
-        let old_issuer =
-            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
-
+    /// Move `token`'s offering from `old_issuer`'s list to the end of `new_issuer`'s list
+    /// and update the reverse lookup. The old slot is filled by the old issuer's last offering.
+    fn move_offering(
+        env: &Env,
+        token: &Address,
+        old_issuer: &Address,
+        new_issuer: &Address,
+    ) -> Result<(), RevoraError> {
         // Update the offering's issuer field in storage
         // We need to find and update the offering
-        let offering = Self::get_offering(env.clone(), old_issuer.clone(), token.clone())
+        let offering = Self::find_offering(env.clone(), old_issuer.clone(), token.clone())
             .ok_or(RevoraError::OfferingNotFound)?;
-
-        // Find the index of this offering
-        let count = Self::get_offering_count(env.clone(), old_issuer.clone());
-        let mut found_index: Option<u32> = None;
-        for i in 0..count {
-            let item_key = DataKey::OfferItem(old_issuer.clone(), i);
-            let stored_offering: Offering = env.storage().persistent().get(&item_key).unwrap();
-            if stored_offering.token == token {
-                found_index = Some(i);
-                break;
-            }
+        let mut issuers = Self::get_token_issuers(env, token);
+        if new_issuer != old_issuer && issuers.contains(new_issuer) {
+            return Err(RevoraError::DuplicateOffering);
         }
 
-        let index = found_index.ok_or(RevoraError::OfferingNotFound)?;
+        let index = Self::find_offering_index(env, old_issuer, token)
+            .ok_or(RevoraError::OfferingNotFound)?;
 
         // Update the offering with new issuer
         let updated_offering = Offering {
@@ -1310,6 +5291,9 @@ impl RevoraRevenueShare {
             token: token.clone(),
             revenue_share_bps: offering.revenue_share_bps,
             payout_asset: offering.payout_asset,
+            status: offering.status,
+            decimals: offering.decimals,
+            max_supply: offering.max_supply,
         };
 
         // Remove from old issuer's storage
@@ -1329,10 +5313,7 @@ impl RevoraRevenueShare {
         }
 
         // Decrement old issuer's count
-        let old_count_key = DataKey::OfferCount(old_issuer.clone());
-        env.storage()
-            .persistent()
-            .set(&old_count_key, &(old_count - 1));
+        Self::set_offering_count(env, old_issuer, old_count - 1);
 
         // Add to new issuer's storage
         let new_count = Self::get_offering_count(env.clone(), new_issuer.clone());
@@ -1342,16 +5323,37 @@ impl RevoraRevenueShare {
             .set(&new_item_key, &updated_offering);
 
         // Increment new issuer's count
-        let new_count_key = DataKey::OfferCount(new_issuer.clone());
-        env.storage()
-            .persistent()
-            .set(&new_count_key, &(new_count + 1));
+        Self::set_offering_count(env, new_issuer, new_count + 1);
 
-        // Update reverse lookup
-        let issuer_lookup_key = DataKey::OfferingIssuer(token.clone());
-        env.storage()
+        // Update reverse lookup, keeping the offering's place in the token's issuer index
+        if let Some(i) = issuers.first_index_of(old_issuer) {
+            issuers.set(i, new_issuer.clone());
+        }
+        Self::set_token_issuers(env, token, &issuers);
+        Ok(())
+    }
+
+    /// Accept a pending issuer transfer. Only the proposed new issuer may call this.
+    /// Completes the two-step transfer and grants full issuer control to the new address.
+    pub fn accept_issuer_transfer(env: Env, token: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+
+        // Get pending transfer
+        let pending_key = DataKey::PendingIssuerTransfer(token.clone());
+        let new_issuer: Address = env
+            .storage()
             .persistent()
-            .set(&issuer_lookup_key, &new_issuer);
+            .get(&pending_key)
+            .ok_or(RevoraError::NoTransferPending)?;
+
+        // Only the proposed new issuer can accept
+        new_issuer.require_auth();
+
+        // Get current issuer
+        let old_issuer =
+            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+
+        Self::move_offering(&env, &token, &old_issuer, &new_issuer)?;
 
         // Clear pending transfer
         env.storage().persistent().remove(&pending_key);
@@ -1364,6 +5366,71 @@ impl RevoraRevenueShare {
         Ok(())
     }
 
+    /// Move an offering to `new_issuer` in one step. Only the current issuer may call this.
+    /// The offering is appended to `new_issuer`'s list (existing offerings are kept) and
+    /// its metadata and metadata version move with it. Any pending two-step transfer
+    /// proposal for the offering is discarded. Fails with `DuplicateOffering` if
+    /// `new_issuer` already has an offering for `token`.
+    pub fn transfer_issuer(
+        env: Env,
+        current_issuer: Address,
+        new_issuer: Address,
+        token: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let issuer = Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        if issuer != current_issuer {
+            return Err(RevoraError::OfferingNotFound);
+        }
+
+        current_issuer.require_auth();
+
+        Self::move_offering(&env, &token, &current_issuer, &new_issuer)?;
+
+        let old_meta_key = DataKey::OfferingMetadata(current_issuer.clone(), token.clone());
+        if let Some(metadata) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, String>(&old_meta_key)
+        {
+            env.storage().persistent().remove(&old_meta_key);
+            let new_meta_key = DataKey::OfferingMetadata(new_issuer.clone(), token.clone());
+            env.storage().persistent().set(&new_meta_key, &metadata);
+        }
+        let old_version_key = DataKey::MetadataVersion(current_issuer.clone(), token.clone());
+        if let Some(version) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u32>(&old_version_key)
+        {
+            env.storage().persistent().remove(&old_version_key);
+            let new_version_key = DataKey::MetadataVersion(new_issuer.clone(), token.clone());
+            env.storage().persistent().set(&new_version_key, &version);
+        }
+        let old_hash_key = DataKey::MetadataHash(current_issuer.clone(), token.clone());
+        if let Some(hash) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, BytesN<32>>(&old_hash_key)
+        {
+            env.storage().persistent().remove(&old_hash_key);
+            let new_hash_key = DataKey::MetadataHash(new_issuer.clone(), token.clone());
+            env.storage().persistent().set(&new_hash_key, &hash);
+        }
+
+        let pending_key = DataKey::PendingIssuerTransfer(token.clone());
+        env.storage().persistent().remove(&pending_key);
+
+        env.events().publish(
+            (EVENT_ISSUER_TRANSFERRED, token),
+            (current_issuer, new_issuer),
+        );
+
+        Ok(())
+    }
+
     /// Cancel a pending issuer transfer. Only the current issuer may call this.
     pub fn cancel_issuer_transfer(env: Env, token: Address) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
@@ -1430,7 +5497,7 @@ impl RevoraRevenueShare {
             panic!("total_supply cannot be zero");
         }
 
-        let offering = Self::get_offering(env.clone(), issuer.clone(), token.clone())
+        let offering = Self::find_offering(env.clone(), issuer.clone(), token.clone())
             .expect("offering not found");
 
         if Self::is_blacklisted(env.clone(), token.clone(), holder.clone()) {
@@ -1471,7 +5538,136 @@ impl RevoraRevenueShare {
             ),
         );
 
-        payout
+        payout
+    }
+
+    /// Split the distributable share of a reported period pro-rata across `holders`.
+    ///
+    /// The pot is `net_amount * revenue_share_bps / BPS_DENOMINATOR` (floor), where
+    /// `net_amount` is the reported amount minus the platform fee; an unreported period or
+    /// a non-positive report distributes nothing. Blacklisted holders
+    /// and non-positive holdings are kept in the result with a payout of 0 and excluded from
+    /// the supply used for the split.
+    ///
+    /// Rounding: each eligible holder receives `floor(holding * pot / eligible_supply)`; the
+    /// remainder left by flooring goes to the largest eligible holder (first in map order
+    /// on ties). The sum of payouts therefore equals the pot exactly and never exceeds it.
+    pub fn compute_distribution(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+        holders: Map<Address, i128>,
+    ) -> Result<Map<Address, i128>, RevoraError> {
+        let pot = Self::distribution_pot(&env, issuer, token.clone(), period_id)?;
+
+        let mut payouts = Map::new(&env);
+        let mut eligible_supply: i128 = 0;
+        let mut largest: Option<(Address, i128)> = None;
+        for (holder, holding) in holders.iter() {
+            payouts.set(holder.clone(), 0);
+            if holding <= 0 || Self::is_blacklisted(env.clone(), token.clone(), holder.clone()) {
+                continue;
+            }
+            eligible_supply = eligible_supply
+                .checked_add(holding)
+                .expect("holdings overflow");
+            if largest.as_ref().is_none_or(|(_, max)| holding > *max) {
+                largest = Some((holder, holding));
+            }
+        }
+
+        if pot == 0 || eligible_supply == 0 {
+            return Ok(payouts);
+        }
+
+        let mut distributed: i128 = 0;
+        for (holder, holding) in holders.iter() {
+            if holding <= 0 || Self::is_blacklisted(env.clone(), token.clone(), holder.clone()) {
+                continue;
+            }
+            let payout = holding.checked_mul(pot).expect("distribution overflow") / eligible_supply;
+            distributed += payout;
+            payouts.set(holder, payout);
+        }
+
+        if let Some((holder, _)) = largest {
+            let remainder = pot - distributed;
+            let current = payouts.get(holder.clone()).unwrap_or(0);
+            payouts.set(holder, current + remainder);
+        }
+        Ok(payouts)
+    }
+
+    /// `compute_distribution` for one chunk of a holder set too large for a single call.
+    /// Each eligible holder in `holders_chunk` receives `floor(holding * pot / total_supply)`
+    /// of the same pot; blacklisted holders and non-positive holdings get 0.
+    ///
+    /// The caller must pass the same `total_supply` for every chunk of a period: the summed
+    /// eligible holdings of the whole set (blacklisted holders excluded), so that chunks
+    /// combine into a consistent split. Unlike the single-shot call, the flooring remainder
+    /// (less than one unit per holder) is not assigned to anyone, so the chunks together may
+    /// fall slightly short of the pot. Fails with `EmptyHoldings` if `total_supply <= 0`.
+    pub fn compute_distribution_chunk(
+        env: Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+        holders_chunk: Map<Address, i128>,
+        total_supply: i128,
+    ) -> Result<Map<Address, i128>, RevoraError> {
+        if total_supply <= 0 {
+            return Err(RevoraError::EmptyHoldings);
+        }
+        let pot = Self::distribution_pot(&env, issuer, token.clone(), period_id)?;
+
+        let mut payouts = Map::new(&env);
+        for (holder, holding) in holders_chunk.iter() {
+            let eligible =
+                holding > 0 && !Self::is_blacklisted(env.clone(), token.clone(), holder.clone());
+            let payout = if eligible {
+                holding.checked_mul(pot).expect("distribution overflow") / total_supply
+            } else {
+                0
+            };
+            payouts.set(holder, payout);
+        }
+        Ok(payouts)
+    }
+
+    /// Holders' pot for a reported period: the offering's share of the reported amount net
+    /// of the platform fee (floor); 0 for an unreported period or a non-positive report.
+    fn distribution_pot(
+        env: &Env,
+        issuer: Address,
+        token: Address,
+        period_id: u64,
+    ) -> Result<i128, RevoraError> {
+        let offering = Self::find_offering(env.clone(), issuer.clone(), token.clone())
+            .ok_or(RevoraError::OfferingNotFound)?;
+        let amount = Self::get_revenue_report(env.clone(), issuer, token, period_id).unwrap_or(0);
+        Ok(Self::net_distribution_pot(
+            env,
+            amount,
+            offering.revenue_share_bps,
+        ))
+    }
+
+    /// Holders' pot for a reported `amount`: the offering's share of the amount net of the
+    /// platform fee, matching the `distributable` field of `rev_repa`.
+    fn net_distribution_pot(env: &Env, amount: i128, revenue_share_bps: u32) -> i128 {
+        let fee = Self::bps_share_floor(amount, Self::get_platform_fee_bps(env.clone()));
+        Self::bps_share_floor(amount - fee, revenue_share_bps)
+    }
+
+    /// `floor(amount * bps / BPS_DENOMINATOR)` for non-negative amounts without
+    /// intermediate overflow; non-positive amounts yield 0.
+    fn bps_share_floor(amount: i128, bps: u32) -> i128 {
+        if amount <= 0 {
+            return 0;
+        }
+        let bps = bps as i128;
+        (amount / BPS_DENOMINATOR) * bps + (amount % BPS_DENOMINATOR) * bps / BPS_DENOMINATOR
     }
 
     /// Calculate the total distributable revenue for an offering.
@@ -1484,7 +5680,7 @@ impl RevoraRevenueShare {
         total_revenue: i128,
     ) -> i128 {
         let offering =
-            Self::get_offering(env, issuer, token).expect("offering not found for token");
+            Self::find_offering(env, issuer, token).expect("offering not found for token");
 
         if total_revenue == 0 {
             return 0;
@@ -1495,75 +5691,611 @@ impl RevoraRevenueShare {
             .expect("division overflow")
     }
 
+    /// Read-only invariant check for an off-chain distribution of `period_id`'s revenue.
+    /// Returns true only if every recipient is not blacklisted, every amount is
+    /// non-negative, and the total matches the period's distributable amount (the same
+    /// net-of-fee holder share carried by `rev_repa`). Because each holder's payout is
+    /// floored, the total may fall short by at most one unit per recipient but never
+    /// exceed it. Returns false (never panics) on any violation or unknown offering/period.
+    pub fn verify_distribution_sums(
+        env: Env,
+        token: Address,
+        period_id: u64,
+        distribution: Map<Address, i128>,
+    ) -> bool {
+        let issuer = match Self::get_current_issuer(&env, &token) {
+            Some(issuer) => issuer,
+            None => return false,
+        };
+        let offering = match Self::find_offering(env.clone(), issuer.clone(), token.clone()) {
+            Some(offering) => offering,
+            None => return false,
+        };
+        let amount = match Self::get_revenue_report(env.clone(), issuer, token.clone(), period_id) {
+            Some(amount) => amount,
+            None => return false,
+        };
+        let distributable = Self::net_distribution_pot(&env, amount, offering.revenue_share_bps);
+
+        let mut total: i128 = 0;
+        for (recipient, share) in distribution.iter() {
+            if share < 0 || Self::is_blacklisted(env.clone(), token.clone(), recipient) {
+                return false;
+            }
+            total = match total.checked_add(share) {
+                Some(total) => total,
+                None => return false,
+            };
+        }
+        total <= distributable && distributable - total <= distribution.len() as i128
+    }
+
     // ── Per-offering metadata storage (#8) ─────────────────────
 
-    /// Maximum allowed length for metadata strings (256 bytes).
+    /// Default maximum length for metadata strings (256 bytes).
     /// Supports IPFS CIDs (46 chars), URLs, and content hashes.
     const MAX_METADATA_LENGTH: usize = 256;
 
+    /// Hard ceiling for the admin-configurable metadata length cap (8 KiB).
+    const MAX_METADATA_LENGTH_CEILING: u32 = 8192;
+
+    /// Override the maximum metadata length (bytes) used by `set_offering_metadata`.
+    /// Only admin may call. Fails with `InvalidMetadataCap` if `new_max` is zero or above
+    /// 8192. Emits `meta_cap` with the new cap.
+    pub fn set_max_metadata_length(env: Env, new_max: u32) -> Result<(), RevoraError> {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .ok_or(RevoraError::Unauthorized)?;
+        admin.require_auth();
+        if new_max == 0 || new_max > Self::MAX_METADATA_LENGTH_CEILING {
+            return Err(RevoraError::InvalidMetadataCap);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::MaxMetadataLength, &new_max);
+        env.events()
+            .publish((EVENT_METADATA_CAP_SET, admin), new_max);
+        Ok(())
+    }
+
+    /// Maximum metadata length in bytes: the admin override if set, else 256.
+    pub fn get_max_metadata_length(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MaxMetadataLength)
+            .unwrap_or(Self::MAX_METADATA_LENGTH as u32)
+    }
+
     /// Set or update metadata reference for an offering.
     ///
-    /// Only callable by the current issuer of the offering.
+    /// Callable by the current issuer of the offering, the issuer's delegated admin
+    /// (see `set_issuer_admin` and `add_admin`), or a `ROLE_METADATA_MANAGER` holder.
+    /// Metadata is stored under the current issuer.
     /// Metadata can be an IPFS hash (e.g., "Qm..."), HTTPS URI, or any reference string.
-    /// Maximum length: 256 bytes.
+    /// Maximum length: 256 bytes unless raised with `set_max_metadata_length`.
     ///
-    /// Emits `EVENT_METADATA_SET` on first set, `EVENT_METADATA_UPDATED` on subsequent updates.
+    /// Each change bumps the offering's metadata version (see `get_metadata_version`).
+    /// Emits `EVENT_METADATA_SET` on first set, `EVENT_METADATA_UPDATED` with
+    /// `(metadata, version)` on subsequent updates. Re-sending the stored value (with no
+    /// content hash or content type on record) succeeds without writing, bumping the
+    /// version or emitting.
     ///
     /// # Errors
-    /// - `OfferingNotFound`: offering doesn't exist or caller is not the current issuer
-    /// - `MetadataTooLarge`: metadata string exceeds MAX_METADATA_LENGTH
+    /// - `OfferingNotFound`: offering doesn't exist
+    /// - `Unauthorized`: caller is not the current issuer, the issuer's admin or a metadata manager
+    /// - `EmptyMetadata`: metadata string is empty
+    /// - `MetadataTooLarge`: metadata string exceeds `get_max_metadata_length`
     /// - `ContractFrozen`: contract is frozen
     pub fn set_offering_metadata(
         env: Env,
-        issuer: Address,
+        caller: Address,
         token: Address,
         metadata: String,
     ) -> Result<(), RevoraError> {
         Self::require_not_frozen(&env)?;
-        Self::require_not_paused(&env);
+        Self::require_not_paused(&env)?;
 
-        // Verify offering exists and issuer is current
-        let current_issuer =
-            Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        // Verify offering exists and caller may act for its current issuer
+        let issuer = Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        Self::require_issuer_or_admin(&env, &caller, &issuer, ROLE_METADATA_MANAGER)?;
 
-        if current_issuer != issuer {
-            return Err(RevoraError::OfferingNotFound);
-        }
+        Self::validate_metadata(&env, &metadata)?;
+        Self::write_metadata(&env, issuer, token, metadata, None, None);
+        Ok(())
+    }
 
-        issuer.require_auth();
+    /// `set_offering_metadata` plus a 32-byte content hash (e.g. SHA-256 of the document)
+    /// so investors can check the off-chain content was not tampered with. Emits
+    /// `meta_hash` with `(content_hash, version)` after `meta_set`/`meta_upd`. A later
+    /// update without a hash clears the stored one, since it would no longer match.
+    /// Same authorization and errors as `set_offering_metadata`.
+    pub fn set_offering_metadata_with_hash(
+        env: Env,
+        caller: Address,
+        token: Address,
+        metadata: String,
+        content_hash: BytesN<32>,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let issuer = Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        Self::require_issuer_or_admin(&env, &caller, &issuer, ROLE_METADATA_MANAGER)?;
+
+        Self::validate_metadata(&env, &metadata)?;
+        Self::write_metadata(&env, issuer, token, metadata, Some(content_hash), None);
+        Ok(())
+    }
+
+    /// Content hash stored with the offering's metadata, if any.
+    pub fn get_metadata_hash(env: Env, issuer: Address, token: Address) -> Option<BytesN<32>> {
+        let key = DataKey::MetadataHash(issuer, token);
+        env.storage().persistent().get(&key)
+    }
+
+    /// `set_offering_metadata` tagged with what the URI points to (e.g. `json`, `image`,
+    /// `ipfs_dir`), so consumers know how to fetch it. Emits `meta_type` with
+    /// `(content_type, version)` after `meta_set`/`meta_upd`. A later update without a
+    /// type clears it back to `METADATA_TYPE_UNKNOWN`. Same authorization and errors as
+    /// `set_offering_metadata`.
+    pub fn set_offering_metadata_with_type(
+        env: Env,
+        caller: Address,
+        token: Address,
+        metadata: String,
+        content_type: Symbol,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
 
-        // Validate metadata length
+        let issuer = Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        Self::require_issuer_or_admin(&env, &caller, &issuer, ROLE_METADATA_MANAGER)?;
+
+        Self::validate_metadata(&env, &metadata)?;
+        Self::write_metadata(&env, issuer, token, metadata, None, Some(content_type));
+        Ok(())
+    }
+
+    /// `(uri, content_type, version)` of the metadata stored for the offering registered as
+    /// `offering_id`; `content_type` is `METADATA_TYPE_UNKNOWN` if it was set without one.
+    /// `None` if the id is unknown or the offering has no metadata of its own.
+    pub fn get_metadata_full(
+        env: Env,
+        issuer: Address,
+        offering_id: String,
+    ) -> Option<(String, Symbol, u32)> {
+        let store = env.storage().persistent();
+        let token: Address = store.get(&DataKey::OfferingById(issuer.clone(), offering_id))?;
+        let metadata: String =
+            store.get(&DataKey::OfferingMetadata(issuer.clone(), token.clone()))?;
+        let content_type: Symbol = store
+            .get(&DataKeyExt::MetadataContentType(token.clone()))
+            .unwrap_or(METADATA_TYPE_UNKNOWN);
+        let version = Self::get_metadata_version(env, issuer, token);
+        Some((metadata, content_type, version))
+    }
+
+    /// Check a metadata reference is non-empty and within the current length cap.
+    fn validate_metadata(env: &Env, metadata: &String) -> Result<(), RevoraError> {
         let metadata_bytes = metadata.len();
-        if metadata_bytes > Self::MAX_METADATA_LENGTH as u32 {
+        if metadata_bytes == 0 {
+            return Err(RevoraError::EmptyMetadata);
+        }
+        if metadata_bytes > Self::get_max_metadata_length(env.clone()) {
             return Err(RevoraError::MetadataTooLarge);
         }
+        Ok(())
+    }
 
+    /// Store validated metadata (and its content hash, clearing any old one when `None`),
+    /// bump its version and emit `meta_set` or `meta_upd`, then `meta_hash` if hashed.
+    /// Does nothing if both already match what is stored.
+    fn write_metadata(
+        env: &Env,
+        issuer: Address,
+        token: Address,
+        metadata: String,
+        content_hash: Option<BytesN<32>>,
+        content_type: Option<Symbol>,
+    ) {
         let key = DataKey::OfferingMetadata(issuer.clone(), token.clone());
-        let is_update = env.storage().persistent().has(&key);
+        let hash_key = DataKey::MetadataHash(issuer.clone(), token.clone());
+        let type_key = DataKeyExt::MetadataContentType(token.clone());
+        let stored: Option<String> = env.storage().persistent().get(&key);
+        let is_update = stored.is_some();
+
+        // Resubmitting what is already stored is a no-op: no write, no version bump, no event
+        if stored.as_ref() == Some(&metadata)
+            && env
+                .storage()
+                .persistent()
+                .get::<DataKey, BytesN<32>>(&hash_key)
+                == content_hash
+            && env
+                .storage()
+                .persistent()
+                .get::<DataKeyExt, Symbol>(&type_key)
+                == content_type
+        {
+            return;
+        }
 
         // Store metadata
         env.storage().persistent().set(&key, &metadata);
 
+        let version_key = DataKey::MetadataVersion(issuer.clone(), token.clone());
+        let version: u32 = env
+            .storage()
+            .persistent()
+            .get(&version_key)
+            .unwrap_or(0u32)
+            .saturating_add(1);
+        env.storage().persistent().set(&version_key, &version);
+
         // Emit appropriate event
         if is_update {
-            env.events()
-                .publish((EVENT_METADATA_UPDATED, issuer, token), metadata);
+            env.events().publish(
+                (EVENT_METADATA_UPDATED, issuer.clone(), token.clone()),
+                (metadata, version),
+            );
         } else {
-            env.events()
-                .publish((EVENT_METADATA_SET, issuer, token), metadata);
+            env.events().publish(
+                (EVENT_METADATA_SET, issuer.clone(), token.clone()),
+                metadata,
+            );
+        }
+
+        match content_hash {
+            Some(hash) => {
+                env.storage().persistent().set(&hash_key, &hash);
+                env.events().publish(
+                    (EVENT_METADATA_HASH, issuer.clone(), token.clone()),
+                    (hash, version),
+                );
+            }
+            None => env.storage().persistent().remove(&hash_key),
+        }
+        match content_type {
+            Some(content_type) => {
+                env.storage().persistent().set(&type_key, &content_type);
+                env.events().publish(
+                    (EVENT_METADATA_TYPE, issuer, token),
+                    (content_type, version),
+                );
+            }
+            None => env.storage().persistent().remove(&type_key),
+        }
+    }
+
+    /// Re-key an offering from `old_id` to `new_id` (ids from `register_offering_with_id`).
+    /// Metadata and its version counter stay with the offering, so no history is lost.
+    /// Emits `meta_ren` with `(old_id, new_id)`.
+    ///
+    /// # Errors
+    /// - `MetadataNotFound`: `old_id` is not registered for the issuer
+    /// - `DuplicateOffering`: `new_id` is already in use
+    pub fn rename_offering_id(
+        env: Env,
+        issuer: Address,
+        old_id: String,
+        new_id: String,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        issuer.require_auth();
+
+        let old_key = DataKey::OfferingById(issuer.clone(), old_id.clone());
+        let token: Address = env
+            .storage()
+            .persistent()
+            .get(&old_key)
+            .ok_or(RevoraError::MetadataNotFound)?;
+        let new_key = DataKey::OfferingById(issuer.clone(), new_id.clone());
+        if env.storage().persistent().has(&new_key) {
+            return Err(RevoraError::DuplicateOffering);
+        }
+
+        env.storage().persistent().remove(&old_key);
+        env.storage().persistent().set(&new_key, &token);
+
+        env.events()
+            .publish((EVENT_METADATA_RENAMED, issuer), (old_id, new_id));
+        Ok(())
+    }
+
+    /// Set metadata for many of the issuer's offerings at once, keyed by the
+    /// `offering_id` given at `register_offering_with_id`. Requires issuer auth once.
+    /// Every entry is resolved and validated before anything is written, so the batch
+    /// is atomic. Emits `meta_set` or `meta_upd` per entry, like `set_offering_metadata`.
+    ///
+    /// # Errors
+    /// - `BatchTooLarge`: more than MAX_PAGE_LIMIT (20) entries
+    /// - `OfferingNotFound`: an offering_id is unknown or its offering moved to another issuer
+    /// - `EmptyMetadata` / `MetadataTooLarge`: an entry fails validation
+    pub fn set_metadata_batch(
+        env: Env,
+        issuer: Address,
+        entries: Vec<(String, String)>,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        issuer.require_auth();
+
+        if entries.len() > MAX_PAGE_LIMIT {
+            return Err(RevoraError::BatchTooLarge);
+        }
+
+        let mut resolved: Vec<(Address, String)> = Vec::new(&env);
+        for (offering_id, metadata) in entries.iter() {
+            let id_key = DataKey::OfferingById(issuer.clone(), offering_id);
+            let token: Address = env
+                .storage()
+                .persistent()
+                .get(&id_key)
+                .ok_or(RevoraError::OfferingNotFound)?;
+            if Self::get_current_issuer(&env, &token).as_ref() != Some(&issuer) {
+                return Err(RevoraError::OfferingNotFound);
+            }
+            Self::validate_metadata(&env, &metadata)?;
+            resolved.push_back((token, metadata));
+        }
+
+        for (token, metadata) in resolved.iter() {
+            Self::write_metadata(&env, issuer.clone(), token, metadata, None, None);
         }
+        Ok(())
+    }
+
+    /// Delete the metadata reference for an offering.
+    ///
+    /// Same authorization as `set_offering_metadata`. Emits `EVENT_METADATA_DELETED`
+    /// with `(metadata, version)` of the removed entry so subscribers can reconcile
+    /// caches from the single event.
+    ///
+    /// # Errors
+    /// - `OfferingNotFound`: offering doesn't exist
+    /// - `Unauthorized`: caller is not the current issuer, the issuer's admin or a metadata manager
+    /// - `MetadataNotFound`: no metadata is stored for the offering
+    /// - `ContractFrozen`: contract is frozen
+    pub fn delete_offering_metadata(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+
+        let issuer = Self::get_current_issuer(&env, &token).ok_or(RevoraError::OfferingNotFound)?;
+        Self::require_issuer_or_admin(&env, &caller, &issuer, ROLE_METADATA_MANAGER)?;
+
+        let key = DataKey::OfferingMetadata(issuer.clone(), token.clone());
+        let metadata: String = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(RevoraError::MetadataNotFound)?;
+        env.storage().persistent().remove(&key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::MetadataHash(issuer.clone(), token.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKeyExt::MetadataContentType(token.clone()));
+        let version = Self::get_metadata_version(env.clone(), issuer.clone(), token.clone());
 
+        env.events()
+            .publish((EVENT_METADATA_DELETED, issuer, token), (metadata, version));
         Ok(())
     }
 
     /// Retrieve metadata reference for an offering.
     ///
-    /// Returns `None` if no metadata has been set for this offering.
+    /// Falls back to the issuer's `set_default_metadata` URI when the offering has none;
+    /// returns `None` if neither is set.
     pub fn get_offering_metadata(env: Env, issuer: Address, token: Address) -> Option<String> {
-        let key = DataKey::OfferingMetadata(issuer, token);
+        Self::resolve_metadata(&env, &issuer, &token).map(|(metadata, _)| metadata)
+    }
+
+    /// Set the metadata URI returned for the issuer's offerings that have none of their own.
+    /// Validated like offering metadata. Emits `meta_dflt` with the URI.
+    pub fn set_default_metadata(env: Env, issuer: Address, uri: String) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        issuer.require_auth();
+
+        Self::validate_metadata(&env, &uri)?;
+        env.storage()
+            .persistent()
+            .set(&DataKeyExt::DefaultMetadata(issuer.clone()), &uri);
+        env.events().publish((EVENT_METADATA_DEFAULT, issuer), uri);
+        Ok(())
+    }
+
+    /// Metadata for the offering registered as `offering_id`, with its source:
+    /// `METADATA_SOURCE_OFFERING` for the offering's own URI, `METADATA_SOURCE_DEFAULT`
+    /// for the issuer default. `None` if the id is unknown or neither is set.
+    pub fn get_metadata_resolved(
+        env: Env,
+        issuer: Address,
+        offering_id: String,
+    ) -> Option<(String, u32)> {
+        let token: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OfferingById(issuer.clone(), offering_id))?;
+        Self::resolve_metadata(&env, &issuer, &token)
+    }
+
+    /// Offering-specific metadata, else the issuer default while `issuer` still owns `token`.
+    fn resolve_metadata(env: &Env, issuer: &Address, token: &Address) -> Option<(String, u32)> {
+        let store = env.storage().persistent();
+        let key = DataKey::OfferingMetadata(issuer.clone(), token.clone());
+        if let Some(metadata) = store.get::<DataKey, String>(&key) {
+            return Some((metadata, METADATA_SOURCE_OFFERING));
+        }
+        if Self::get_current_issuer(env, token).as_ref() != Some(issuer) {
+            return None;
+        }
+        store
+            .get::<DataKeyExt, String>(&DataKeyExt::DefaultMetadata(issuer.clone()))
+            .map(|metadata| (metadata, METADATA_SOURCE_DEFAULT))
+    }
+
+    /// Number of times metadata has been set for an offering (0 if never).
+    /// Keeps counting across `delete_offering_metadata`, so it never goes backwards.
+    pub fn get_metadata_version(env: Env, issuer: Address, token: Address) -> u32 {
+        let key = DataKey::MetadataVersion(issuer, token);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Paginate `(token, metadata)` pairs for the issuer's offerings that have metadata.
+    /// Entries follow offering registration order, so pages are stable across calls
+    /// as long as no metadata is added or deleted in between. Same `start`/`limit`
+    /// conventions as `get_offerings_page`.
+    pub fn get_all_metadata_page(
+        env: Env,
+        issuer: Address,
+        start: u32,
+        limit: u32,
+    ) -> (Vec<(Address, String)>, Option<u32>) {
+        let offering_count = Self::get_offering_count(env.clone(), issuer.clone());
+        let mut entries: Vec<(Address, String)> = Vec::new(&env);
+        for i in 0..offering_count {
+            let item_key = DataKey::OfferItem(issuer.clone(), i);
+            let offering: Offering = env.storage().persistent().get(&item_key).unwrap();
+            let key = DataKey::OfferingMetadata(issuer.clone(), offering.token.clone());
+            if let Some(metadata) = env.storage().persistent().get::<DataKey, String>(&key) {
+                entries.push_back((offering.token, metadata));
+            }
+        }
+        let count = entries.len();
+
+        let effective_limit = Self::effective_page_limit(&env, limit);
+
+        if start >= count {
+            return (Vec::new(&env), None);
+        }
+
+        let end = core::cmp::min(start + effective_limit, count);
+        let results = entries.slice(start..end);
+
+        let next_cursor = if end < count { Some(end) } else { None };
+        (results, next_cursor)
+    }
+
+    // ── Issuer admin delegation ────────────────────────────────
+
+    /// Set the delegated admin for `issuer`. The admin may manage metadata for all of
+    /// the issuer's offerings. Replaces any previously set admin.
+    pub fn set_issuer_admin(env: Env, issuer: Address, admin: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        issuer.require_auth();
+
+        let key = DataKey::IssuerAdmin(issuer.clone());
+        env.storage().persistent().set(&key, &admin);
+
+        env.events()
+            .publish((EVENT_ISSUER_ADMIN_SET, issuer), admin);
+        Ok(())
+    }
+
+    /// Get the delegated admin for `issuer`, if set.
+    pub fn get_issuer_admin(env: Env, issuer: Address) -> Option<Address> {
+        let key = DataKey::IssuerAdmin(issuer);
         env.storage().persistent().get(&key)
     }
 
+    /// Add `admin` to `issuer`'s delegated admins, alongside any `set_issuer_admin` admin.
+    /// Adding a current admin is a no-op. Emits `admin_add`.
+    pub fn add_admin(env: Env, issuer: Address, admin: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        issuer.require_auth();
+
+        if Self::get_admins(env.clone(), issuer.clone()).contains(&admin) {
+            return Ok(());
+        }
+        let key = DataKeyExt::IssuerAdmins(issuer.clone());
+        let mut admins: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+        admins.push_back(admin.clone());
+        env.storage().persistent().set(&key, &admins);
+
+        env.events()
+            .publish((EVENT_ISSUER_ADMIN_ADDED, issuer), admin);
+        Ok(())
+    }
+
+    /// Remove `admin` from `issuer`'s delegated admins, including the `set_issuer_admin`
+    /// admin. Revoking an address that is not an admin is a no-op. Emits `admin_rev`.
+    pub fn revoke_admin(env: Env, issuer: Address, admin: Address) -> Result<(), RevoraError> {
+        Self::require_not_frozen(&env)?;
+        Self::require_not_paused(&env)?;
+        issuer.require_auth();
+
+        let mut revoked = false;
+        let single_key = DataKey::IssuerAdmin(issuer.clone());
+        if env
+            .storage()
+            .persistent()
+            .get::<DataKey, Address>(&single_key)
+            == Some(admin.clone())
+        {
+            env.storage().persistent().remove(&single_key);
+            revoked = true;
+        }
+        let key = DataKeyExt::IssuerAdmins(issuer.clone());
+        if let Some(mut admins) = env
+            .storage()
+            .persistent()
+            .get::<DataKeyExt, Vec<Address>>(&key)
+        {
+            if let Some(i) = admins.first_index_of(&admin) {
+                admins.remove(i);
+                if admins.is_empty() {
+                    env.storage().persistent().remove(&key);
+                } else {
+                    env.storage().persistent().set(&key, &admins);
+                }
+                revoked = true;
+            }
+        }
+
+        if revoked {
+            env.events()
+                .publish((EVENT_ISSUER_ADMIN_REVOKED, issuer), admin);
+        }
+        Ok(())
+    }
+
+    /// All delegated admins of `issuer`: the `set_issuer_admin` admin (if any) first, then
+    /// `add_admin` admins in the order they were added.
+    pub fn get_admins(env: Env, issuer: Address) -> Vec<Address> {
+        let mut admins = Vec::new(&env);
+        if let Some(admin) = Self::get_issuer_admin(env.clone(), issuer.clone()) {
+            admins.push_back(admin);
+        }
+        let key = DataKeyExt::IssuerAdmins(issuer);
+        if let Some(added) = env
+            .storage()
+            .persistent()
+            .get::<DataKeyExt, Vec<Address>>(&key)
+        {
+            for admin in added.iter() {
+                if !admins.contains(&admin) {
+                    admins.push_back(admin);
+                }
+            }
+        }
+        admins
+    }
+
     // ── Testnet mode configuration (#24) ───────────────────────
 
     /// Enable or disable testnet mode. Only admin may call.
@@ -2,10 +2,13 @@
 use soroban_sdk::{
     symbol_short,
     testutils::{Address as _, Events as _, Ledger as _},
-    token, vec, Address, Env, IntoVal, String as SdkString, Symbol, Vec,
+    token, vec, Address, Bytes, BytesN, Env, IntoVal, Map, String as SdkString, Symbol, Vec,
 };
 
-use crate::{RevoraError, RevoraRevenueShare, RevoraRevenueShareClient, RoundingMode};
+use crate::{
+    ContractStats, OfferingHistory, OfferingSnapshot, OfferingStatus, PeriodGuardConfig,
+    RevoraError, RevoraRevenueShare, RevoraRevenueShareClient, RoundingMode,
+};
 
 // ── helper ────────────────────────────────────────────────────
 
@@ -79,6 +82,64 @@ fn register_offering_emits_exact_event() {
     );
 }
 
+#[test]
+fn register_offering_with_id_emits_id_topic_and_is_retrievable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let offering_id = SdkString::from_str(&env, "series-a");
+    let bps: u32 = 1_500;
+
+    client.register_offering_with_id(&issuer, &token, &bps, &token, &offering_id);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (
+                    symbol_short!("offer_reg"),
+                    issuer.clone(),
+                    offering_id.clone()
+                )
+                    .into_val(&env),
+                (token.clone(), bps, token.clone()).into_val(&env),
+            ),
+        ]
+    );
+
+    let by_id = client.get_offering_by_id(&issuer, &offering_id).unwrap();
+    assert_eq!(by_id, client.get_offering(&issuer, &token));
+    assert_eq!(by_id.token, token);
+    assert_eq!(by_id.revenue_share_bps, bps);
+    assert_eq!(client.get_offering_count(&issuer), 1);
+}
+
+#[test]
+fn get_offering_by_id_unknown_returns_none() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let offering_id = SdkString::from_str(&env, "series-a");
+
+    assert_eq!(client.get_offering_by_id(&issuer, &offering_id), None);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    assert_eq!(client.get_offering_by_id(&issuer, &offering_id), None);
+
+    let other_token = Address::generate(&env);
+    client.register_offering_with_id(&issuer, &other_token, &1_000, &other_token, &offering_id);
+    let other_issuer = Address::generate(&env);
+    assert_eq!(client.get_offering_by_id(&other_issuer, &offering_id), None);
+}
+
 #[test]
 fn report_revenue_emits_exact_event() {
     let env = Env::default();
@@ -108,7 +169,7 @@ fn report_revenue_emits_exact_event() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (amount, period_id, empty_bl.clone()).into_val(&env),
+                (amount, period_id, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -135,7 +196,7 @@ fn report_revenue_emits_exact_event() {
                     token.clone()
                 )
                     .into_val(&env),
-                (amount, period_id).into_val(&env),
+                (amount, period_id, amount, amount / 10, 7u32).into_val(&env),
             ),
         ]
     );
@@ -176,7 +237,7 @@ fn combined_flow_preserves_event_order() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (amount, period_id, empty_bl.clone()).into_val(&env),
+                (amount, period_id, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -203,7 +264,7 @@ fn combined_flow_preserves_event_order() {
                     token.clone()
                 )
                     .into_val(&env),
-                (amount, period_id).into_val(&env),
+                (amount, period_id, amount, amount / 10, 7u32).into_val(&env),
             ),
         ]
     );
@@ -249,7 +310,7 @@ fn complex_mixed_flow_events_in_order() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer_a.clone(), token_x.clone()).into_val(&env),
-                (100_000i128, 1u64, empty_bl.clone()).into_val(&env),
+                (100_000i128, 1u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -276,12 +337,12 @@ fn complex_mixed_flow_events_in_order() {
                     token_x.clone(),
                 )
                     .into_val(&env),
-                (100_000i128, 1u64).into_val(&env),
+                (100_000i128, 1u64, 100_000i128, 5_000i128, 7u32).into_val(&env),
             ),
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer_b.clone(), token_y.clone()).into_val(&env),
-                (200_000i128, 1u64, empty_bl.clone()).into_val(&env),
+                (200_000i128, 1u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -308,7 +369,7 @@ fn complex_mixed_flow_events_in_order() {
                     token_y.clone(),
                 )
                     .into_val(&env),
-                (200_000i128, 1u64).into_val(&env),
+                (200_000i128, 1u64, 200_000i128, 15_000i128, 7u32).into_val(&env),
             ),
         ]
     );
@@ -391,7 +452,7 @@ fn multiple_revenue_reports_same_offering() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (10_000i128, 1u64, empty_bl.clone()).into_val(&env),
+                (10_000i128, 1u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -418,12 +479,12 @@ fn multiple_revenue_reports_same_offering() {
                     token.clone()
                 )
                     .into_val(&env),
-                (10_000i128, 1u64).into_val(&env),
+                (10_000i128, 1u64, 10_000i128, 1_000i128, 7u32).into_val(&env),
             ),
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (20_000i128, 2u64, empty_bl.clone()).into_val(&env),
+                (20_000i128, 2u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -450,12 +511,12 @@ fn multiple_revenue_reports_same_offering() {
                     token.clone()
                 )
                     .into_val(&env),
-                (20_000i128, 2u64).into_val(&env),
+                (20_000i128, 2u64, 20_000i128, 2_000i128, 7u32).into_val(&env),
             ),
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (30_000i128, 3u64, empty_bl.clone()).into_val(&env),
+                (30_000i128, 3u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -482,7 +543,7 @@ fn multiple_revenue_reports_same_offering() {
                     token.clone()
                 )
                     .into_val(&env),
-                (30_000i128, 3u64).into_val(&env),
+                (30_000i128, 3u64, 30_000i128, 3_000i128, 7u32).into_val(&env),
             ),
         ]
     );
@@ -526,7 +587,7 @@ fn same_issuer_different_tokens() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token_x.clone()).into_val(&env),
-                (500_000i128, 1u64, empty_bl.clone()).into_val(&env),
+                (500_000i128, 1u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -553,12 +614,12 @@ fn same_issuer_different_tokens() {
                     token_x.clone()
                 )
                     .into_val(&env),
-                (500_000i128, 1u64).into_val(&env),
+                (500_000i128, 1u64, 500_000i128, 50_000i128, 7u32).into_val(&env),
             ),
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token_y.clone()).into_val(&env),
-                (750_000i128, 1u64, empty_bl.clone()).into_val(&env),
+                (750_000i128, 1u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -585,7 +646,7 @@ fn same_issuer_different_tokens() {
                     token_y.clone()
                 )
                     .into_val(&env),
-                (750_000i128, 1u64).into_val(&env),
+                (750_000i128, 1u64, 750_000i128, 150_000i128, 7u32).into_val(&env),
             ),
         ]
     );
@@ -620,7 +681,7 @@ fn topic_symbols_are_distinct() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (1_000_000i128, 1u64, empty_bl.clone()).into_val(&env),
+                (1_000_000i128, 1u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -647,7 +708,7 @@ fn topic_symbols_are_distinct() {
                     token.clone()
                 )
                     .into_val(&env),
-                (1_000_000i128, 1u64).into_val(&env),
+                (1_000_000i128, 1u64, 1_000_000i128, 100_000i128, 7u32).into_val(&env),
             ),
         ]
     );
@@ -680,7 +741,7 @@ fn rev_rep_topics_include_token_address() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (999i128, 7u64, empty_bl.clone()).into_val(&env),
+                (999i128, 7u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -707,7 +768,7 @@ fn rev_rep_topics_include_token_address() {
                     token.clone()
                 )
                     .into_val(&env),
-                (999i128, 7u64).into_val(&env),
+                (999i128, 7u64, 999i128, 99i128, 7u32).into_val(&env),
             ),
         ]
     );
@@ -795,7 +856,7 @@ fn zero_amount_revenue_report() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (0i128, 1u64, empty_bl.clone()).into_val(&env),
+                (0i128, 1u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -822,7 +883,7 @@ fn zero_amount_revenue_report() {
                     token.clone()
                 )
                     .into_val(&env),
-                (0i128, 1u64).into_val(&env),
+                (0i128, 1u64, 0i128, 0i128, 7u32).into_val(&env),
             ),
         ]
     );
@@ -856,7 +917,7 @@ fn large_revenue_amount() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (large_amount, u64::MAX, empty_bl.clone()).into_val(&env),
+                (large_amount, u64::MAX, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -883,10 +944,27 @@ fn large_revenue_amount() {
                     token.clone()
                 )
                     .into_val(&env),
-                (large_amount, u64::MAX).into_val(&env),
+                (
+                    large_amount,
+                    u64::MAX,
+                    large_amount,
+                    large_amount / 10,
+                    7u32
+                )
+                    .into_val(&env),
             ),
         ]
     );
+
+    // The cumulative total sits at i128::MAX: one more unit is a structured overflow error.
+    assert_eq!(client.get_total_revenue(&issuer, &token), i128::MAX);
+    let r = client.try_report_revenue(&issuer, &token, &token, &1, &1, &false);
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::Overflow as u32)
+    );
+    assert_eq!(client.get_total_revenue(&issuer, &token), i128::MAX);
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), None);
 }
 
 #[test]
@@ -918,7 +996,7 @@ fn negative_revenue_amount() {
             (
                 contract_id.clone(),
                 (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env),
-                (negative, 99u64, empty_bl.clone()).into_val(&env),
+                (negative, 99u64, empty_bl.clone(), false).into_val(&env),
             ),
             (
                 contract_id.clone(),
@@ -945,7 +1023,7 @@ fn negative_revenue_amount() {
                     token.clone()
                 )
                     .into_val(&env),
-                (negative, 99u64).into_val(&env),
+                (negative, 99u64, negative, 0i128, 7u32).into_val(&env),
             ),
         ]
     );
@@ -1010,6 +1088,29 @@ fn it_emits_versioned_events() {
 
 // ── period/amount fuzz coverage ───────────────────────────────
 
+/// Overriding report for the fuzz sweeps. Boundary amounts can push the cumulative total
+/// out of `i128` range; that must surface as the `Overflow` contract error, never a trap.
+/// Returns whether the report was accepted.
+fn report_or_overflow(
+    client: &RevoraRevenueShareClient,
+    issuer: &Address,
+    token: &Address,
+    payout_asset: &Address,
+    amount: i128,
+    period: u64,
+) -> bool {
+    match client.try_report_revenue(issuer, token, payout_asset, &amount, &period, &true) {
+        Ok(_) => true,
+        Err(err) => {
+            assert_eq!(
+                err.unwrap_err(),
+                soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::Overflow as u32)
+            );
+            false
+        }
+    }
+}
+
 #[test]
 fn fuzz_period_and_amount_boundaries_do_not_panic() {
     let env = Env::default();
@@ -1024,11 +1125,14 @@ fn fuzz_period_and_amount_boundaries_do_not_panic() {
     let mut calls = 0usize;
     for amount in BOUNDARY_AMOUNTS {
         for period in BOUNDARY_PERIODS {
-            client.report_revenue(&issuer, &token, &payout_asset, &amount, &period, &false);
-            calls += 1;
+            // Periods repeat across amounts, so override to exercise every pair.
+            if report_or_overflow(&client, &issuer, &token, &payout_asset, amount, period) {
+                calls += 1;
+            }
         }
     }
 
+    assert!(calls > 0);
     assert_eq!(env.events().all().len(), calls as u32 * 4 + 1);
 }
 
@@ -1051,8 +1155,12 @@ fn fuzz_period_and_amount_repeatable_sweep_do_not_panic() {
         assert_eq!(next_period(&mut seed_a), next_period(&mut seed_b));
     }
 
-    // Reset and run deterministic fuzz-style inputs through contract entrypoint.
+    // Reset and run deterministic fuzz-style inputs through contract entrypoint. Rejected
+    // overflow calls roll back through the host, which the default budget does not cover
+    // across this many iterations.
+    env.budget().reset_unlimited();
     let mut seed = 0x00A1_1CE5_ED19_u64;
+    let mut calls = 0u32;
     for i in 0..FUZZ_ITERATIONS {
         let mut amount = next_amount(&mut seed);
         let mut period = next_period(&mut seed);
@@ -1069,10 +1177,13 @@ fn fuzz_period_and_amount_repeatable_sweep_do_not_panic() {
             period = 0;
         }
 
-        client.report_revenue(&issuer, &token, &payout_asset, &amount, &period, &false);
+        if report_or_overflow(&client, &issuer, &token, &payout_asset, amount, period) {
+            calls += 1;
+        }
     }
 
-    assert_eq!(env.events().all().len(), FUZZ_ITERATIONS as u32 * 4 + 1);
+    assert!(calls > 0);
+    assert_eq!(env.events().all().len(), calls * 4 + 1);
 }
 
 // ---------------------------------------------------------------------------
@@ -1098,6 +1209,84 @@ fn register_n(env: &Env, client: &RevoraRevenueShareClient, issuer: &Address, n:
     }
 }
 
+#[test]
+fn offerings_by_bps_range_filters_and_keeps_cursor() {
+    let (env, client, issuer) = setup();
+    // bps: 100, 101, 102, 103, 104
+    register_n(&env, &client, &issuer, 5);
+
+    let (page, cursor) = client.get_offerings_by_bps_range(&issuer, &101, &103, &0, &10);
+    assert_eq!(cursor, None);
+    assert_eq!(page.len(), 3);
+    for (i, offering) in page.iter().enumerate() {
+        assert_eq!(offering.revenue_share_bps, 101 + i as u32);
+    }
+
+    // Cursor indexes the underlying list: the first window [0, 2) holds one match.
+    let (page, cursor) = client.get_offerings_by_bps_range(&issuer, &101, &103, &0, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(cursor, Some(2));
+    let (page, cursor) = client.get_offerings_by_bps_range(&issuer, &101, &103, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(cursor, Some(4));
+    let (page, cursor) = client.get_offerings_by_bps_range(&issuer, &101, &103, &4, &2);
+    assert_eq!(page.len(), 0);
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn offerings_by_bps_range_rejects_inverted_range() {
+    let (env, client, issuer) = setup();
+    register_n(&env, &client, &issuer, 2);
+    let r = client.try_get_offerings_by_bps_range(&issuer, &200, &100, &0, &10);
+    assert_eq!(r, Err(Ok(RevoraError::InvalidBpsRange)));
+    let (page, _) = client.get_offerings_by_bps_range(&issuer, &100, &100, &0, &10);
+    assert_eq!(page.len(), 1);
+}
+
+#[test]
+fn offering_round_trips_through_page_and_compares_equal() {
+    use crate::Offering;
+
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_234, &payout_asset);
+
+    let expected = Offering {
+        issuer: issuer.clone(),
+        token: token.clone(),
+        revenue_share_bps: 1_234,
+        payout_asset: payout_asset.clone(),
+        status: OfferingStatus::Active,
+        decimals: 7,
+        max_supply: 0,
+    };
+    let (page, _) = client.get_offerings_page(&issuer, &0, &1);
+    let from_page = page.get(0).unwrap();
+    assert_eq!(from_page, expected);
+    assert_eq!(client.get_offering(&issuer, &token), from_page);
+
+    let mut other = expected.clone();
+    other.revenue_share_bps = 1_235;
+    assert_ne!(other, expected);
+}
+
+#[test]
+fn get_offering_found_and_not_found() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    assert_eq!(
+        client.try_get_offering(&issuer, &token),
+        Err(Ok(RevoraError::OfferingNotFound))
+    );
+
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let offering = client.try_get_offering(&issuer, &token).unwrap().unwrap();
+    assert_eq!(offering.token, token);
+    assert_eq!(offering.revenue_share_bps, 1_000);
+}
+
 #[test]
 fn empty_issuer_returns_empty_page() {
     let (_env, client, issuer) = setup();
@@ -1266,6 +1455,7 @@ fn add_marks_investor_as_blacklisted() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
     let investor = Address::generate(&env);
 
@@ -1280,6 +1470,7 @@ fn remove_unmarks_investor() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
     let investor = Address::generate(&env);
 
@@ -1294,6 +1485,7 @@ fn get_blacklist_returns_all_blocked_investors() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
     let inv_a = Address::generate(&env);
     let inv_b = Address::generate(&env);
@@ -1328,6 +1520,7 @@ fn double_add_is_idempotent() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
     let investor = Address::generate(&env);
 
@@ -1343,6 +1536,7 @@ fn remove_nonexistent_is_idempotent() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
     let investor = Address::generate(&env);
 
@@ -1358,6 +1552,7 @@ fn blacklist_is_scoped_per_offering() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token_a = Address::generate(&env);
     let token_b = Address::generate(&env);
     let investor = Address::generate(&env);
@@ -1374,6 +1569,7 @@ fn removing_from_one_offering_does_not_affect_another() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token_a = Address::generate(&env);
     let token_b = Address::generate(&env);
     let investor = Address::generate(&env);
@@ -1394,6 +1590,7 @@ fn blacklist_add_emits_event() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
     let investor = Address::generate(&env);
 
@@ -1408,6 +1605,7 @@ fn blacklist_remove_emits_event() {
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
     let investor = Address::generate(&env);
 
@@ -1417,194 +1615,463 @@ fn blacklist_remove_emits_event() {
     assert!(env.events().all().len() > before);
 }
 
-// ── distribution enforcement ──────────────────────────────────
+// ── batch add ─────────────────────────────────────────────────
 
 #[test]
-fn blacklisted_investor_excluded_from_distribution_filter() {
+fn blacklist_add_batch_skips_duplicates() {
     let env = Env::default();
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
-    let allowed = Address::generate(&env);
-    let blocked = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
 
-    client.blacklist_add(&admin, &token, &blocked);
+    client.blacklist_add(&admin, &token, &a);
+    let batch = vec![&env, a.clone(), b.clone(), b.clone(), c.clone()];
+    client.blacklist_add_batch(&admin, &token, &batch);
 
-    let investors = [allowed.clone(), blocked.clone()];
-    let eligible = investors
-        .iter()
-        .filter(|inv| !client.is_blacklisted(&token, inv))
-        .count();
+    assert_eq!(client.get_blacklist(&token).len(), 3);
+    assert!(client.is_blacklisted(&token, &b));
+    assert!(client.is_blacklisted(&token, &c));
 
-    assert_eq!(eligible, 1);
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("bl_batch"), token.clone(), admin.clone()).into_val(&env)
+    );
+    let added: u32 = data.into_val(&env);
+    assert_eq!(added, 2);
 }
 
 #[test]
-fn blacklist_takes_precedence_over_whitelist() {
+fn blacklist_add_batch_rejects_oversized_batch() {
     let env = Env::default();
     env.mock_all_auths();
     let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
-    let investor = Address::generate(&env);
-
-    client.blacklist_add(&admin, &token, &investor);
 
-    // Even if investor were on a whitelist, blacklist must win
-    assert!(client.is_blacklisted(&token, &investor));
+    let mut batch = Vec::new(&env);
+    for _ in 0..101 {
+        batch.push_back(Address::generate(&env));
+    }
+    let r = client.try_blacklist_add_batch(&admin, &token, &batch);
+    assert_eq!(r, Err(Ok(RevoraError::BatchTooLarge)));
+    assert_eq!(client.get_blacklist(&token).len(), 0);
 }
 
-// ── auth enforcement ──────────────────────────────────────────
-
 #[test]
-#[should_panic]
-fn blacklist_add_requires_auth() {
-    let env = Env::default(); // no mock_all_auths
+fn blacklist_add_batch_rejects_caller_other_than_stored_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
     let client = make_client(&env);
-    let bad_actor = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let impostor = Address::generate(&env);
+    client.set_admin(&admin);
     let token = Address::generate(&env);
-    let victim = Address::generate(&env);
+    let batch = vec![&env, Address::generate(&env), Address::generate(&env)];
 
-    client.blacklist_add(&bad_actor, &token, &victim);
+    let r = client.try_blacklist_add_batch(&impostor, &token, &batch);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    assert_eq!(client.get_blacklist(&token).len(), 0);
+
+    client.blacklist_add_batch(&admin, &token, &batch);
+    assert_eq!(client.get_blacklist(&token).len(), 2);
 }
 
-#[test]
-#[should_panic]
-fn blacklist_remove_requires_auth() {
-    let env = Env::default(); // no mock_all_auths
-    let client = make_client(&env);
-    let bad_actor = Address::generate(&env);
-    let token = Address::generate(&env);
-    let investor = Address::generate(&env);
+// ── pagination ────────────────────────────────────────────────
 
-    client.blacklist_remove(&bad_actor, &token, &investor);
+/// Blacklist `n` fresh investors for `token`.
+fn blacklist_n(env: &Env, client: &RevoraRevenueShareClient, token: &Address, n: u32) {
+    let admin = client.get_admin().unwrap_or_else(|| {
+        let admin = Address::generate(env);
+        client.set_admin(&admin);
+        admin
+    });
+    for _ in 0..n {
+        client.blacklist_add(&admin, token, &Address::generate(env));
+    }
 }
 
-// ── structured error codes (#41) ──────────────────────────────
-
 #[test]
-fn register_offering_rejects_bps_over_10000() {
+fn blacklist_page_multi_page_cursor_progression() {
     let env = Env::default();
     env.mock_all_auths();
     let client = make_client(&env);
-    let issuer = Address::generate(&env);
     let token = Address::generate(&env);
-    let payout_asset = Address::generate(&env);
+    blacklist_n(&env, &client, &token, 7);
 
-    let result = client.try_register_offering(&issuer, &token, &10_001, &payout_asset);
-    assert!(
-        result.is_err(),
-        "contract must return Err(RevoraError::InvalidRevenueShareBps) for bps > 10000"
-    );
-    assert_eq!(
-        RevoraError::InvalidRevenueShareBps as u32,
-        1,
-        "error code for integrators"
-    );
+    let (page1, cursor1) = client.get_blacklist_page(&token, &0, &3);
+    assert_eq!(page1.len(), 3);
+    assert_eq!(cursor1, Some(3));
+
+    let (page2, cursor2) = client.get_blacklist_page(&token, &cursor1.unwrap(), &3);
+    assert_eq!(page2.len(), 3);
+    assert_eq!(cursor2, Some(6));
+
+    let (page3, cursor3) = client.get_blacklist_page(&token, &cursor2.unwrap(), &3);
+    assert_eq!(page3.len(), 1);
+    assert_eq!(cursor3, None);
+
+    // Pages concatenate to the full list.
+    let mut all = page1.clone();
+    all.append(&page2);
+    all.append(&page3);
+    assert_eq!(all, client.get_blacklist(&token));
 }
 
 #[test]
-fn register_offering_accepts_bps_exactly_10000() {
+fn blacklist_page_limit_zero_uses_max_page_limit() {
     let env = Env::default();
     env.mock_all_auths();
     let client = make_client(&env);
-    let issuer = Address::generate(&env);
     let token = Address::generate(&env);
-    let payout_asset = Address::generate(&env);
-
-    let result = client.try_register_offering(&issuer, &token, &10_000, &payout_asset);
-    assert!(result.is_ok());
-}
-
-// ---------------------------------------------------------------------------
-// Storage limit negative tests (#31): many offerings/reports, no panics
-// ---------------------------------------------------------------------------
+    blacklist_n(&env, &client, &token, 25);
 
-/// Maximum reasonable offering count used in tests to probe storage growth.
-/// Reduced to 50 to stay within Soroban computational budget limits.
-const STORAGE_STRESS_OFFERING_COUNT: u32 = 50;
+    let (page, cursor) = client.get_blacklist_page(&token, &0, &0);
+    assert_eq!(page.len(), 20);
+    assert_eq!(cursor, Some(20));
 
-#[test]
-fn storage_stress_many_offerings_no_panic() {
-    let (env, client, issuer) = setup();
-    register_n(&env, &client, &issuer, STORAGE_STRESS_OFFERING_COUNT);
-    let count = client.get_offering_count(&issuer);
-    assert_eq!(count, STORAGE_STRESS_OFFERING_COUNT);
-    let (page, cursor) =
-        client.get_offerings_page(&issuer, &(STORAGE_STRESS_OFFERING_COUNT - 5), &10);
+    let (page, cursor) = client.get_blacklist_page(&token, &20, &0);
     assert_eq!(page.len(), 5);
     assert_eq!(cursor, None);
 }
 
 #[test]
-fn storage_stress_many_reports_no_panic() {
+fn blacklist_page_out_of_bounds_cursor_returns_empty() {
     let env = Env::default();
     env.mock_all_auths();
     let client = make_client(&env);
-    let issuer = Address::generate(&env);
     let token = Address::generate(&env);
-    let payout_asset = Address::generate(&env);
-    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    blacklist_n(&env, &client, &token, 3);
 
-    for period_id in 1..=100_u64 {
-        client.report_revenue(
-            &issuer,
-            &token,
-            &payout_asset,
-            &(period_id as i128 * 10_000),
-            &period_id,
-            &false,
-        );
-    }
-    assert!(env.events().all().len() >= 100);
+    let (page, cursor) = client.get_blacklist_page(&token, &100, &5);
+    assert_eq!(page.len(), 0);
+    assert_eq!(cursor, None);
 }
 
 #[test]
-fn storage_stress_large_blacklist_no_panic() {
+fn blacklist_page_empty_token() {
     let env = Env::default();
-    env.mock_all_auths();
     let client = make_client(&env);
-    let admin = Address::generate(&env);
     let token = Address::generate(&env);
 
-    for _ in 0..80 {
-        let investor = Address::generate(&env);
-        client.blacklist_add(&admin, &token, &investor);
-    }
-    let list = client.get_blacklist(&token);
-    assert_eq!(list.len(), 80);
+    let (page, cursor) = client.get_blacklist_page(&token, &0, &10);
+    assert_eq!(page.len(), 0);
+    assert_eq!(cursor, None);
 }
 
-// ---------------------------------------------------------------------------
-// Gas / compute usage characterization (#36): large scenarios, document behavior
-// ---------------------------------------------------------------------------
-
-#[test]
-fn gas_characterization_many_offerings_single_issuer() {
-    let (env, client, issuer) = setup();
-    let n = 50_u32;
-    register_n(&env, &client, &issuer, n);
-
-    let (page, _) = client.get_offerings_page(&issuer, &0, &20);
-    assert_eq!(page.len(), 20);
-}
+// ── distribution enforcement ──────────────────────────────────
 
 #[test]
-fn gas_characterization_report_revenue_with_large_blacklist() {
+fn blacklisted_investor_excluded_from_distribution_filter() {
     let env = Env::default();
     env.mock_all_auths();
     let client = make_client(&env);
-    let issuer = Address::generate(&env);
-    let token = Address::generate(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let token = Address::generate(&env);
+    let allowed = Address::generate(&env);
+    let blocked = Address::generate(&env);
+
+    client.blacklist_add(&admin, &token, &blocked);
+
+    let investors = [allowed.clone(), blocked.clone()];
+    let eligible = investors
+        .iter()
+        .filter(|inv| !client.is_blacklisted(&token, inv))
+        .count();
+
+    assert_eq!(eligible, 1);
+}
+
+#[test]
+fn blacklist_takes_precedence_over_whitelist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let token = Address::generate(&env);
+    let investor = Address::generate(&env);
+
+    client.blacklist_add(&admin, &token, &investor);
+
+    // Even if investor were on a whitelist, blacklist must win
+    assert!(client.is_blacklisted(&token, &investor));
+}
+
+// ── whitelist mode ────────────────────────────────────────────
+
+#[test]
+fn whitelist_add_and_remove() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    client.set_admin(&admin);
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let investor = Address::generate(&env);
+
+    assert!(!client.is_whitelisted(&token, &investor));
+    client.whitelist_add(&admin, &token, &investor);
+    assert!(client.is_whitelisted(&token, &investor));
+    client.whitelist_remove(&admin, &token, &investor);
+    assert!(!client.is_whitelisted(&token, &investor));
+}
+
+#[test]
+fn eligibility_blacklist_beats_whitelist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    client.set_admin(&admin);
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let investor = Address::generate(&env);
+
+    client.set_whitelist_enabled(&admin, &token, &true);
+    client.whitelist_add(&admin, &token, &investor);
+    client.blacklist_add(&admin, &token, &investor);
+
+    assert!(!client.is_eligible(&token, &investor));
+}
+
+#[test]
+fn eligibility_toggles_with_whitelist_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    client.set_admin(&admin);
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let listed = Address::generate(&env);
+    let unlisted = Address::generate(&env);
+
+    client.whitelist_add(&admin, &token, &listed);
+
+    // Mode off: only the blacklist applies.
+    assert!(!client.is_whitelist_enabled(&token));
+    assert!(client.is_eligible(&token, &listed));
+    assert!(client.is_eligible(&token, &unlisted));
+
+    client.set_whitelist_enabled(&admin, &token, &true);
+    assert!(client.is_eligible(&token, &listed));
+    assert!(!client.is_eligible(&token, &unlisted));
+
+    client.set_whitelist_enabled(&admin, &token, &false);
+    assert!(client.is_eligible(&token, &unlisted));
+}
+
+#[test]
+fn whitelist_is_scoped_per_offering() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    client.set_admin(&admin);
+    let token_a = Address::generate(&env);
+    client.register_offering(&issuer, &token_a, &1_000, &token_a);
+    let token_b = Address::generate(&env);
+    client.register_offering(&issuer, &token_b, &1_000, &token_b);
+    let investor = Address::generate(&env);
+
+    client.whitelist_add(&admin, &token_a, &investor);
+    client.set_whitelist_enabled(&admin, &token_a, &true);
+    client.set_whitelist_enabled(&admin, &token_b, &true);
+
+    assert!(client.is_eligible(&token_a, &investor));
+    assert!(!client.is_eligible(&token_b, &investor));
+}
+
+#[test]
+fn whitelist_edits_require_admin_issuer_or_manager() {
+    let (env, client, issuer) = setup();
+    let stranger = Address::generate(&env);
+    let token = Address::generate(&env);
+    let unregistered = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    let r = client.try_whitelist_add(&stranger, &token, &stranger);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    let r = client.try_set_whitelist_enabled(&stranger, &token, &true);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    assert!(!client.is_whitelisted(&token, &stranger));
+    assert!(!client.is_whitelist_enabled(&token));
+
+    let investor = Address::generate(&env);
+    client.whitelist_add(&issuer, &token, &investor);
+    let r = client.try_whitelist_remove(&stranger, &token, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    assert!(client.is_whitelisted(&token, &investor));
+
+    let r = client.try_whitelist_add(&issuer, &unregistered, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+// ── auth enforcement ──────────────────────────────────────────
+
+#[test]
+#[should_panic]
+fn blacklist_add_requires_auth() {
+    let env = Env::default(); // no mock_all_auths
+    let client = make_client(&env);
+    let bad_actor = Address::generate(&env);
+    let token = Address::generate(&env);
+    let victim = Address::generate(&env);
+
+    client.blacklist_add(&bad_actor, &token, &victim);
+}
+
+#[test]
+#[should_panic]
+fn blacklist_remove_requires_auth() {
+    let env = Env::default(); // no mock_all_auths
+    let client = make_client(&env);
+    let bad_actor = Address::generate(&env);
+    let token = Address::generate(&env);
+    let investor = Address::generate(&env);
+
+    client.blacklist_remove(&bad_actor, &token, &investor);
+}
+
+// ── structured error codes (#41) ──────────────────────────────
+
+#[test]
+fn register_offering_rejects_bps_over_10000() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
     let payout_asset = Address::generate(&env);
-    client.register_offering(&issuer, &token, &500, &payout_asset);
 
-    for _ in 0..30 {
-        client.blacklist_add(&Address::generate(&env), &token, &Address::generate(&env));
+    let result = client.try_register_offering(&issuer, &token, &10_001, &payout_asset);
+    assert!(
+        result.is_err(),
+        "contract must return Err(RevoraError::InvalidRevenueShareBps) for bps > 10000"
+    );
+    assert_eq!(
+        RevoraError::InvalidRevenueShareBps as u32,
+        1,
+        "error code for integrators"
+    );
+}
+
+#[test]
+fn register_offering_accepts_bps_exactly_10000() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+
+    let result = client.try_register_offering(&issuer, &token, &10_000, &payout_asset);
+    assert!(result.is_ok());
+}
+
+// ---------------------------------------------------------------------------
+// Storage limit negative tests (#31): many offerings/reports, no panics
+// ---------------------------------------------------------------------------
+
+/// Maximum reasonable offering count used in tests to probe storage growth.
+/// Reduced to 50 to stay within Soroban computational budget limits.
+const STORAGE_STRESS_OFFERING_COUNT: u32 = 50;
+
+#[test]
+fn storage_stress_many_offerings_no_panic() {
+    let (env, client, issuer) = setup();
+    register_n(&env, &client, &issuer, STORAGE_STRESS_OFFERING_COUNT);
+    let count = client.get_offering_count(&issuer);
+    assert_eq!(count, STORAGE_STRESS_OFFERING_COUNT);
+    let (page, cursor) =
+        client.get_offerings_page(&issuer, &(STORAGE_STRESS_OFFERING_COUNT - 5), &10);
+    assert_eq!(page.len(), 5);
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn storage_stress_many_reports_no_panic() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+
+    for period_id in 1..=100_u64 {
+        client.report_revenue(
+            &issuer,
+            &token,
+            &payout_asset,
+            &(period_id as i128 * 10_000),
+            &period_id,
+            &false,
+        );
     }
+    assert!(env.events().all().len() >= 100);
+}
+
+#[test]
+fn storage_stress_large_blacklist_no_panic() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
     let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let token = Address::generate(&env);
+
+    for _ in 0..80 {
+        let investor = Address::generate(&env);
+        client.blacklist_add(&admin, &token, &investor);
+    }
+    let list = client.get_blacklist(&token);
+    assert_eq!(list.len(), 80);
+}
+
+// ---------------------------------------------------------------------------
+// Gas / compute usage characterization (#36): large scenarios, document behavior
+// ---------------------------------------------------------------------------
+
+#[test]
+fn gas_characterization_many_offerings_single_issuer() {
+    let (env, client, issuer) = setup();
+    let n = 50_u32;
+    register_n(&env, &client, &issuer, n);
+
+    let (page, _) = client.get_offerings_page(&issuer, &0, &20);
+    assert_eq!(page.len(), 20);
+}
+
+#[test]
+fn gas_characterization_report_revenue_with_large_blacklist() {
+    let env = Env::default();
     env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &500, &payout_asset);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    for _ in 0..30 {
+        client.blacklist_add(&admin, &token, &Address::generate(&env));
+    }
     client.blacklist_add(&admin, &token, &Address::generate(&env));
 
     client.report_revenue(&issuer, &token, &payout_asset, &1_000_000, &1, &false);
@@ -1775,14 +2242,54 @@ fn audit_summary_aggregates_revenue_and_count() {
 }
 
 #[test]
-fn audit_summary_per_offering_isolation() {
-    let env = Env::default();
-    env.mock_all_auths();
-    let client = make_client(&env);
-    let issuer = Address::generate(&env);
-    let token_a = Address::generate(&env);
-    let token_b = Address::generate(&env);
-    let payout_asset_a = Address::generate(&env);
+fn get_total_revenue_zero_before_any_report() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    assert_eq!(client.get_total_revenue(&issuer, &token), 0);
+}
+
+#[test]
+fn get_total_revenue_accumulates_reports() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &100, &1, &false);
+    client.report_revenue(&issuer, &token, &payout_asset, &250, &2, &false);
+    assert_eq!(client.get_total_revenue(&issuer, &token), 350);
+}
+
+#[test]
+fn get_total_revenue_clawback_can_cross_zero() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &100, &1, &false);
+    client.report_revenue(&issuer, &token, &payout_asset, &-150, &2, &false);
+    assert_eq!(client.get_total_revenue(&issuer, &token), -50);
+}
+
+#[test]
+fn get_total_revenue_override_replaces_period_amount() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &100, &1, &false);
+    client.report_revenue(&issuer, &token, &payout_asset, &40, &1, &true);
+    assert_eq!(client.get_total_revenue(&issuer, &token), 40);
+}
+
+#[test]
+fn audit_summary_per_offering_isolation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    let payout_asset_a = Address::generate(&env);
     let payout_asset_b = Address::generate(&env);
     client.register_offering(&issuer, &token_a, &1_000, &payout_asset_a);
     client.register_offering(&issuer, &token_b, &1_000, &payout_asset_b);
@@ -2145,6 +2652,53 @@ fn set_holder_share_emits_event() {
     assert!(env.events().all().len() > before);
 }
 
+#[test]
+fn record_holdings_stores_snapshot_and_emits_total() {
+    let (env, client, issuer, token, _payment_token, _contract_id) = claim_setup();
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let mut holdings = Map::new(&env);
+    holdings.set(a.clone(), 600);
+    holdings.set(b.clone(), 400);
+
+    assert_eq!(client.get_holdings(&token, &1), None);
+    client.record_holdings(&issuer, &token, &1, &holdings);
+    assert_eq!(client.get_holdings(&token, &1), Some(holdings));
+    assert_eq!(client.get_holdings(&token, &2), None);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("hold_rec"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: (u64, i128) = data.into_val(&env);
+    assert_eq!(data, (1, 1_000));
+}
+
+#[test]
+fn record_holdings_rejects_zero_supply() {
+    let (env, client, issuer, token, _payment_token, _contract_id) = claim_setup();
+    let mut holdings = Map::new(&env);
+    holdings.set(Address::generate(&env), 0);
+
+    let r = client.try_record_holdings(&issuer, &token, &1, &holdings);
+    assert_eq!(r, Err(Ok(RevoraError::EmptyHoldings)));
+    let r = client.try_record_holdings(&issuer, &token, &1, &Map::new(&env));
+    assert_eq!(r, Err(Ok(RevoraError::EmptyHoldings)));
+    assert_eq!(client.get_holdings(&token, &1), None);
+}
+
+#[test]
+fn record_holdings_requires_current_issuer() {
+    let (env, client, _issuer, token, _payment_token, _contract_id) = claim_setup();
+    let mut holdings = Map::new(&env);
+    holdings.set(Address::generate(&env), 10);
+
+    let r = client.try_record_holdings(&Address::generate(&env), &token, &1, &holdings);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
 #[test]
 fn get_holder_share_returns_zero_for_unknown() {
     let (env, client, _issuer, token, _payment_token, _contract_id) = claim_setup();
@@ -2232,7 +2786,8 @@ fn claim_no_double_counting() {
 
     // Second claim should fail - nothing pending
     let result = client.try_claim(&holder, &token, &0);
-    assert!(result.is_err());
+    assert_eq!(result, Err(Ok(RevoraError::NoPendingClaims)));
+    assert_eq!(balance(&env, &payment_token, &holder), 100_000);
 }
 
 #[test]
@@ -2280,7 +2835,8 @@ fn claim_fails_for_blacklisted_holder() {
     client.blacklist_add(&issuer, &token, &holder);
 
     let result = client.try_claim(&holder, &token, &0);
-    assert!(result.is_err());
+    assert_eq!(result, Err(Ok(RevoraError::HolderBlacklisted)));
+    assert_eq!(balance(&env, &payment_token, &holder), 0);
 }
 
 #[test]
@@ -3047,8 +3603,7 @@ fn issuer_transfer_accept_completes_transfer() {
 
     // Verify offering issuer is updated - offering is now stored under new_issuer
     let offering = client.get_offering(&new_issuer, &token);
-    assert!(offering.is_some());
-    assert_eq!(offering.unwrap().issuer, new_issuer);
+    assert_eq!(offering.issuer, new_issuer);
 }
 
 #[test]
@@ -3120,7 +3675,7 @@ fn testnet_mode_allows_bps_over_10000() {
     assert!(result.is_ok());
 
     // Verify offering was registered
-    let offering = client.get_offering(&issuer, &token).unwrap();
+    let offering = client.get_offering(&issuer, &token);
     assert_eq!(offering.revenue_share_bps, 15_000);
 }
 
@@ -3294,7 +3849,7 @@ fn testnet_mode_affects_only_validation_not_storage() {
     client.set_testnet_mode(&false);
 
     // Offering should still exist with high bps value
-    let offering = client.get_offering(&issuer, &token).unwrap();
+    let offering = client.get_offering(&issuer, &token);
     assert_eq!(offering.revenue_share_bps, 25_000);
 }
 
@@ -3660,8 +4215,7 @@ fn issuer_transfer_get_offering_still_works() {
 
     // get_offering should find the offering under new issuer now
     let offering = client.get_offering(&new_issuer, &token);
-    assert!(offering.is_some());
-    assert_eq!(offering.unwrap().issuer, new_issuer);
+    assert_eq!(offering.issuer, new_issuer);
 }
 
 #[test]
@@ -3669,12 +4223,12 @@ fn issuer_transfer_preserves_revenue_share_bps() {
     let (env, client, issuer, token, _payment_token, _contract_id) = claim_setup();
     let new_issuer = Address::generate(&env);
 
-    let offering_before = client.get_offering(&issuer, &token).unwrap();
+    let offering_before = client.get_offering(&issuer, &token);
 
     client.propose_issuer_transfer(&token, &new_issuer);
     client.accept_issuer_transfer(&token);
 
-    let offering_after = client.get_offering(&new_issuer, &token).unwrap();
+    let offering_after = client.get_offering(&new_issuer, &token);
     assert_eq!(
         offering_before.revenue_share_bps,
         offering_after.revenue_share_bps
@@ -3797,7 +4351,7 @@ fn pause_unpause_idempotence_and_events() {
     let client = make_client(&env);
     let admin = Address::generate(&env);
 
-    client.initialize(&admin, &None::<Address>);
+    client.initialize(&admin, &None::<Address>, &0);
     assert!(!client.is_paused());
 
     // Pause twice (idempotent)
@@ -3817,7 +4371,48 @@ fn pause_unpause_idempotence_and_events() {
 }
 
 #[test]
-#[should_panic(expected = "contract is paused")]
+fn initialize_stores_admin_and_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+
+    assert_eq!(client.get_platform_fee_bps(), 0);
+    client.initialize(&admin, &None::<Address>, &250);
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(client.get_platform_fee_bps(), 250);
+}
+
+#[test]
+fn initialize_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+
+    client.initialize(&admin, &None::<Address>, &0);
+    let r = client.try_initialize(&Address::generate(&env), &None::<Address>, &100);
+    assert_eq!(r, Err(Ok(RevoraError::AlreadyInitialized)));
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(client.get_platform_fee_bps(), 0);
+}
+
+#[test]
+fn initialize_rejects_fee_over_10000() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+
+    let r = client.try_initialize(&admin, &None::<Address>, &10_001);
+    assert_eq!(r, Err(Ok(RevoraError::InvalidFeeBps)));
+    assert_eq!(client.get_admin(), None);
+
+    client.initialize(&admin, &None::<Address>, &10_000);
+    assert_eq!(client.get_platform_fee_bps(), 10_000);
+}
+
+#[test]
 fn register_blocked_while_paused() {
     let env = Env::default();
     env.mock_all_auths();
@@ -3827,13 +4422,20 @@ fn register_blocked_while_paused() {
     let token = Address::generate(&env);
     let payout_asset = Address::generate(&env);
 
-    client.initialize(&admin, &None::<Address>);
+    client.initialize(&admin, &None::<Address>, &0);
     client.pause_admin(&admin);
-    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    let r = client.try_register_offering(&issuer, &token, &1_000, &payout_asset);
+    assert_eq!(r, Err(Ok(RevoraError::ContractPaused)));
+
+    // Read-only getters stay available while paused.
+    assert_eq!(client.get_offering_count(&issuer), 0);
+    assert_eq!(
+        client.try_get_offering(&issuer, &token),
+        Err(Ok(RevoraError::OfferingNotFound))
+    );
 }
 
 #[test]
-#[should_panic(expected = "contract is paused")]
 fn report_blocked_while_paused() {
     let env = Env::default();
     env.mock_all_auths();
@@ -3843,11 +4445,13 @@ fn report_blocked_while_paused() {
     let token = Address::generate(&env);
     let payout_asset = Address::generate(&env);
 
-    client.initialize(&admin, &None::<Address>);
+    client.initialize(&admin, &None::<Address>, &0);
     // Register before pausing
     client.register_offering(&issuer, &token, &1_000, &payout_asset);
     client.pause_admin(&admin);
-    client.report_revenue(&issuer, &token, &payout_asset, &1_000_000, &1, &false);
+    let r = client.try_report_revenue(&issuer, &token, &payout_asset, &1_000_000, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::ContractPaused)));
+    assert_eq!(client.get_offering_count(&issuer), 1);
 }
 
 #[test]
@@ -3858,7 +4462,7 @@ fn pause_safety_role_works() {
     let admin = Address::generate(&env);
     let safety = Address::generate(&env);
 
-    client.initialize(&admin, &Some(safety.clone()));
+    client.initialize(&admin, &Some(safety.clone()), &0);
     assert!(!client.is_paused());
 
     // Safety can pause
@@ -3871,7 +4475,6 @@ fn pause_safety_role_works() {
 }
 
 #[test]
-#[should_panic(expected = "contract is paused")]
 fn blacklist_add_blocked_while_paused() {
     let env = Env::default();
     env.mock_all_auths();
@@ -3880,13 +4483,14 @@ fn blacklist_add_blocked_while_paused() {
     let token = Address::generate(&env);
     let investor = Address::generate(&env);
 
-    client.initialize(&admin, &None::<Address>);
+    client.initialize(&admin, &None::<Address>, &0);
     client.pause_admin(&admin);
-    client.blacklist_add(&admin, &token, &investor);
+    let r = client.try_blacklist_add(&admin, &token, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::ContractPaused)));
+    assert!(!client.is_blacklisted(&token, &investor));
 }
 
 #[test]
-#[should_panic(expected = "contract is paused")]
 fn blacklist_remove_blocked_while_paused() {
     let env = Env::default();
     env.mock_all_auths();
@@ -3895,9 +4499,34 @@ fn blacklist_remove_blocked_while_paused() {
     let token = Address::generate(&env);
     let investor = Address::generate(&env);
 
-    client.initialize(&admin, &None::<Address>);
+    client.initialize(&admin, &None::<Address>, &0);
     client.pause_admin(&admin);
-    client.blacklist_remove(&admin, &token, &investor);
+    let r = client.try_blacklist_remove(&admin, &token, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::ContractPaused)));
+}
+
+#[test]
+fn metadata_and_unpause_round_trip() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let metadata = SdkString::from_str(&env, "ipfs://QmTest");
+
+    client.initialize(&admin, &None::<Address>, &0);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.pause_admin(&admin);
+    let r = client.try_set_offering_metadata(&issuer, &token, &metadata);
+    assert_eq!(r, Err(Ok(RevoraError::ContractPaused)));
+
+    client.unpause_admin(&admin);
+    client.set_offering_metadata(&issuer, &token, &metadata);
+    assert_eq!(
+        client.get_offering_metadata(&issuer, &token),
+        Some(metadata)
+    );
 }
 
 // ===========================================================================
@@ -4326,6 +4955,85 @@ fn calculate_distribution_single_holder_owns_all() {
     assert_eq!(payout, 50_000);
 }
 
+// ── Pro-rata compute_distribution ─────────────────────────────
+
+/// Register a 15% offering and report `amount` for period 1.
+fn distribution_setup(amount: i128) -> (Env, RevoraRevenueShareClient<'static>, Address, Address) {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_500, &token);
+    client.report_revenue(&issuer, &token, &token, &amount, &1, &false);
+    (env, client, issuer, token)
+}
+
+#[test]
+fn compute_distribution_splits_pro_rata() {
+    let (env, client, issuer, token) = distribution_setup(1_000_000);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let mut holders = Map::new(&env);
+    holders.set(a.clone(), 750);
+    holders.set(b.clone(), 250);
+
+    let payouts = client.compute_distribution(&issuer, &token, &1, &holders);
+    assert_eq!(payouts.get(a).unwrap(), 112_500);
+    assert_eq!(payouts.get(b).unwrap(), 37_500);
+}
+
+#[test]
+fn compute_distribution_sum_never_exceeds_pot_and_remainder_goes_to_largest() {
+    let (env, client, issuer, token) = distribution_setup(1_000);
+    // Pot = 150; holdings 1/1/1 leave a remainder of 0, so use 2/3/4 (pot 150 / 9).
+    let small = Address::generate(&env);
+    let mid = Address::generate(&env);
+    let big = Address::generate(&env);
+    let mut holders = Map::new(&env);
+    holders.set(small.clone(), 2);
+    holders.set(mid.clone(), 3);
+    holders.set(big.clone(), 4);
+
+    let payouts = client.compute_distribution(&issuer, &token, &1, &holders);
+    let total: i128 = payouts.values().iter().sum();
+    assert_eq!(total, 150);
+    assert_eq!(payouts.get(small).unwrap(), 33);
+    assert_eq!(payouts.get(mid).unwrap(), 50);
+    assert_eq!(payouts.get(big).unwrap(), 66 + 1);
+}
+
+#[test]
+fn compute_distribution_blacklisted_holder_gets_zero() {
+    let (env, client, issuer, token) = distribution_setup(1_000_000);
+    let good = Address::generate(&env);
+    let bad = Address::generate(&env);
+    client.blacklist_add(&issuer, &token, &bad);
+    let mut holders = Map::new(&env);
+    holders.set(good.clone(), 100);
+    holders.set(bad.clone(), 900);
+
+    let payouts = client.compute_distribution(&issuer, &token, &1, &holders);
+    assert_eq!(payouts.get(bad).unwrap(), 0);
+    assert_eq!(payouts.get(good).unwrap(), 150_000);
+}
+
+#[test]
+fn compute_distribution_unreported_period_pays_nothing() {
+    let (env, client, issuer, token) = distribution_setup(1_000_000);
+    let a = Address::generate(&env);
+    let mut holders = Map::new(&env);
+    holders.set(a.clone(), 100);
+
+    let payouts = client.compute_distribution(&issuer, &token, &2, &holders);
+    assert_eq!(payouts.get(a).unwrap(), 0);
+}
+
+#[test]
+fn compute_distribution_unknown_offering_errors() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let r = client.try_compute_distribution(&issuer, &token, &1, &Map::new(&env));
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
 // ── Per-offering metadata storage tests (#8) ──────────────────
 
 #[test]
@@ -4429,7 +5137,7 @@ fn test_set_metadata_respects_freeze() {
     let issuer = Address::generate(&env);
     let token = Address::generate(&env);
 
-    client.initialize(&admin, &None);
+    client.initialize(&admin, &None, &0);
     client.register_offering(&issuer, &token, &1000, &token);
     client.freeze();
 
@@ -4448,7 +5156,7 @@ fn test_set_metadata_respects_pause() {
     let issuer = Address::generate(&env);
     let token = Address::generate(&env);
 
-    client.initialize(&admin, &None);
+    client.initialize(&admin, &None, &0);
     client.register_offering(&issuer, &token, &1000, &token);
     client.pause_admin(&admin);
 
@@ -4469,10 +5177,10 @@ fn test_set_metadata_empty_string() {
 
     let metadata = SdkString::from_str(&env, "");
     let result = client.try_set_offering_metadata(&issuer, &token, &metadata);
-    assert!(result.is_ok());
+    assert_eq!(result, Err(Ok(RevoraError::EmptyMetadata)));
 
     let retrieved = client.get_offering_metadata(&issuer, &token);
-    assert_eq!(retrieved, Some(metadata));
+    assert_eq!(retrieved, None);
 }
 
 #[test]
@@ -4722,6 +5430,201 @@ fn test_set_metadata_requires_issuer() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_set_metadata_too_large_returns_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    client.register_offering(&issuer, &token, &1000, &token);
+
+    let metadata = SdkString::from_str(&env, &"a".repeat(257));
+    let result = client.try_set_offering_metadata(&issuer, &token, &metadata);
+    assert_eq!(result, Err(Ok(RevoraError::MetadataTooLarge)));
+}
+
+#[test]
+fn test_delete_metadata_removes_and_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    client.register_offering(&issuer, &token, &1000, &token);
+    let metadata = SdkString::from_str(&env, "ipfs://QmTest");
+    client.set_offering_metadata(&issuer, &token, &metadata);
+
+    client.delete_offering_metadata(&issuer, &token);
+    assert!(!client
+        .get_all_metadata_page(&issuer, &0, &10)
+        .0
+        .iter()
+        .any(|(t, _)| t == token));
+    assert_eq!(client.get_offering_metadata(&issuer, &token), None);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("meta_del"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: (SdkString, u32) = data.into_val(&env);
+    assert_eq!(data, (metadata, 1));
+}
+
+#[test]
+fn test_delete_metadata_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    let result = client.try_delete_offering_metadata(&issuer, &token);
+    assert_eq!(result, Err(Ok(RevoraError::OfferingNotFound)));
+
+    client.register_offering(&issuer, &token, &1000, &token);
+    let result = client.try_delete_offering_metadata(&issuer, &token);
+    assert_eq!(result, Err(Ok(RevoraError::MetadataNotFound)));
+}
+
+#[test]
+fn test_all_metadata_page_empty_issuer() {
+    let (env, client, issuer) = setup();
+    let (page, cursor) = client.get_all_metadata_page(&issuer, &0, &10);
+    assert_eq!(page.len(), 0);
+    assert_eq!(cursor, None);
+
+    // Offerings without metadata are not listed.
+    register_n(&env, &client, &issuer, 3);
+    let (page, cursor) = client.get_all_metadata_page(&issuer, &0, &10);
+    assert_eq!(page.len(), 0);
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn test_all_metadata_page_multi_page_stable_order() {
+    let (env, client, issuer) = setup();
+    register_n(&env, &client, &issuer, 5);
+    let (offerings, _) = client.get_offerings_page(&issuer, &0, &5);
+    // Metadata on offerings 0, 1, 3 and 4 only; set out of order.
+    for i in [4u32, 0, 3, 1] {
+        let token = offerings.get(i).unwrap().token;
+        let metadata = SdkString::from_str(&env, "ipfs://QmTest");
+        client.set_offering_metadata(&issuer, &token, &metadata);
+    }
+
+    let (p1, c1) = client.get_all_metadata_page(&issuer, &0, &3);
+    assert_eq!(p1.len(), 3);
+    assert_eq!(c1, Some(3));
+    let (p2, c2) = client.get_all_metadata_page(&issuer, &3, &3);
+    assert_eq!(p2.len(), 1);
+    assert_eq!(c2, None);
+
+    let expected = [0u32, 1, 3, 4];
+    for (j, i) in expected.iter().enumerate() {
+        let token = offerings.get(*i).unwrap().token;
+        let (got, _) = if j < 3 {
+            p1.get(j as u32).unwrap()
+        } else {
+            p2.get(j as u32 - 3).unwrap()
+        };
+        assert_eq!(got, token);
+    }
+
+    // Same call twice yields the same page.
+    assert_eq!(client.get_all_metadata_page(&issuer, &0, &3), (p1, c1));
+}
+
+#[test]
+fn test_metadata_version_increments_and_is_in_update_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    client.register_offering(&issuer, &token, &1000, &token);
+    assert_eq!(client.get_metadata_version(&issuer, &token), 0);
+
+    for (i, uri) in ["ipfs://QmOne", "ipfs://QmTwo", "ipfs://QmThree"]
+        .iter()
+        .enumerate()
+    {
+        let metadata = SdkString::from_str(&env, uri);
+        client.set_offering_metadata(&issuer, &token, &metadata);
+        let version = i as u32 + 1;
+        assert_eq!(client.get_metadata_version(&issuer, &token), version);
+
+        if version > 1 {
+            let events = env.events().all();
+            let (_, topics, data) = events.last().unwrap();
+            assert_eq!(
+                topics,
+                (symbol_short!("meta_upd"), issuer.clone(), token.clone()).into_val(&env)
+            );
+            let data: (SdkString, u32) = data.into_val(&env);
+            assert_eq!(data, (metadata, version));
+        }
+    }
+    assert_eq!(client.get_metadata_version(&issuer, &token), 3);
+}
+
+#[test]
+fn test_set_max_metadata_length_allows_longer_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.set_admin(&admin);
+    client.register_offering(&issuer, &token, &1000, &token);
+
+    let long_uri = SdkString::from_str(&env, &"a".repeat(2000));
+    let r = client.try_set_offering_metadata(&issuer, &token, &long_uri);
+    assert_eq!(r, Err(Ok(RevoraError::MetadataTooLarge)));
+    assert_eq!(client.get_max_metadata_length(), 256);
+
+    client.set_max_metadata_length(&4096);
+    assert_eq!(client.get_max_metadata_length(), 4096);
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("meta_cap"), admin.clone()).into_val(&env)
+    );
+    let data: u32 = data.into_val(&env);
+    assert_eq!(data, 4096);
+
+    client.set_offering_metadata(&issuer, &token, &long_uri);
+    assert_eq!(
+        client.get_offering_metadata(&issuer, &token),
+        Some(long_uri)
+    );
+}
+
+#[test]
+fn test_set_max_metadata_length_rejects_out_of_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+
+    let r = client.try_set_max_metadata_length(&1024);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    client.set_admin(&Address::generate(&env));
+    let r = client.try_set_max_metadata_length(&8193);
+    assert_eq!(r, Err(Ok(RevoraError::InvalidMetadataCap)));
+    let r = client.try_set_max_metadata_length(&0);
+    assert_eq!(r, Err(Ok(RevoraError::InvalidMetadataCap)));
+    client.set_max_metadata_length(&8192);
+    assert_eq!(client.get_max_metadata_length(), 8192);
+}
+
 #[test]
 fn test_metadata_ipfs_cid_format() {
     let env = Env::default();
@@ -4780,3 +5683,4520 @@ fn test_metadata_content_hash_format() {
     let retrieved = client.get_offering_metadata(&issuer, &token);
     assert_eq!(retrieved, Some(content_hash));
 }
+
+// ===========================================================================
+// Offering updates
+// ===========================================================================
+
+#[test]
+fn update_offering_changes_middle_of_paginated_list() {
+    let (env, client, issuer) = setup();
+    register_n(&env, &client, &issuer, 5);
+
+    let (page, _) = client.get_offerings_page(&issuer, &2, &1);
+    let target = page.get(0).unwrap();
+    client.update_offering(&issuer, &target.token, &4_200);
+
+    assert_eq!(client.get_offering_count(&issuer), 5);
+    let (page, cursor) = client.get_offerings_page(&issuer, &2, &1);
+    assert_eq!(cursor, Some(3));
+    let updated = page.get(0).unwrap();
+    assert_eq!(updated.token, target.token);
+    assert_eq!(updated.revenue_share_bps, 4_200);
+
+    // Neighbours are untouched.
+    let (all, _) = client.get_offerings_page(&issuer, &0, &10);
+    assert_eq!(all.get(1).unwrap().revenue_share_bps, 101);
+    assert_eq!(all.get(3).unwrap().revenue_share_bps, 103);
+}
+
+#[test]
+fn update_offering_emits_event() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+
+    client.update_offering(&issuer, &token, &2_500);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("offer_upd"), issuer.clone()).into_val(&env)
+    );
+    let data: (Address, u32, u32) = data.into_val(&env);
+    assert_eq!(data, (token, 1_000, 2_500));
+}
+
+#[test]
+fn update_offering_rejects_bps_over_10000() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+
+    let r = client.try_update_offering(&issuer, &token, &10_001);
+    assert_eq!(r, Err(Ok(RevoraError::InvalidRevenueShareBps)));
+    assert_eq!(
+        client.get_offering(&issuer, &token).revenue_share_bps,
+        1_000
+    );
+}
+
+#[test]
+fn update_offering_fails_for_unknown_offering() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+
+    let r = client.try_update_offering(&issuer, &token, &500);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+// ===========================================================================
+// Offering close
+// ===========================================================================
+
+#[test]
+fn register_offering_defaults_to_active() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    assert_eq!(
+        client.get_offering(&issuer, &token).status,
+        OfferingStatus::Active
+    );
+}
+
+#[test]
+fn closed_offering_rejects_revenue_report() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &1_000, &1, &false);
+
+    client.close_offering(&issuer, &token);
+
+    let r = client.try_report_revenue(&issuer, &token, &payout_asset, &1_000, &2, &false);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingClosed)));
+}
+
+#[test]
+fn closed_offering_still_listed_in_pages() {
+    let (env, client, issuer) = setup();
+    register_n(&env, &client, &issuer, 3);
+    let (page, _) = client.get_offerings_page(&issuer, &0, &10);
+    let closed_token = page.get(1).unwrap().token;
+
+    client.close_offering(&issuer, &closed_token);
+
+    let (page, _) = client.get_offerings_page(&issuer, &0, &10);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().status, OfferingStatus::Active);
+    assert_eq!(page.get(1).unwrap().status, OfferingStatus::Closed);
+    assert_eq!(page.get(2).unwrap().status, OfferingStatus::Active);
+}
+
+#[test]
+fn close_offering_emits_event_and_is_not_repeatable() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    client.close_offering(&issuer, &token);
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("offer_cls"), issuer.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, token);
+
+    let r = client.try_close_offering(&issuer, &token);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingClosed)));
+}
+
+#[test]
+fn close_offering_fails_for_non_issuer() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    let r = client.try_close_offering(&other, &token);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+// ===========================================================================
+// Persisted revenue reports
+// ===========================================================================
+
+#[test]
+fn revenue_reports_are_persisted_per_period() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+
+    client.report_revenue(&issuer, &token, &payout_asset, &10_000, &1, &false);
+    client.report_revenue(&issuer, &token, &payout_asset, &20_000, &2, &false);
+    client.report_revenue(&issuer, &token, &payout_asset, &30_000, &3, &false);
+
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(10_000));
+    assert_eq!(client.get_revenue_report(&issuer, &token, &2), Some(20_000));
+    assert_eq!(client.get_revenue_report(&issuer, &token, &3), Some(30_000));
+    assert_eq!(client.get_revenue_report(&issuer, &token, &4), None);
+}
+
+#[test]
+fn duplicate_period_report_fails_without_override() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &10_000, &1, &false);
+
+    let r = client.try_report_revenue(&issuer, &token, &payout_asset, &99_000, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicatePeriod)));
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(10_000));
+    assert_eq!(
+        client
+            .get_audit_summary(&issuer, &token)
+            .unwrap()
+            .report_count,
+        1
+    );
+}
+
+#[test]
+fn duplicate_period_report_with_override_replaces_amount() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &10_000, &1, &false);
+
+    client.report_revenue(&issuer, &token, &payout_asset, &15_000, &1, &true);
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(15_000));
+}
+
+#[test]
+fn get_revenue_report_none_for_unknown_offering() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), None);
+}
+
+#[test]
+fn report_revenue_indexed_puts_period_in_topics() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+
+    client.report_revenue_indexed(&issuer, &token, &payout_asset, &10_000, &42, &false);
+
+    let events = env.events().all();
+    let (_, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics
+                == (
+                    symbol_short!("rev_rep"),
+                    issuer.clone(),
+                    token.clone(),
+                    42u64,
+                )
+                    .into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, Vec<Address>) = data.into_val(&env);
+    assert_eq!(data, (10_000, 42, Vec::new(&env)));
+    // The three-topic form is not emitted by the indexed variant.
+    let unindexed = events.iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("rev_rep"), issuer.clone(), token.clone()).into_val(&env)
+    });
+    assert!(!unindexed);
+    assert_eq!(
+        client.get_revenue_report(&issuer, &token, &42),
+        Some(10_000)
+    );
+}
+
+#[test]
+fn idempotent_report_replay_emits_once() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    let key = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.report_revenue_idempotent(&issuer, &token, &payout_asset, &10_000, &1, &key);
+    let r = client.try_report_revenue_idempotent(&issuer, &token, &payout_asset, &10_000, &1, &key);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateReport)));
+
+    let reports = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            *topics == (symbol_short!("rev_rep"), issuer.clone(), token.clone()).into_val(&env)
+        })
+        .count();
+    assert_eq!(reports, 1);
+    assert_eq!(
+        client
+            .get_audit_summary(&issuer, &token)
+            .unwrap()
+            .report_count,
+        1
+    );
+
+    // A fresh key for another period is accepted.
+    let other_key = BytesN::from_array(&env, &[8u8; 32]);
+    client.report_revenue_idempotent(&issuer, &token, &payout_asset, &5_000, &2, &other_key);
+    assert_eq!(client.get_revenue_report(&issuer, &token, &2), Some(5_000));
+}
+
+#[test]
+fn idempotent_report_key_not_consumed_on_failure() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    let key = BytesN::from_array(&env, &[1u8; 32]);
+
+    let r = client.try_report_revenue_idempotent(&issuer, &token, &payout_asset, &10_000, &1, &key);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue_idempotent(&issuer, &token, &payout_asset, &10_000, &1, &key);
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(10_000));
+}
+
+#[test]
+fn get_reports_in_range_skips_gaps() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    for (period, amount) in [(1u64, 100i128), (3, 300), (7, 700)] {
+        client.report_revenue(&issuer, &token, &payout_asset, &amount, &period, &false);
+    }
+
+    let all = client.get_reports_in_range(&issuer, &token, &0, &10);
+    assert_eq!(all, vec![&env, (1u64, 100i128), (3, 300), (7, 700)]);
+    let window = client.get_reports_in_range(&issuer, &token, &2, &7);
+    assert_eq!(window, vec![&env, (3u64, 300i128), (7, 700)]);
+    assert_eq!(
+        client.get_reports_in_range(&issuer, &token, &4, &6).len(),
+        0
+    );
+    assert_eq!(
+        client.get_reports_in_range(&issuer, &token, &7, &1).len(),
+        0
+    );
+}
+
+#[test]
+fn get_reports_in_range_rejects_wide_range() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    // 20 periods (0..=19) is the widest accepted window.
+    assert_eq!(
+        client.get_reports_in_range(&issuer, &token, &0, &19).len(),
+        0
+    );
+    let r = client.try_get_reports_in_range(&issuer, &token, &0, &20);
+    assert_eq!(r, Err(Ok(RevoraError::RangeTooWide)));
+    let r = client.try_get_reports_in_range(&issuer, &token, &0, &u64::MAX);
+    assert_eq!(r, Err(Ok(RevoraError::RangeTooWide)));
+}
+
+// ===========================================================================
+// Issuer admin delegation
+// ===========================================================================
+
+#[test]
+fn set_issuer_admin_stores_and_emits_event() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+
+    assert_eq!(client.get_issuer_admin(&issuer), None);
+    client.set_issuer_admin(&issuer, &admin);
+    assert_eq!(client.get_issuer_admin(&issuer), Some(admin.clone()));
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("admin_set"), issuer.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, admin);
+}
+
+#[test]
+fn issuer_admin_can_set_metadata() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_issuer_admin(&issuer, &admin);
+
+    let metadata = SdkString::from_str(&env, "ipfs://QmAdmin");
+    client.set_offering_metadata(&admin, &token, &metadata);
+
+    // Stored under the issuer, not the admin.
+    assert_eq!(
+        client.get_offering_metadata(&issuer, &token),
+        Some(metadata)
+    );
+    assert_eq!(client.get_offering_metadata(&admin, &token), None);
+}
+
+#[test]
+fn third_party_cannot_set_metadata_with_admin_configured() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_issuer_admin(&issuer, &admin);
+
+    let metadata = SdkString::from_str(&env, "ipfs://QmStranger");
+    let r = client.try_set_offering_metadata(&stranger, &token, &metadata);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+}
+
+#[test]
+#[should_panic]
+fn set_issuer_admin_requires_auth() {
+    let env = Env::default(); // no mock_all_auths
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let admin = Address::generate(&env);
+
+    client.set_issuer_admin(&issuer, &admin);
+}
+
+// ===========================================================================
+// Contract upgrade
+// ===========================================================================
+
+/// Upload a minimal valid contract Wasm (header plus the `contractenvmetav0`
+/// interface-version section for protocol 21) and return its hash.
+fn upload_test_wasm(env: &Env) -> BytesN<32> {
+    let wasm: [u8; 40] = [
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic + version
+        0x00, 30, 17, // custom section, size, name length
+        b'c', b'o', b'n', b't', b'r', b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a',
+        b'v', b'0', // "contractenvmetav0"
+        0, 0, 0, 0, // SCEnvMetaKind::InterfaceVersion
+        0, 0, 0, 21, 0, 0, 0, 0, // protocol 21, pre-release 0
+    ];
+    env.deployer()
+        .upload_contract_wasm(Bytes::from_slice(env, &wasm))
+}
+
+#[test]
+fn upgrade_rejects_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let hash = upload_test_wasm(&env);
+    let r = client.try_upgrade(&stranger, &hash);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+}
+
+#[test]
+fn upgrade_rejects_when_no_admin_set() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let caller = Address::generate(&env);
+
+    let hash = upload_test_wasm(&env);
+    let r = client.try_upgrade(&caller, &hash);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+}
+
+#[test]
+fn upgrade_by_admin_succeeds_and_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let hash = upload_test_wasm(&env);
+    client.upgrade(&admin, &hash);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("upgraded"), admin.clone()).into_val(&env)
+    );
+    let data: (BytesN<32>, u32) = data.into_val(&env);
+    assert_eq!(data, (hash, crate::CONTRACT_VERSION));
+}
+
+#[test]
+fn upgrade_blocked_when_frozen() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.freeze();
+
+    let hash = upload_test_wasm(&env);
+    let r = client.try_upgrade(&admin, &hash);
+    assert_eq!(r, Err(Ok(RevoraError::ContractFrozen)));
+}
+
+// ===========================================================================
+// Storage TTL extension
+// ===========================================================================
+
+#[test]
+fn extend_ttl_bumps_offerings_metadata_and_blacklist() {
+    use crate::{DataKey, TTL_EXTEND_TO};
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let investor = Address::generate(&env);
+
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_offering_metadata(&issuer, &token, &SdkString::from_str(&env, "ipfs://QmTest"));
+    client.blacklist_add(&issuer, &token, &investor);
+
+    // Advance the ledger so the entries' remaining TTL drops below the threshold.
+    env.ledger().with_mut(|l| l.sequence_number += 1_000);
+
+    client.extend_ttl(&issuer);
+    client.bump_blacklist_ttl(&token);
+
+    env.as_contract(&contract_id, || {
+        let store = env.storage().persistent();
+        assert_eq!(
+            store.get_ttl(&DataKey::OfferCount(issuer.clone())),
+            TTL_EXTEND_TO
+        );
+        assert_eq!(
+            store.get_ttl(&DataKey::OfferItem(issuer.clone(), 0)),
+            TTL_EXTEND_TO
+        );
+        assert_eq!(
+            store.get_ttl(&DataKey::OfferingMetadata(issuer.clone(), token.clone())),
+            TTL_EXTEND_TO
+        );
+        assert_eq!(
+            store.get_ttl(&DataKey::Blacklist(token.clone())),
+            TTL_EXTEND_TO
+        );
+    });
+    assert_eq!(client.get_offering(&issuer, &token).token, token);
+}
+
+#[test]
+fn extend_ttl_without_entries_is_noop() {
+    let (env, client, issuer) = setup();
+    client.extend_ttl(&issuer);
+    client.bump_blacklist_ttl(&Address::generate(&env));
+    assert_eq!(client.get_offering_count(&issuer), 0);
+}
+
+// ===========================================================================
+// Offering removal
+// ===========================================================================
+
+#[test]
+fn remove_middle_offering_compacts_index() {
+    let (env, client, issuer) = setup();
+    register_n(&env, &client, &issuer, 5);
+    let (before, _) = client.get_offerings_page(&issuer, &0, &5);
+    let removed = before.get(2).unwrap().token;
+    client.set_offering_metadata(
+        &issuer,
+        &removed,
+        &SdkString::from_str(&env, "ipfs://QmTest"),
+    );
+
+    client.remove_offering(&issuer, &removed);
+
+    assert_eq!(client.get_offering_count(&issuer), 4);
+    let (after, cursor) = client.get_offerings_page(&issuer, &0, &5);
+    assert_eq!(cursor, None);
+    assert_eq!(after.len(), 4);
+    assert_eq!(after.get(0).unwrap(), before.get(0).unwrap());
+    assert_eq!(after.get(1).unwrap(), before.get(1).unwrap());
+    assert_eq!(after.get(2).unwrap(), before.get(3).unwrap());
+    assert_eq!(after.get(3).unwrap(), before.get(4).unwrap());
+    assert_eq!(
+        client.try_get_offering(&issuer, &removed),
+        Err(Ok(RevoraError::OfferingNotFound))
+    );
+    assert_eq!(client.get_offering_metadata(&issuer, &removed), None);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("offer_rm"), issuer.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, removed);
+}
+
+#[test]
+fn remove_offering_drops_pending_issuer_transfer() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let proposed = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.propose_issuer_transfer(&token, &proposed);
+    client.remove_offering(&issuer, &token);
+
+    let second_issuer = Address::generate(&env);
+    client.register_offering(&second_issuer, &token, &2_000, &token);
+    let r = client.try_accept_issuer_transfer(&token);
+    assert_eq!(r, Err(Ok(RevoraError::NoTransferPending)));
+    assert_eq!(client.get_offering_count(&second_issuer), 1);
+    assert_eq!(client.get_offering_count(&proposed), 0);
+}
+
+#[test]
+fn remove_offering_revokes_delegated_reporter() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_reporter(&issuer, &token, &oracle);
+    client.remove_offering(&issuer, &token);
+
+    client.register_offering(&issuer, &token, &1_000, &token);
+    assert_eq!(client.get_reporter(&issuer, &token), None);
+    let r = client.try_report_revenue(&oracle, &token, &token, &100, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+#[test]
+fn remove_offering_revokes_reporter_contract() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let stub_id = env.register_contract(None, SettlementStub);
+    let stub = SettlementStubClient::new(&env, &stub_id);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_reporter_contract(&issuer, &token, &stub_id);
+    client.remove_offering(&issuer, &token);
+
+    client.register_offering(&issuer, &token, &1_000, &token);
+    assert_eq!(client.get_reporter_contract(&issuer, &token), None);
+    let r = stub.try_settle(&client.address, &token, &token, &5_000, &1);
+    assert!(r.is_err());
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), None);
+}
+
+#[test]
+fn remove_offering_unknown_or_wrong_issuer() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let r = client.try_remove_offering(&issuer, &token);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let r = client.try_remove_offering(&Address::generate(&env), &token);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+    assert_eq!(client.get_offering_count(&issuer), 1);
+}
+
+#[test]
+fn removed_token_can_be_registered_again() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.remove_offering(&issuer, &token);
+    assert_eq!(client.get_offering_count(&issuer), 0);
+
+    client.register_offering(&issuer, &token, &2_000, &token);
+    assert_eq!(
+        client.get_offering(&issuer, &token).revenue_share_bps,
+        2_000
+    );
+}
+
+// ===========================================================================
+// One-step issuer transfer
+// ===========================================================================
+
+#[test]
+fn transfer_issuer_appends_to_new_issuer_list() {
+    let (env, client, old_issuer) = setup();
+    let new_issuer = Address::generate(&env);
+    register_n(&env, &client, &old_issuer, 3);
+    register_n(&env, &client, &new_issuer, 2);
+    let (before, _) = client.get_offerings_page(&old_issuer, &0, &3);
+    let token = before.get(0).unwrap().token;
+    let metadata = SdkString::from_str(&env, "ipfs://QmMoved");
+    client.set_offering_metadata(&old_issuer, &token, &metadata);
+
+    client.transfer_issuer(&old_issuer, &new_issuer, &token);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("iss_xfer"), token.clone()).into_val(&env)
+    );
+    let data: (Address, Address) = data.into_val(&env);
+    assert_eq!(data, (old_issuer.clone(), new_issuer.clone()));
+
+    assert_eq!(client.get_offering_count(&old_issuer), 2);
+    assert_eq!(client.get_offering_count(&new_issuer), 3);
+    assert_eq!(
+        client.try_get_offering(&old_issuer, &token),
+        Err(Ok(RevoraError::OfferingNotFound))
+    );
+
+    let (page, cursor) = client.get_offerings_page(&new_issuer, &2, &10);
+    assert_eq!(cursor, None);
+    let moved = page.get(0).unwrap();
+    assert_eq!(moved.token, token);
+    assert_eq!(moved.issuer, new_issuer);
+
+    assert_eq!(client.get_offering_metadata(&old_issuer, &token), None);
+    assert_eq!(
+        client.get_offering_metadata(&new_issuer, &token),
+        Some(metadata)
+    );
+    assert_eq!(client.get_metadata_version(&new_issuer, &token), 1);
+}
+
+#[test]
+fn transfer_issuer_requires_current_issuer() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    let r = client.try_transfer_issuer(&stranger, &stranger, &token);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+    assert_eq!(client.get_offering_count(&issuer), 1);
+}
+
+#[test]
+fn transfer_issuer_clears_pending_proposal() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let proposed = Address::generate(&env);
+    let new_issuer = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.propose_issuer_transfer(&token, &proposed);
+
+    client.transfer_issuer(&issuer, &new_issuer, &token);
+    assert_eq!(client.get_pending_issuer_transfer(&token), None);
+    let r = client.try_accept_issuer_transfer(&token);
+    assert_eq!(r, Err(Ok(RevoraError::NoTransferPending)));
+}
+
+// ===========================================================================
+// Roles
+// ===========================================================================
+
+#[test]
+fn grant_and_revoke_roles() {
+    use crate::{ROLE_BLACKLIST_MANAGER, ROLE_FEE_MANAGER, ROLE_METADATA_MANAGER};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let account = Address::generate(&env);
+    client.set_admin(&admin);
+
+    assert!(!client.has_role(&account, &ROLE_BLACKLIST_MANAGER));
+    client.grant_role(&account, &ROLE_BLACKLIST_MANAGER);
+    client.grant_role(&account, &ROLE_FEE_MANAGER);
+    assert!(client.has_role(&account, &ROLE_BLACKLIST_MANAGER));
+    assert!(client.has_role(&account, &(ROLE_BLACKLIST_MANAGER | ROLE_FEE_MANAGER)));
+    assert!(!client.has_role(&account, &ROLE_METADATA_MANAGER));
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("role_gr"), account.clone()).into_val(&env)
+    );
+    let data: u32 = data.into_val(&env);
+    assert_eq!(data, ROLE_FEE_MANAGER);
+
+    client.revoke_role(&account, &ROLE_BLACKLIST_MANAGER);
+    assert!(!client.has_role(&account, &ROLE_BLACKLIST_MANAGER));
+    assert!(client.has_role(&account, &ROLE_FEE_MANAGER));
+}
+
+#[test]
+fn grant_role_rejects_invalid_role_and_missing_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let account = Address::generate(&env);
+
+    let r = client.try_grant_role(&account, &1);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    client.set_admin(&Address::generate(&env));
+    assert_eq!(
+        client.try_grant_role(&account, &0),
+        Err(Ok(RevoraError::InvalidRole))
+    );
+    assert_eq!(
+        client.try_grant_role(&account, &8),
+        Err(Ok(RevoraError::InvalidRole))
+    );
+    assert!(!client.has_role(&account, &0));
+}
+
+#[test]
+fn blacklist_manager_cannot_touch_metadata() {
+    use crate::{ROLE_BLACKLIST_MANAGER, ROLE_METADATA_MANAGER};
+
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let token = Address::generate(&env);
+    let investor = Address::generate(&env);
+    client.set_admin(&admin);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.grant_role(&manager, &ROLE_BLACKLIST_MANAGER);
+
+    client.blacklist_add(&manager, &token, &investor);
+    assert!(client.is_blacklisted(&token, &investor));
+
+    let metadata = SdkString::from_str(&env, "ipfs://QmTest");
+    let r = client.try_set_offering_metadata(&manager, &token, &metadata);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    client.grant_role(&manager, &ROLE_METADATA_MANAGER);
+    client.set_offering_metadata(&manager, &token, &metadata);
+    assert_eq!(
+        client.get_offering_metadata(&issuer, &token),
+        Some(metadata)
+    );
+}
+
+// ===========================================================================
+// Platform fee skim
+// ===========================================================================
+
+#[test]
+fn report_event_carries_distributable_amount() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_500, &payout_asset);
+
+    client.report_revenue(&issuer, &token, &payout_asset, &1_000_000, &1, &false);
+
+    let events = env.events().all();
+    let (_, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics
+                == (
+                    symbol_short!("rev_repa"),
+                    issuer.clone(),
+                    token.clone(),
+                    payout_asset.clone(),
+                )
+                    .into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, i128, i128, u32) = data.into_val(&env);
+    assert_eq!(data, (1_000_000, 1, 1_000_000, 150_000, 7));
+
+    let r = client.try_report_revenue(
+        &issuer,
+        &Address::generate(&env),
+        &payout_asset,
+        &1_000_000,
+        &1,
+        &false,
+    );
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+#[test]
+fn report_revenue_skims_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.initialize(&admin, &None::<Address>, &250);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+
+    client.report_revenue(&issuer, &token, &payout_asset, &1_000_000, &1, &false);
+    assert_eq!(client.get_accrued_fees(&payout_asset), 25_000);
+
+    let rev_repa = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics
+                == (
+                    symbol_short!("rev_repa"),
+                    issuer.clone(),
+                    token.clone(),
+                    payout_asset.clone(),
+                )
+                    .into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, i128, i128, u32) = rev_repa.2.into_val(&env);
+    assert_eq!(data, (1_000_000, 1, 975_000, 97_500, 7));
+
+    // Overrides accrue only the difference.
+    client.report_revenue(&issuer, &token, &payout_asset, &2_000_000, &1, &true);
+    assert_eq!(client.get_accrued_fees(&payout_asset), 50_000);
+    client.report_revenue(&issuer, &token, &payout_asset, &400_000, &2, &false);
+    assert_eq!(client.get_accrued_fees(&payout_asset), 60_000);
+}
+
+#[test]
+fn no_fee_accrues_without_platform_fee() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &1_000_000, &1, &false);
+    assert_eq!(client.get_accrued_fees(&payout_asset), 0);
+}
+
+#[test]
+fn withdraw_fees_transfers_and_resets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let (payout_asset, asset_admin) = create_payment_token(&env);
+    client.initialize(&admin, &None::<Address>, &250);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &1_000_000, &1, &false);
+    mint_tokens(&env, &payout_asset, &asset_admin, &issuer, &1_000_000);
+    client.deposit_revenue(&issuer, &token, &payout_asset, &1_000_000, &1);
+
+    let stranger = Address::generate(&env);
+    let r = client.try_withdraw_fees(&stranger, &payout_asset, &treasury);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    // Nothing can leave before a destination is registered.
+    let r = client.try_withdraw_fees(&admin, &payout_asset, &treasury);
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::UnregisteredDestination as u32)
+    );
+    client.set_fee_destination(&admin, &treasury);
+
+    assert_eq!(
+        client.withdraw_fees(&admin, &payout_asset, &treasury),
+        25_000
+    );
+    assert_eq!(balance(&env, &payout_asset, &treasury), 25_000);
+    assert_eq!(client.get_accrued_fees(&payout_asset), 0);
+    assert_eq!(client.withdraw_fees(&admin, &payout_asset, &treasury), 0);
+    assert_eq!(balance(&env, &payout_asset, &contract_id), 975_000);
+}
+
+#[test]
+fn withdraw_fees_never_pays_out_holder_deposits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let token = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let (payout_asset, asset_admin) = create_payment_token(&env);
+    client.initialize(&admin, &None::<Address>, &1_000);
+    client.set_fee_destination(&admin, &treasury);
+    client.register_offering(&issuer, &token, &10_000, &payout_asset);
+    client.set_holder_share(&issuer, &token, &holder, &10_000);
+    mint_tokens(&env, &payout_asset, &asset_admin, &issuer, &200_000);
+
+    client.report_revenue(&issuer, &token, &payout_asset, &100_000, &1, &false);
+    client.deposit_revenue(&issuer, &token, &payout_asset, &100_000, &1);
+    assert_eq!(client.get_claimable_liability(&payout_asset), 90_000);
+
+    assert_eq!(
+        client.withdraw_fees(&admin, &payout_asset, &treasury),
+        10_000
+    );
+    assert_eq!(client.claim(&holder, &token, &0), 90_000);
+    assert_eq!(balance(&env, &payout_asset, &treasury), 10_000);
+    assert_eq!(balance(&env, &payout_asset, &holder), 90_000);
+    assert_eq!(balance(&env, &payout_asset, &contract_id), 0);
+
+    // A report without a matching deposit accrues a fee the contract does not hold yet;
+    // it stays accrued instead of being paid from the next period's deposit.
+    client.report_revenue(&issuer, &token, &payout_asset, &100_000, &2, &false);
+    client.report_revenue(&issuer, &token, &payout_asset, &100_000, &3, &false);
+    client.deposit_revenue(&issuer, &token, &payout_asset, &100_000, &2);
+    assert_eq!(
+        client.withdraw_fees(&admin, &payout_asset, &treasury),
+        10_000
+    );
+    assert_eq!(client.get_accrued_fees(&payout_asset), 10_000);
+    assert_eq!(client.claim(&holder, &token, &0), 90_000);
+}
+
+// ===========================================================================
+// Lightweight read helpers
+// ===========================================================================
+
+#[test]
+fn offering_exists_empty_and_populated() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    assert!(!client.offering_exists(&issuer, &token));
+
+    client.register_offering(&issuer, &token, &1_000, &token);
+    assert!(client.offering_exists(&issuer, &token));
+    assert!(!client.offering_exists(&Address::generate(&env), &token));
+    assert!(!client.offering_exists(&issuer, &Address::generate(&env)));
+}
+
+#[test]
+fn get_offering_index_is_usable_as_cursor() {
+    let (env, client, issuer) = setup();
+    register_n(&env, &client, &issuer, 5);
+    let (all, _) = client.get_offerings_page(&issuer, &0, &5);
+
+    for i in 0..5u32 {
+        let token = all.get(i).unwrap().token;
+        let index = client.get_offering_index(&issuer, &token).unwrap();
+        assert_eq!(index, i);
+        let (page, _) = client.get_offerings_page(&issuer, &index, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().token, token);
+    }
+    assert_eq!(
+        client.get_offering_index(&issuer, &Address::generate(&env)),
+        None
+    );
+}
+
+#[test]
+fn count_blacklisted_empty_and_populated() {
+    let (env, client, _issuer) = setup();
+    let token = Address::generate(&env);
+    assert_eq!(client.count_blacklisted(&token), 0);
+
+    blacklist_n(&env, &client, &token, 3);
+    assert_eq!(client.count_blacklisted(&token), 3);
+    assert_eq!(
+        client.count_blacklisted(&token),
+        client.get_blacklist(&token).len()
+    );
+}
+
+#[test]
+fn register_same_token_twice_fails() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    let r = client.try_register_offering(&issuer, &token, &2_000, &token);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateOffering)));
+    assert_eq!(client.get_offering_count(&issuer), 1);
+    assert_eq!(
+        client.get_offering(&issuer, &token).revenue_share_bps,
+        1_000
+    );
+
+    // Another issuer may register the same token, but does not take it over.
+    let other = Address::generate(&env);
+    client.register_offering(&other, &token, &1_000, &token);
+    assert_eq!(client.get_offering_count(&other), 1);
+    let found = client.get_offerings_for_token(&token);
+    assert_eq!(found.get(0).unwrap().issuer, issuer);
+    let r = client.try_register_offering(&other, &token, &1_000, &token);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateOffering)));
+}
+
+// ===========================================================================
+// Batch offering registration
+// ===========================================================================
+
+#[test]
+fn register_offerings_batch_of_five() {
+    let (env, client, issuer) = setup();
+    register_n(&env, &client, &issuer, 1);
+    let mut entries = Vec::new(&env);
+    for i in 0..5u32 {
+        entries.push_back((
+            Address::generate(&env),
+            100 * (i + 1),
+            Address::generate(&env),
+        ));
+    }
+
+    client.register_offerings(&issuer, &entries);
+
+    assert_eq!(client.get_offering_count(&issuer), 6);
+    let (page, cursor) = client.get_offerings_page(&issuer, &1, &10);
+    assert_eq!(cursor, None);
+    assert_eq!(page.len(), 5);
+    for i in 0..5u32 {
+        let (token, bps, payout_asset) = entries.get(i).unwrap();
+        let offering = page.get(i).unwrap();
+        assert_eq!(offering.token, token);
+        assert_eq!(offering.revenue_share_bps, bps);
+        assert_eq!(offering.payout_asset, payout_asset);
+        assert_eq!(offering.status, OfferingStatus::Active);
+    }
+
+    let regs = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            *topics == (symbol_short!("offer_reg"), issuer.clone()).into_val(&env)
+        })
+        .count();
+    // One from the earlier single registration plus one per batch entry.
+    assert_eq!(regs, 6);
+}
+
+#[test]
+fn register_offerings_invalid_bps_is_atomic() {
+    let (env, client, issuer) = setup();
+    let mut entries = Vec::new(&env);
+    entries.push_back((Address::generate(&env), 1_000u32, Address::generate(&env)));
+    entries.push_back((Address::generate(&env), 10_001u32, Address::generate(&env)));
+
+    let r = client.try_register_offerings(&issuer, &entries);
+    assert_eq!(r, Err(Ok(RevoraError::InvalidRevenueShareBps)));
+    assert_eq!(client.get_offering_count(&issuer), 0);
+    let (first, _, _) = entries.get(0).unwrap();
+    assert!(!client.offering_exists(&issuer, &first));
+}
+
+#[test]
+fn register_offerings_rejects_duplicate_tokens() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+
+    let mut repeated = Vec::new(&env);
+    repeated.push_back((token.clone(), 100u32, payout_asset.clone()));
+    repeated.push_back((token.clone(), 200u32, payout_asset.clone()));
+    let r = client.try_register_offerings(&issuer, &repeated);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateOffering)));
+    assert_eq!(client.get_offering_count(&issuer), 0);
+
+    client.register_offering(&issuer, &token, &100, &payout_asset);
+    let mut existing = Vec::new(&env);
+    existing.push_back((Address::generate(&env), 100u32, payout_asset.clone()));
+    existing.push_back((token.clone(), 100u32, payout_asset.clone()));
+    let r = client.try_register_offerings(&issuer, &existing);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateOffering)));
+    assert_eq!(client.get_offering_count(&issuer), 1);
+}
+
+#[test]
+fn register_offerings_rejects_oversized_batch() {
+    let (env, client, issuer) = setup();
+    let mut entries = Vec::new(&env);
+    for _ in 0..21 {
+        entries.push_back((Address::generate(&env), 100u32, Address::generate(&env)));
+    }
+    let r = client.try_register_offerings(&issuer, &entries);
+    assert_eq!(r, Err(Ok(RevoraError::BatchTooLarge)));
+    assert_eq!(client.get_offering_count(&issuer), 0);
+}
+
+// ===========================================================================
+// Blacklist snapshot in revenue reports
+// ===========================================================================
+
+#[test]
+fn report_event_carries_blacklist_snapshot() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    blacklist_n(&env, &client, &token, 3);
+
+    client.report_revenue(&issuer, &token, &payout_asset, &1_000, &1, &false);
+
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (symbol_short!("rev_rep"), issuer.clone(), token.clone()).into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, Vec<Address>) = data.into_val(&env);
+    assert_eq!(data.2, client.get_blacklist(&token));
+    assert_eq!(data.2.len(), 3);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, Vec<Address>, bool) = data.into_val(&env);
+    assert!(!data.3);
+}
+
+#[test]
+fn report_event_blacklist_snapshot_is_capped() {
+    let (env, client, issuer) = setup();
+    env.budget().reset_unlimited();
+    let token = Address::generate(&env);
+    let payout_asset = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    let mut investors = Vec::new(&env);
+    for _ in 0..51 {
+        investors.push_back(Address::generate(&env));
+    }
+    client.blacklist_add_batch(&issuer, &token, &investors);
+
+    client.report_revenue(&issuer, &token, &payout_asset, &1_000, &1, &false);
+
+    let events = env.events().all();
+    let (_, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (symbol_short!("rev_rep"), issuer.clone(), token.clone()).into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, Vec<Address>) = data.into_val(&env);
+    assert_eq!(data.2, client.get_blacklist(&token).slice(0..50));
+
+    let (_, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (symbol_short!("rev_init"), issuer.clone(), token.clone()).into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, Vec<Address>, bool) = data.into_val(&env);
+    assert_eq!(data.2.len(), 50);
+    assert!(data.3);
+
+    client.report_revenue(&issuer, &token, &payout_asset, &2_000, &1, &true);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (symbol_short!("rev_ovrd"), issuer.clone(), token.clone()).into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, i128, Vec<Address>, bool) = data.into_val(&env);
+    assert_eq!((data.0, data.2, data.3.len()), (2_000, 1_000, 50));
+    assert!(data.4);
+    assert!(!env.events().all().iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("bl_trunc"), issuer.clone(), token.clone()).into_val(&env)
+    }));
+}
+
+// ===========================================================================
+// Expiring blacklist entries
+// ===========================================================================
+
+#[test]
+fn blacklist_entry_expires_automatically() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let temporary = Address::generate(&env);
+    let permanent = Address::generate(&env);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    client.blacklist_add_until(&issuer, &token, &temporary, &2_000);
+    client.blacklist_add(&issuer, &token, &permanent);
+    assert!(client.is_blacklisted(&token, &temporary));
+    assert_eq!(client.get_blacklist(&token).len(), 2);
+    assert_eq!(client.count_blacklisted(&token), 2);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.get(events.len() - 2).unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("bl_add_u"), token.clone(), issuer.clone()).into_val(&env)
+    );
+    let data: (Address, u64) = data.into_val(&env);
+    assert_eq!(data, (temporary.clone(), 2_000));
+
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    assert!(!client.is_blacklisted(&token, &temporary));
+    assert!(client.is_blacklisted(&token, &permanent));
+    assert_eq!(client.get_blacklist(&token), vec![&env, permanent.clone()]);
+    assert_eq!(client.count_blacklisted(&token), 1);
+}
+
+#[test]
+fn blacklist_add_makes_expiring_entry_permanent() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let investor = Address::generate(&env);
+
+    client.blacklist_add_until(&issuer, &token, &investor, &100);
+    client.blacklist_add(&issuer, &token, &investor);
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    assert!(client.is_blacklisted(&token, &investor));
+
+    client.blacklist_remove(&issuer, &token, &investor);
+    client.blacklist_add_until(&issuer, &token, &investor, &500);
+    assert!(!client.is_blacklisted(&token, &investor));
+}
+
+#[test]
+fn expired_entries_count_as_absent_for_writes() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let token = Address::generate(&env);
+    let source = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.register_offering(&issuer, &source, &1_000, &source);
+    let expired = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.set_max_blacklist_size(&1);
+
+    client.blacklist_add_until(&issuer, &token, &expired, &100);
+    env.ledger().set_timestamp(100);
+    let batch = vec![&env, expired.clone()];
+    assert_eq!(client.preview_blacklist_batch(&token, &batch), (1, 0));
+    client.blacklist_add_batch(&issuer, &token, &batch);
+    assert!(client.is_blacklisted(&token, &expired));
+
+    // The stale entry no longer holds the only slot under the cap.
+    client.blacklist_remove(&issuer, &token, &expired);
+    client.blacklist_add_until(&issuer, &token, &expired, &200);
+    env.ledger().set_timestamp(200);
+    client.blacklist_add(&issuer, &token, &other);
+    assert_eq!(client.get_blacklist(&token), vec![&env, other.clone()]);
+
+    // A transfer re-lists an investor whose entry on the target has expired.
+    client.set_max_blacklist_size(&10);
+    client.blacklist_add_until(&issuer, &token, &expired, &300);
+    client.blacklist_add(&issuer, &source, &expired);
+    env.ledger().set_timestamp(300);
+    assert_eq!(client.blacklist_transfer(&admin, &source, &token), 1);
+    assert!(client.is_blacklisted(&token, &expired));
+}
+
+// ===========================================================================
+// Clearing a blacklist
+// ===========================================================================
+
+#[test]
+fn clear_blacklist_removes_all_entries() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_admin(&admin);
+    blacklist_n(&env, &client, &token, 3);
+    let blocked = client.get_blacklist(&token);
+    let expiring = Address::generate(&env);
+    client.blacklist_add_until(&issuer, &token, &expiring, &1_000);
+
+    assert_eq!(client.clear_blacklist(&admin, &token), 4);
+    for investor in blocked.iter() {
+        assert!(!client.is_blacklisted(&token, &investor));
+    }
+    assert!(!client.is_blacklisted(&token, &expiring));
+    assert_eq!(client.get_blacklist(&token).len(), 0);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("bl_clear"), token.clone(), admin.clone()).into_val(&env)
+    );
+    let data: u32 = data.into_val(&env);
+    assert_eq!(data, 4);
+
+    // Idempotent: clearing again removes nothing.
+    assert_eq!(client.clear_blacklist(&admin, &token), 0);
+}
+
+#[test]
+fn clear_blacklist_requires_admin_or_manager() {
+    use crate::ROLE_BLACKLIST_MANAGER;
+
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.set_admin(&admin);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    blacklist_n(&env, &client, &token, 2);
+
+    let r = client.try_clear_blacklist(&manager, &token);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    assert_eq!(client.count_blacklisted(&token), 2);
+
+    client.grant_role(&manager, &ROLE_BLACKLIST_MANAGER);
+    assert_eq!(client.clear_blacklist(&manager, &token), 2);
+    blacklist_n(&env, &client, &token, 1);
+    let r = client.try_clear_blacklist(&issuer, &token);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    assert_eq!(client.clear_blacklist(&admin, &token), 1);
+}
+
+#[test]
+fn blacklist_edits_require_admin_issuer_or_manager() {
+    use crate::ROLE_BLACKLIST_MANAGER;
+
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = Address::generate(&env);
+    let unregistered = Address::generate(&env);
+    let investor = Address::generate(&env);
+    client.set_admin(&admin);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    let batch = vec![&env, investor.clone()];
+    let r = client.try_blacklist_add(&stranger, &token, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    let r = client.try_blacklist_add_until(&stranger, &token, &investor, &1_000);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    let r = client.try_blacklist_add_with_reason(&stranger, &token, &investor, &1);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    let r = client.try_blacklist_add_batch(&stranger, &token, &batch);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    assert!(!client.is_blacklisted(&token, &investor));
+
+    client.blacklist_add(&issuer, &token, &investor);
+    let r = client.try_blacklist_remove(&stranger, &token, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    assert!(client.is_blacklisted(&token, &investor));
+
+    // Without an offering the issuer has no say; the admin and role holders still do.
+    let r = client.try_blacklist_add(&issuer, &unregistered, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    client.blacklist_add(&admin, &unregistered, &investor);
+    client.grant_role(&stranger, &ROLE_BLACKLIST_MANAGER);
+    client.blacklist_remove(&stranger, &unregistered, &investor);
+    client.blacklist_remove(&stranger, &token, &investor);
+    assert!(!client.is_blacklisted(&token, &investor));
+    assert!(!client.is_blacklisted(&unregistered, &investor));
+}
+
+#[test]
+fn blacklist_count_all_tracks_net_changes() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let token_a = Address::generate(&env);
+    client.register_offering(&issuer, &token_a, &1_000, &token_a);
+    let token_b = Address::generate(&env);
+    client.register_offering(&issuer, &token_b, &1_000, &token_b);
+    let investor = Address::generate(&env);
+    let other = Address::generate(&env);
+    assert_eq!(client.get_blacklist_count_all(), 0);
+
+    client.blacklist_add(&issuer, &token_a, &investor);
+    client.blacklist_add(&issuer, &token_a, &investor);
+    assert_eq!(client.get_blacklist_count_all(), 1);
+
+    client.blacklist_remove(&issuer, &token_a, &other);
+    assert_eq!(client.get_blacklist_count_all(), 1);
+
+    let mut batch = Vec::new(&env);
+    batch.push_back(investor.clone());
+    batch.push_back(other.clone());
+    client.blacklist_add_batch(&issuer, &token_b, &batch);
+    client.blacklist_add_until(&issuer, &token_b, &other, &1_000);
+    assert_eq!(client.get_blacklist_count_all(), 3);
+
+    client.blacklist_remove(&issuer, &token_a, &investor);
+    assert_eq!(client.get_blacklist_count_all(), 2);
+    client.clear_blacklist(&admin, &token_b);
+    assert_eq!(client.get_blacklist_count_all(), 0);
+}
+
+// ===========================================================================
+// Period ordering guard
+// ===========================================================================
+
+#[test]
+fn period_guard_rejects_repeat_and_backdated_periods() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+    client.set_period_guard(&issuer, &issuer, &token, &true, &false);
+
+    for period in 1..=3u64 {
+        client.report_revenue(&issuer, &token, &payout, &100, &period, &false);
+    }
+    assert_eq!(client.get_last_reported_period(&issuer, &token), Some(3));
+
+    let r = client.try_report_revenue(&issuer, &token, &payout, &100, &2, &true);
+    assert_eq!(r, Err(Ok(RevoraError::NonMonotonicPeriod)));
+    let r = client.try_report_revenue(&issuer, &token, &payout, &100, &3, &true);
+    assert_eq!(r, Err(Ok(RevoraError::NonMonotonicPeriod)));
+
+    client.set_period_guard(&issuer, &issuer, &token, &true, &true);
+    client.report_revenue(&issuer, &token, &payout, &150, &2, &true);
+    assert_eq!(client.get_last_reported_period(&issuer, &token), Some(3));
+}
+
+#[test]
+fn period_guard_is_off_by_default_and_admin_can_set_it() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.set_admin(&admin);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+
+    client.report_revenue(&issuer, &token, &payout, &100, &5, &false);
+    client.report_revenue(&issuer, &token, &payout, &100, &4, &false);
+    assert_eq!(client.get_period_guard(&issuer, &token), None);
+
+    let r = client.try_set_period_guard(&stranger, &issuer, &token, &true, &false);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    client.set_period_guard(&admin, &issuer, &token, &true, &false);
+    assert_eq!(
+        client.get_period_guard(&issuer, &token),
+        Some(PeriodGuardConfig {
+            enforce: true,
+            allow_backdated: false,
+        })
+    );
+    let r = client.try_report_revenue(&issuer, &token, &payout, &100, &4, &true);
+    assert_eq!(r, Err(Ok(RevoraError::NonMonotonicPeriod)));
+    client.report_revenue(&issuer, &token, &payout, &100, &6, &false);
+}
+
+#[test]
+fn snapshot_offering_matches_individual_getters() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &2_500, &payout);
+    client.set_offering_metadata(&issuer, &token, &SdkString::from_str(&env, "ipfs://QmOne"));
+    client.set_offering_metadata(&issuer, &token, &SdkString::from_str(&env, "ipfs://QmTwo"));
+    client.report_revenue(&issuer, &token, &payout, &1_000, &1, &false);
+    client.report_revenue(&issuer, &token, &payout, &500, &2, &false);
+    blacklist_n(&env, &client, &token, 2);
+
+    let snap = client.snapshot_offering(&issuer, &token);
+    assert_eq!(
+        snap,
+        OfferingSnapshot {
+            offering: client.get_offering(&issuer, &token),
+            metadata: client.get_offering_metadata(&issuer, &token),
+            metadata_version: client.get_metadata_version(&issuer, &token),
+            total_revenue: client.get_total_revenue(&issuer, &token),
+            active: true,
+            blacklist_count: client.count_blacklisted(&token),
+        }
+    );
+    assert_eq!(snap.metadata_version, 2);
+    assert_eq!(snap.total_revenue, 1_500);
+    assert_eq!(snap.blacklist_count, 2);
+}
+
+#[test]
+fn snapshot_offering_unknown_offering_fails() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let r = client.try_snapshot_offering(&issuer, &token);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+// ===========================================================================
+// Batch metadata
+// ===========================================================================
+
+#[test]
+fn set_metadata_batch_emits_set_or_update_per_entry() {
+    let (env, client, issuer) = setup();
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    let id_a = SdkString::from_str(&env, "series-a");
+    let id_b = SdkString::from_str(&env, "series-b");
+    client.register_offering_with_id(&issuer, &token_a, &1_000, &token_a, &id_a);
+    client.register_offering_with_id(&issuer, &token_b, &1_000, &token_b, &id_b);
+    client.set_offering_metadata(&issuer, &token_a, &SdkString::from_str(&env, "ipfs://old"));
+
+    let uri_a = SdkString::from_str(&env, "ipfs://new-a");
+    let uri_b = SdkString::from_str(&env, "ipfs://new-b");
+    let mut entries = Vec::new(&env);
+    entries.push_back((id_a, uri_a.clone()));
+    entries.push_back((id_b, uri_b.clone()));
+    client.set_metadata_batch(&issuer, &entries);
+
+    assert_eq!(
+        client.get_offering_metadata(&issuer, &token_a),
+        Some(uri_a.clone())
+    );
+    assert_eq!(
+        client.get_offering_metadata(&issuer, &token_b),
+        Some(uri_b.clone())
+    );
+
+    let events = env.events().all();
+    let upd: (SdkString, u32) = events
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (symbol_short!("meta_upd"), issuer.clone(), token_a.clone()).into_val(&env)
+        })
+        .map(|(_, _, data)| data.into_val(&env))
+        .unwrap();
+    assert_eq!(upd, (uri_a, 2));
+    let set: SdkString = events
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (symbol_short!("meta_set"), issuer.clone(), token_b.clone()).into_val(&env)
+        })
+        .map(|(_, _, data)| data.into_val(&env))
+        .unwrap();
+    assert_eq!(set, uri_b);
+}
+
+#[test]
+fn set_metadata_batch_is_atomic() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let id = SdkString::from_str(&env, "series-a");
+    client.register_offering_with_id(&issuer, &token, &1_000, &token, &id);
+
+    let mut entries = Vec::new(&env);
+    entries.push_back((id.clone(), SdkString::from_str(&env, "ipfs://ok")));
+    entries.push_back((id.clone(), SdkString::from_str(&env, "")));
+    let r = client.try_set_metadata_batch(&issuer, &entries);
+    assert_eq!(r, Err(Ok(RevoraError::EmptyMetadata)));
+    assert_eq!(client.get_offering_metadata(&issuer, &token), None);
+
+    let mut entries = Vec::new(&env);
+    entries.push_back((
+        SdkString::from_str(&env, "missing"),
+        SdkString::from_str(&env, "ipfs://x"),
+    ));
+    let r = client.try_set_metadata_batch(&issuer, &entries);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+
+    let mut entries = Vec::new(&env);
+    for _ in 0..21 {
+        entries.push_back((id.clone(), SdkString::from_str(&env, "ipfs://x")));
+    }
+    let r = client.try_set_metadata_batch(&issuer, &entries);
+    assert_eq!(r, Err(Ok(RevoraError::BatchTooLarge)));
+}
+
+#[test]
+fn rename_offering_id_moves_metadata_key() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let old_id = SdkString::from_str(&env, "seires-a");
+    let new_id = SdkString::from_str(&env, "series-a");
+    let uri = SdkString::from_str(&env, "ipfs://QmSeries");
+    client.register_offering_with_id(&issuer, &token, &1_000, &token, &old_id);
+    client.set_offering_metadata(&issuer, &token, &uri);
+
+    client.rename_offering_id(&issuer, &old_id, &new_id);
+
+    assert_eq!(client.get_offering_by_id(&issuer, &old_id), None);
+    let offering = client.get_offering_by_id(&issuer, &new_id).unwrap();
+    assert_eq!(offering.token, token);
+    assert_eq!(
+        client.get_offering_metadata(&issuer, &offering.token),
+        Some(uri)
+    );
+    assert_eq!(client.get_metadata_version(&issuer, &token), 1);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("meta_ren"), issuer.clone()).into_val(&env)
+    );
+    let data: (SdkString, SdkString) = data.into_val(&env);
+    assert_eq!(data, (old_id.clone(), new_id.clone()));
+
+    let r = client.try_rename_offering_id(&issuer, &old_id, &new_id);
+    assert_eq!(r, Err(Ok(RevoraError::MetadataNotFound)));
+}
+
+#[test]
+fn rename_offering_id_rejects_taken_id() {
+    let (env, client, issuer) = setup();
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    let id_a = SdkString::from_str(&env, "series-a");
+    let id_b = SdkString::from_str(&env, "series-b");
+    client.register_offering_with_id(&issuer, &token_a, &1_000, &token_a, &id_a);
+    client.register_offering_with_id(&issuer, &token_b, &1_000, &token_b, &id_b);
+
+    let r = client.try_rename_offering_id(&issuer, &id_a, &id_b);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateOffering)));
+    assert_eq!(
+        client.get_offering_by_id(&issuer, &id_a).unwrap().token,
+        token_a
+    );
+}
+
+// ===========================================================================
+// Page limit override
+// ===========================================================================
+
+#[test]
+fn raised_page_limit_returns_larger_pages() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    for _ in 0..60 {
+        let token = Address::generate(&env);
+        client.register_offering(&issuer, &token, &1_000, &token);
+    }
+
+    let (page, next) = client.get_offerings_page(&issuer, &0, &0);
+    assert_eq!(page.len(), 20);
+    assert_eq!(next, Some(20));
+
+    client.set_max_page_limit(&50);
+    assert_eq!(client.get_max_page_limit(), 50);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("page_cfg"), admin.clone()).into_val(&env)
+    );
+    let data: u32 = data.into_val(&env);
+    assert_eq!(data, 50);
+
+    let (page, next) = client.get_offerings_page(&issuer, &0, &0);
+    assert_eq!(page.len(), 50);
+    assert_eq!(next, Some(50));
+    let (page, next) = client.get_offerings_page(&issuer, &50, &100);
+    assert_eq!(page.len(), 10);
+    assert_eq!(next, None);
+}
+
+#[test]
+fn set_max_page_limit_validates_bounds() {
+    let (env, client, _issuer) = setup();
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    assert_eq!(
+        client.try_set_max_page_limit(&0),
+        Err(Ok(RevoraError::InvalidPageLimit))
+    );
+    assert_eq!(
+        client.try_set_max_page_limit(&101),
+        Err(Ok(RevoraError::InvalidPageLimit))
+    );
+    client.set_max_page_limit(&100);
+    assert_eq!(client.get_max_page_limit(), 100);
+}
+
+// ===========================================================================
+// Distribution invariant checker
+// ===========================================================================
+
+#[test]
+fn verify_distribution_sums_checks_recipients_and_total() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &5_000, &payout);
+    client.report_revenue(&issuer, &token, &payout, &10_001, &1, &false);
+    // 50% of 10_001 floors to 5_000.
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+
+    let mut dist = Map::new(&env);
+    dist.set(a.clone(), 3_333);
+    dist.set(b.clone(), 1_666);
+    assert!(client.verify_distribution_sums(&token, &1, &dist));
+
+    let mut over = dist.clone();
+    over.set(b.clone(), 1_668);
+    assert!(!client.verify_distribution_sums(&token, &1, &over));
+
+    let mut short = dist.clone();
+    short.set(b.clone(), 1_600);
+    assert!(!client.verify_distribution_sums(&token, &1, &short));
+
+    let mut negative = dist.clone();
+    negative.set(c.clone(), -1);
+    assert!(!client.verify_distribution_sums(&token, &1, &negative));
+
+    client.blacklist_add(&issuer, &token, &a);
+    assert!(!client.verify_distribution_sums(&token, &1, &dist));
+
+    assert!(!client.verify_distribution_sums(&token, &2, &Map::new(&env)));
+}
+
+#[test]
+fn computed_distribution_passes_verification_with_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.initialize(&admin, &None::<Address>, &250);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+    client.report_revenue(&issuer, &token, &payout, &1_000_000, &1, &false);
+
+    let mut holders = Map::new(&env);
+    holders.set(Address::generate(&env), 600);
+    holders.set(Address::generate(&env), 300);
+    holders.set(Address::generate(&env), 100);
+    // 10% of 1_000_000 net of the 2.5% fee.
+    let dist = client.compute_distribution(&issuer, &token, &1, &holders);
+    let total: i128 = dist.values().iter().sum();
+    assert_eq!(total, 97_500);
+    assert!(client.verify_distribution_sums(&token, &1, &dist));
+
+    let chunk = client.compute_distribution_chunk(&issuer, &token, &1, &holders, &1_000);
+    let total: i128 = chunk.values().iter().sum();
+    assert_eq!(total, 97_500);
+    assert!(client.verify_distribution_sums(&token, &1, &chunk));
+}
+
+// ===========================================================================
+// Contract stats
+// ===========================================================================
+
+#[test]
+fn contract_stats_sum_offerings_across_issuers() {
+    let (env, client, issuer) = setup();
+    let other_issuer = Address::generate(&env);
+    let mut removed = None;
+    for _ in 0..3 {
+        let token = Address::generate(&env);
+        client.register_offering(&issuer, &token, &1_000, &token);
+        removed = Some(token);
+    }
+    let mut batch = Vec::new(&env);
+    for _ in 0..2 {
+        let token = Address::generate(&env);
+        batch.push_back((token.clone(), 500u32, token));
+    }
+    client.register_offerings(&other_issuer, &batch);
+    for _ in 0..2 {
+        let investor = Address::generate(&env);
+        client.blacklist_add(&issuer, &removed.clone().unwrap(), &investor);
+    }
+
+    let stats = client.get_contract_stats();
+    assert_eq!(
+        stats,
+        ContractStats {
+            total_offerings: client.get_offering_count(&issuer)
+                + client.get_offering_count(&other_issuer),
+            total_blacklist_entries: 2,
+            paused: false,
+            initialized: false,
+        }
+    );
+    assert_eq!(stats.total_offerings, 5);
+
+    client.remove_offering(&issuer, &removed.unwrap());
+    assert_eq!(client.get_contract_stats().total_offerings, 4);
+}
+
+#[test]
+fn contract_stats_report_initialized_and_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = make_client(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &None::<Address>, &0);
+    client.pause_admin(&admin);
+
+    let stats = client.get_contract_stats();
+    assert!(stats.initialized);
+    assert!(stats.paused);
+}
+
+// ===========================================================================
+// Offering decimals
+// ===========================================================================
+
+#[test]
+fn offering_decimals_are_carried_in_revenue_events() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering_with_decimals(&issuer, &token, &1_000, &payout, &6);
+    assert_eq!(client.get_offering_decimals(&issuer, &token), 6);
+    assert_eq!(client.get_offering(&issuer, &token).decimals, 6);
+
+    client.report_revenue(&issuer, &token, &payout, &1_000_000, &1, &false);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics
+                == (
+                    symbol_short!("rev_repa"),
+                    issuer.clone(),
+                    token.clone(),
+                    payout.clone(),
+                )
+                    .into_val(&env)
+        })
+        .unwrap();
+    let data: (i128, u64, i128, i128, u32) = data.into_val(&env);
+    assert_eq!(data.4, 6);
+}
+
+#[test]
+fn offering_decimals_default_and_bounds() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    assert_eq!(client.get_offering_decimals(&issuer, &token), 7);
+
+    let other = Address::generate(&env);
+    let r = client.try_register_offering_with_decimals(&issuer, &other, &1_000, &other, &19);
+    assert_eq!(r, Err(Ok(RevoraError::InvalidDecimals)));
+    client.register_offering_with_decimals(&issuer, &other, &1_000, &other, &18);
+}
+
+#[test]
+fn owner_can_force_remove_blacklist_entry() {
+    use crate::ROLE_BLACKLIST_MANAGER;
+
+    let (env, client, _issuer) = setup();
+    let owner = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let token = Address::generate(&env);
+    let investor = Address::generate(&env);
+    client.set_admin(&owner);
+    client.grant_role(&manager, &ROLE_BLACKLIST_MANAGER);
+    client.blacklist_add(&manager, &token, &investor);
+
+    let r = client.try_force_remove_blacklist_entry(&manager, &token, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    assert!(client.is_blacklisted(&token, &investor));
+
+    client.force_remove_blacklist_entry(&owner, &token, &investor);
+    assert!(!client.is_blacklisted(&token, &investor));
+    assert_eq!(client.get_blacklist_count_all(), 0);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("bl_frc_rm"), token.clone(), owner.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, investor);
+}
+
+#[test]
+fn blacklist_keeps_insertion_order() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let d = Address::generate(&env);
+
+    client.blacklist_add(&issuer, &token, &a);
+    client.blacklist_add(&issuer, &token, &b);
+    client.blacklist_add(&issuer, &token, &c);
+    client.blacklist_remove(&issuer, &token, &b);
+    assert_eq!(
+        client.get_blacklist(&token),
+        vec![&env, a.clone(), c.clone()]
+    );
+
+    client.blacklist_add_batch(
+        &issuer,
+        &token,
+        &vec![&env, d.clone(), b.clone(), a.clone()],
+    );
+    let expected = vec![&env, a.clone(), c.clone(), d.clone(), b.clone()];
+    assert_eq!(client.get_blacklist(&token), expected);
+    let (page, next) = client.get_blacklist_page(&token, &1, &2);
+    assert_eq!(page, vec![&env, c, d]);
+    assert_eq!(next, Some(3));
+}
+
+// ===========================================================================
+// Delegated reporter
+// ===========================================================================
+
+#[test]
+fn delegated_reporter_can_report_revenue() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let random = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+
+    let r = client.try_report_revenue(&oracle, &token, &payout, &100, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+
+    client.set_reporter(&issuer, &token, &oracle);
+    assert_eq!(client.get_reporter(&issuer, &token), Some(oracle.clone()));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("rep_set"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, oracle);
+
+    client.report_revenue(&oracle, &token, &payout, &100, &1, &false);
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(100));
+    client.report_revenue(&issuer, &token, &payout, &200, &2, &false);
+    assert_eq!(client.get_total_revenue(&issuer, &token), 300);
+
+    let r = client.try_report_revenue(&random, &token, &payout, &100, &3, &false);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+
+    client.set_reporter(&issuer, &token, &issuer);
+    assert_eq!(client.get_reporter(&issuer, &token), None);
+    let r = client.try_report_revenue(&oracle, &token, &payout, &100, &3, &false);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+#[test]
+fn delegated_reporter_requires_reporter_auth() {
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+
+    let env = Env::default();
+    let client = make_client(&env);
+    let issuer = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let token = Address::generate(&env);
+    env.mock_all_auths();
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_reporter(&issuer, &token, &oracle);
+
+    // Only the issuer signs: a report submitted as the oracle must be rejected.
+    env.mock_auths(&[MockAuth {
+        address: &issuer,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "report_revenue",
+            args: (&oracle, &token, &token, 100i128, 1u64, false).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client
+        .try_report_revenue(&oracle, &token, &token, &100, &1, &false)
+        .is_err());
+}
+
+// ===========================================================================
+// Stuck token rescue
+// ===========================================================================
+
+#[test]
+fn owner_can_rescue_surplus_but_not_claimable_funds() {
+    let (env, client, issuer, token, payment_token, contract_id) = claim_setup();
+    let owner = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let to = Address::generate(&env);
+    client.set_admin(&owner);
+
+    client.deposit_revenue(&issuer, &token, &payment_token, &100_000, &1);
+    client.set_holder_share(&issuer, &token, &holder, &5_000);
+    assert_eq!(client.get_claimable_liability(&payment_token), 100_000);
+
+    // 30_000 sent to the contract directly is surplus.
+    token::StellarAssetClient::new(&env, &payment_token).mint(&contract_id, &30_000);
+
+    let r = client.try_withdraw_stuck_tokens(&owner, &payment_token, &to, &30_001);
+    assert_eq!(r, Err(Ok(RevoraError::InsufficientSurplus)));
+    let r = client.try_withdraw_stuck_tokens(&holder, &payment_token, &to, &1);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    client.withdraw_stuck_tokens(&owner, &payment_token, &to, &30_000);
+    assert_eq!(balance(&env, &payment_token, &to), 30_000);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("rescue"), payment_token.clone(), to.clone()).into_val(&env)
+    );
+    let data: i128 = data.into_val(&env);
+    assert_eq!(data, 30_000);
+
+    // After the holder claims half, the unclaimed remainder stays protected.
+    assert_eq!(client.claim(&holder, &token, &0), 50_000);
+    assert_eq!(client.get_claimable_liability(&payment_token), 50_000);
+    let r = client.try_withdraw_stuck_tokens(&owner, &payment_token, &to, &1);
+    assert_eq!(r, Err(Ok(RevoraError::InsufficientSurplus)));
+}
+
+#[test]
+fn is_blacklisted_batch_returns_parallel_flags() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let blocked_a = Address::generate(&env);
+    let blocked_b = Address::generate(&env);
+    let clean = Address::generate(&env);
+    client.blacklist_add(&issuer, &token, &blocked_a);
+    client.blacklist_add(&issuer, &token, &blocked_b);
+
+    let investors = vec![
+        &env,
+        clean.clone(),
+        blocked_a.clone(),
+        clean.clone(),
+        blocked_b.clone(),
+    ];
+    assert_eq!(
+        client.is_blacklisted_batch(&token, &investors),
+        vec![&env, false, true, false, true]
+    );
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..21 {
+        too_many.push_back(clean.clone());
+    }
+    let r = client.try_is_blacklisted_batch(&token, &too_many);
+    assert_eq!(r, Err(Ok(RevoraError::BatchTooLarge)));
+}
+
+// ===========================================================================
+// Report memos
+// ===========================================================================
+
+#[test]
+fn report_memo_round_trips() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+    let memo = SdkString::from_str(&env, "Q1 SaaS subscriptions");
+
+    client.report_revenue_with_memo(&issuer, &token, &payout, &5_000, &1, &memo);
+    assert_eq!(
+        client.get_report_memo(&issuer, &token, &1),
+        Some(memo.clone())
+    );
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(5_000));
+    assert_eq!(client.get_report_memo(&issuer, &token, &2), None);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("rev_memo"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: (u64, SdkString) = data.into_val(&env);
+    assert_eq!(data, (1, memo.clone()));
+
+    // A second memo report for the period is a duplicate, not an override.
+    let other = SdkString::from_str(&env, "restated");
+    let r = client.try_report_revenue_with_memo(&issuer, &token, &payout, &9_000, &1, &other);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicatePeriod)));
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(5_000));
+    assert_eq!(client.get_report_memo(&issuer, &token, &1), Some(memo));
+}
+
+#[test]
+fn report_memo_over_cap_is_rejected() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+
+    let long = SdkString::from_bytes(&env, &[b'm'; 257]);
+    let r = client.try_report_revenue_with_memo(&issuer, &token, &payout, &5_000, &1, &long);
+    assert_eq!(r, Err(Ok(RevoraError::MemoTooLong)));
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), None);
+
+    let max = SdkString::from_bytes(&env, &[b'm'; 256]);
+    client.report_revenue_with_memo(&issuer, &token, &payout, &5_000, &1, &max);
+}
+
+// ===========================================================================
+// Two-step ownership transfer
+// ===========================================================================
+
+#[test]
+fn ownership_transfer_requires_acceptance() {
+    let (env, client, issuer) = setup();
+    let owner = Address::generate(&env);
+    let next_owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let investor = Address::generate(&env);
+    client.set_admin(&owner);
+    client.blacklist_add(&issuer, &token, &investor);
+
+    let r = client.try_transfer_ownership(&stranger, &next_owner);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    let r = client.try_accept_ownership(&next_owner);
+    assert_eq!(r, Err(Ok(RevoraError::NoTransferPending)));
+
+    client.transfer_ownership(&owner, &next_owner);
+    assert_eq!(client.get_pending_owner(), Some(next_owner.clone()));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("own_start"), owner.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, next_owner);
+
+    // Until acceptance the old owner keeps control and the pending owner has none.
+    assert_eq!(client.get_admin(), Some(owner.clone()));
+    let r = client.try_force_remove_blacklist_entry(&next_owner, &token, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    let r = client.try_accept_ownership(&stranger);
+    assert_eq!(r, Err(Ok(RevoraError::UnauthorizedTransferAccept)));
+
+    client.accept_ownership(&next_owner);
+    assert_eq!(client.get_admin(), Some(next_owner.clone()));
+    assert_eq!(client.get_pending_owner(), None);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("own_done"), owner.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, next_owner);
+
+    let r = client.try_force_remove_blacklist_entry(&owner, &token, &investor);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    client.force_remove_blacklist_entry(&next_owner, &token, &investor);
+}
+
+// ===========================================================================
+// Sorted offering pages
+// ===========================================================================
+
+#[test]
+fn offerings_page_sorted_by_bps() {
+    use crate::{SORT_BPS_ASC, SORT_BPS_DESC, SORT_INSERTION};
+
+    let (env, client, issuer) = setup();
+    let mut tokens = Vec::new(&env);
+    for bps in [500u32, 1_500, 1_000, 1_500] {
+        let token = Address::generate(&env);
+        client.register_offering(&issuer, &token, &bps, &token);
+        tokens.push_back(token);
+    }
+    let bps_of = |page: &Vec<crate::Offering>| {
+        let mut out = Vec::new(&env);
+        for o in page.iter() {
+            out.push_back(o.revenue_share_bps);
+        }
+        out
+    };
+
+    let (page, next) = client.get_offerings_page_sorted(&issuer, &0, &0, &SORT_BPS_DESC);
+    assert_eq!(bps_of(&page), vec![&env, 1_500, 1_500, 1_000, 500]);
+    assert_eq!(next, None);
+    // Ties keep registration order.
+    assert_eq!(page.get(0).unwrap().token, tokens.get(1).unwrap());
+    assert_eq!(page.get(1).unwrap().token, tokens.get(3).unwrap());
+
+    let (page, next) = client.get_offerings_page_sorted(&issuer, &0, &3, &SORT_BPS_ASC);
+    assert_eq!(bps_of(&page), vec![&env, 500, 1_000, 1_500]);
+    assert_eq!(next, Some(3));
+    let (page, next) = client.get_offerings_page_sorted(&issuer, &3, &3, &SORT_BPS_ASC);
+    assert_eq!(bps_of(&page), vec![&env, 1_500]);
+    assert_eq!(next, None);
+
+    let (page, _) = client.get_offerings_page_sorted(&issuer, &0, &0, &SORT_INSERTION);
+    assert_eq!(page, client.get_offerings_page(&issuer, &0, &0).0);
+
+    let r = client.try_get_offerings_page_sorted(&issuer, &0, &0, &3);
+    assert_eq!(r, Err(Ok(RevoraError::InvalidSortMode)));
+}
+
+// ===========================================================================
+// Metadata content hash
+// ===========================================================================
+
+#[test]
+fn metadata_hash_round_trips_and_follows_updates() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let uri_v1 = SdkString::from_str(&env, "ipfs://QmV1");
+    let uri_v2 = SdkString::from_str(&env, "ipfs://QmV2");
+    let hash_v1 = BytesN::from_array(&env, &[1u8; 32]);
+    let hash_v2 = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.set_offering_metadata_with_hash(&issuer, &token, &uri_v1, &hash_v1);
+    assert_eq!(
+        client.get_metadata_hash(&issuer, &token),
+        Some(hash_v1.clone())
+    );
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("meta_hash"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: (BytesN<32>, u32) = data.into_val(&env);
+    assert_eq!(data, (hash_v1, 1));
+
+    client.set_offering_metadata_with_hash(&issuer, &token, &uri_v2, &hash_v2);
+    assert_eq!(client.get_offering_metadata(&issuer, &token), Some(uri_v2));
+    assert_eq!(client.get_metadata_hash(&issuer, &token), Some(hash_v2));
+
+    // A plain update is allowed but drops the now-stale commitment.
+    client.set_offering_metadata(&issuer, &token, &uri_v1);
+    assert_eq!(client.get_metadata_hash(&issuer, &token), None);
+}
+
+// ===========================================================================
+// Removing all offerings
+// ===========================================================================
+
+#[test]
+fn remove_all_offerings_clears_issuer() {
+    let (env, client, issuer) = setup();
+    let other_issuer = Address::generate(&env);
+    let kept = Address::generate(&env);
+    client.register_offering(&other_issuer, &kept, &1_000, &kept);
+    let mut first = None;
+    for _ in 0..3 {
+        let token = Address::generate(&env);
+        client.register_offering(&issuer, &token, &1_000, &token);
+        first.get_or_insert(token);
+    }
+    let first = first.unwrap();
+    client.set_offering_metadata(&issuer, &first, &SdkString::from_str(&env, "ipfs://x"));
+
+    let r = client.try_remove_all_offerings(&issuer, &false);
+    assert_eq!(r, Err(Ok(RevoraError::ConfirmationRequired)));
+    assert_eq!(client.get_offering_count(&issuer), 3);
+
+    assert_eq!(client.remove_all_offerings(&issuer, &true), 3);
+    assert_eq!(client.get_offering_count(&issuer), 0);
+    let (page, next) = client.get_offerings_page(&issuer, &0, &0);
+    assert_eq!(page.len(), 0);
+    assert_eq!(next, None);
+    assert_eq!(client.get_offering_metadata(&issuer, &first), None);
+    assert_eq!(client.get_contract_stats().total_offerings, 1);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("iss_clear"), issuer.clone()).into_val(&env)
+    );
+    let data: u32 = data.into_val(&env);
+    assert_eq!(data, 3);
+
+    // The tokens are free to be registered again.
+    client.register_offering(&issuer, &first, &500, &first);
+    assert_eq!(client.remove_all_offerings(&other_issuer, &true), 1);
+    assert_eq!(client.remove_all_offerings(&other_issuer, &true), 0);
+}
+
+#[test]
+fn remove_all_offerings_drops_pending_issuer_transfers() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let proposed = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.propose_issuer_transfer(&token, &proposed);
+    client.remove_all_offerings(&issuer, &true);
+
+    let second_issuer = Address::generate(&env);
+    client.register_offering(&second_issuer, &token, &2_000, &token);
+    let r = client.try_accept_issuer_transfer(&token);
+    assert_eq!(r, Err(Ok(RevoraError::NoTransferPending)));
+    assert_eq!(client.get_offering_count(&second_issuer), 1);
+}
+
+#[test]
+fn period_distributable_floors_bps_share() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_500, &payout);
+    client.report_revenue(&issuer, &token, &payout, &1_000_000, &1, &false);
+    client.report_revenue(&issuer, &token, &payout, &999, &2, &false);
+
+    assert_eq!(
+        client.get_period_distributable(&issuer, &token, &1),
+        150_000
+    );
+    // 999 * 1500 / 10000 = 149.85, floored.
+    assert_eq!(client.get_period_distributable(&issuer, &token, &2), 149);
+
+    let r = client.try_get_period_distributable(&issuer, &token, &3);
+    assert_eq!(r, Err(Ok(RevoraError::ReportNotFound)));
+    let other = Address::generate(&env);
+    let r = client.try_get_period_distributable(&issuer, &other, &1);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+// ===========================================================================
+// Reporter contract
+// ===========================================================================
+
+/// Minimal settlement contract that reports revenue to Revora under its own address.
+#[soroban_sdk::contract]
+pub struct SettlementStub;
+
+#[soroban_sdk::contractimpl]
+impl SettlementStub {
+    pub fn settle(
+        env: Env,
+        revora: Address,
+        token: Address,
+        payout_asset: Address,
+        amount: i128,
+        period_id: u64,
+    ) {
+        RevoraRevenueShareClient::new(&env, &revora).report_revenue(
+            &env.current_contract_address(),
+            &token,
+            &payout_asset,
+            &amount,
+            &period_id,
+            &false,
+        );
+    }
+}
+
+#[test]
+fn reporter_contract_can_report_revenue() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    let stub_id = env.register_contract(None, SettlementStub);
+    let stub = SettlementStubClient::new(&env, &stub_id);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+
+    client.set_reporter_contract(&issuer, &token, &stub_id);
+    assert_eq!(
+        client.get_reporter_contract(&issuer, &token),
+        Some(stub_id.clone())
+    );
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("rep_c_set"), issuer.clone(), token.clone()).into_val(&env)
+    );
+
+    // No mocked auths from here on: the stub's own invocation authorizes the report.
+    env.set_auths(&[]);
+    stub.settle(&client.address, &token, &payout, &5_000, &1);
+
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(5_000));
+    let rev_rep = env.events().all().iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("rev_rep"), issuer.clone(), token.clone()).into_val(&env)
+    });
+    assert!(rev_rep);
+}
+
+// ===========================================================================
+// Event summary counters
+// ===========================================================================
+
+#[test]
+fn events_summary_counts_registrations_reports_and_blacklist_actions() {
+    let (env, client, issuer) = setup();
+    let payout = Address::generate(&env);
+    let token_a = Address::generate(&env);
+    client.register_offering(&issuer, &token_a, &1_000, &payout);
+    let mut batch = Vec::new(&env);
+    for _ in 0..2 {
+        let token = Address::generate(&env);
+        batch.push_back((token, 1_000u32, payout.clone()));
+    }
+    client.register_offerings(&issuer, &batch);
+
+    client.report_revenue(&issuer, &token_a, &payout, &100, &1, &false);
+    client.report_revenue_indexed(&issuer, &token_a, &payout, &100, &2, &false);
+    let investor = Address::generate(&env);
+    client.blacklist_add(&issuer, &token_a, &investor);
+    client.blacklist_remove(&issuer, &token_a, &investor);
+
+    let summary = client.get_events_summary();
+    assert_eq!(summary.get(symbol_short!("offer_reg")), Some(3));
+    assert_eq!(summary.get(symbol_short!("rev_rep")), Some(2));
+    assert_eq!(summary.get(symbol_short!("bl_add")), Some(1));
+    assert_eq!(summary.get(symbol_short!("bl_rem")), Some(1));
+    assert_eq!(summary.get(symbol_short!("offer_rm")), None);
+}
+
+#[test]
+fn blacklist_transfer_merges_entries_into_new_token() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.set_admin(&admin);
+    let old_token = Address::generate(&env);
+    client.register_offering(&issuer, &old_token, &1_000, &old_token);
+    let new_token = Address::generate(&env);
+    client.register_offering(&issuer, &new_token, &1_000, &new_token);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let expired = Address::generate(&env);
+    client.blacklist_add(&issuer, &old_token, &a);
+    client.blacklist_add_until(&issuer, &old_token, &b, &5_000);
+    client.blacklist_add(&issuer, &old_token, &c);
+    client.blacklist_add_until(&issuer, &old_token, &expired, &10);
+    client.blacklist_add(&issuer, &new_token, &c);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+
+    let r = client.try_blacklist_transfer(&stranger, &old_token, &new_token);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    assert_eq!(client.blacklist_transfer(&admin, &old_token, &new_token), 2);
+    for investor in [&a, &b, &c] {
+        assert!(client.is_blacklisted(&new_token, investor));
+    }
+    assert!(!client.is_blacklisted(&new_token, &expired));
+    assert_eq!(
+        client.get_blacklist(&new_token),
+        vec![&env, c, a, b.clone()]
+    );
+
+    // Expiries carry over.
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    assert!(!client.is_blacklisted(&new_token, &b));
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("bl_xfer"),
+            old_token.clone(),
+            new_token.clone()
+        )
+            .into_val(&env)
+    );
+    let data: u32 = data.into_val(&env);
+    assert_eq!(data, 2);
+}
+
+#[test]
+fn min_report_amount_rejects_dust_but_not_clawbacks() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+    assert_eq!(client.get_min_report_amount(&issuer, &token), 0);
+    client.set_min_report_amount(&issuer, &token, &1_000);
+    assert_eq!(client.get_min_report_amount(&issuer, &token), 1_000);
+
+    let r = client.try_report_revenue(&issuer, &token, &payout, &999, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::AmountBelowMinimum)));
+    let r = client.try_report_revenue(&issuer, &token, &payout, &0, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::AmountBelowMinimum)));
+
+    client.report_revenue(&issuer, &token, &payout, &1_000, &1, &false);
+    client.report_revenue(&issuer, &token, &payout, &-1, &2, &false);
+    client.report_revenue(&issuer, &token, &payout, &-1_000_000, &3, &false);
+    assert_eq!(client.get_revenue_report(&issuer, &token, &2), Some(-1));
+}
+
+// ===========================================================================
+// Per-offering pause
+// ===========================================================================
+
+#[test]
+fn pausing_one_offering_blocks_only_its_reports() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.set_admin(&admin);
+    let payout = Address::generate(&env);
+    let paused = Address::generate(&env);
+    let running = Address::generate(&env);
+    client.register_offering(&issuer, &paused, &1_000, &payout);
+    client.register_offering(&issuer, &running, &1_000, &payout);
+
+    let r = client.try_pause_offering(&stranger, &paused);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    client.pause_offering(&issuer, &paused);
+    assert!(client.is_offering_paused(&paused));
+    assert!(!client.is_offering_paused(&running));
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("off_pause"), issuer.clone(), paused.clone()).into_val(&env)
+    );
+
+    let r = client.try_report_revenue(&issuer, &paused, &payout, &100, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingPaused)));
+    client.report_revenue(&issuer, &running, &payout, &100, &1, &false);
+    assert_eq!(
+        client.get_offering(&issuer, &paused).status,
+        OfferingStatus::Paused
+    );
+
+    client.unpause_offering(&admin, &paused);
+    assert!(!client.is_offering_paused(&paused));
+    client.report_revenue(&issuer, &paused, &payout, &100, &1, &false);
+}
+
+// ===========================================================================
+// Offering lifecycle history
+// ===========================================================================
+
+#[test]
+fn offering_history_records_created_updated_and_closed() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    assert_eq!(client.get_offering_history(&issuer, &token), None);
+
+    env.ledger().set_timestamp(1_000);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+    env.ledger().set_timestamp(2_000);
+    client.update_offering(&issuer, &token, &1_500);
+    assert_eq!(
+        client.get_offering_history(&issuer, &token),
+        Some(OfferingHistory {
+            created_at: 1_000,
+            updated_at: Some(2_000),
+            closed_at: None,
+        })
+    );
+
+    env.ledger().set_timestamp(3_000);
+    client.close_offering(&issuer, &token);
+    let history = client.get_offering_history(&issuer, &token).unwrap();
+    assert_eq!(history.closed_at, Some(3_000));
+    assert_eq!(history.created_at, 1_000);
+
+    let other = Address::generate(&env);
+    assert_eq!(client.get_offering_history(&other, &token), None);
+}
+
+// ===========================================================================
+// Investor eligibility with reason
+// ===========================================================================
+
+#[test]
+fn get_my_eligibility_reports_reason_codes() {
+    use crate::{ELIGIBLE, INELIGIBLE_BLACKLISTED, INELIGIBLE_NOT_WHITELISTED};
+
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let investor = Address::generate(&env);
+    let blocked = Address::generate(&env);
+    let expiring = Address::generate(&env);
+    env.ledger().set_timestamp(1_000);
+
+    assert_eq!(
+        client.get_my_eligibility(&token, &investor),
+        (true, ELIGIBLE)
+    );
+
+    client.blacklist_add(&issuer, &token, &blocked);
+    client.blacklist_add_until(&issuer, &token, &expiring, &2_000);
+    assert_eq!(
+        client.get_my_eligibility(&token, &blocked),
+        (false, INELIGIBLE_BLACKLISTED)
+    );
+    assert_eq!(
+        client.get_my_eligibility(&token, &expiring),
+        (false, INELIGIBLE_BLACKLISTED)
+    );
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(
+        client.get_my_eligibility(&token, &expiring),
+        (true, ELIGIBLE)
+    );
+
+    client.set_whitelist_enabled(&issuer, &token, &true);
+    assert_eq!(
+        client.get_my_eligibility(&token, &investor),
+        (false, INELIGIBLE_NOT_WHITELISTED)
+    );
+    client.whitelist_add(&issuer, &token, &investor);
+    assert_eq!(
+        client.get_my_eligibility(&token, &investor),
+        (true, ELIGIBLE)
+    );
+    assert_eq!(
+        client.get_my_eligibility(&token, &blocked),
+        (false, INELIGIBLE_BLACKLISTED)
+    );
+}
+
+// ===========================================================================
+// Metadata idempotency
+// ===========================================================================
+
+#[test]
+fn setting_same_metadata_twice_emits_one_event() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+    let uri = SdkString::from_str(&env, "ipfs://QmSame");
+
+    let meta_events = |env: &Env| {
+        env.events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| {
+                let name: Symbol = topics.get(0).unwrap().into_val(env);
+                name == symbol_short!("meta_set") || name == symbol_short!("meta_upd")
+            })
+            .count()
+    };
+
+    client.set_offering_metadata(&issuer, &token, &uri);
+    assert_eq!(meta_events(&env), 1);
+    client.set_offering_metadata(&issuer, &token, &uri);
+    assert_eq!(meta_events(&env), 1);
+    assert_eq!(client.get_metadata_version(&issuer, &token), 1);
+
+    let changed = SdkString::from_str(&env, "ipfs://QmChanged");
+    client.set_offering_metadata(&issuer, &token, &changed);
+    assert_eq!(meta_events(&env), 2);
+    assert_eq!(client.get_metadata_version(&issuer, &token), 2);
+}
+
+// ===========================================================================
+// Storage migration
+// ===========================================================================
+
+/// Overwrite `issuer`'s offering at `index` with a pre-`active` (`LegacyOffering`) entry.
+fn write_legacy_offering(
+    env: &Env,
+    contract_id: &Address,
+    issuer: &Address,
+    index: u32,
+    token: &Address,
+    payout: &Address,
+) {
+    let legacy = crate::LegacyOffering {
+        issuer: issuer.clone(),
+        token: token.clone(),
+        revenue_share_bps: 1_500,
+        payout_asset: payout.clone(),
+    };
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::OfferItem(issuer.clone(), index), &legacy);
+    });
+}
+
+#[test]
+fn migrate_storage_upgrades_legacy_offerings() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let legacy_token = Address::generate(&env);
+    let current_token = Address::generate(&env);
+    let payout = Address::generate(&env);
+
+    let r = client.try_migrate_storage(&issuer);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    client.set_admin(&admin);
+
+    client.register_offering(&issuer, &legacy_token, &1_000, &payout);
+    client.register_offering(&issuer, &current_token, &2_000, &payout);
+    client.close_offering(&issuer, &current_token);
+    write_legacy_offering(&env, &contract_id, &issuer, 0, &legacy_token, &payout);
+
+    assert_eq!(client.migrate_storage(&issuer), 1);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("migrated"), issuer.clone()).into_val(&env)
+    );
+    let migrated: u32 = data.into_val(&env);
+    assert_eq!(migrated, 1);
+
+    let offering = client.get_offering(&issuer, &legacy_token);
+    assert_eq!(offering.revenue_share_bps, 1_500);
+    assert_eq!(offering.payout_asset, payout);
+    assert_eq!(offering.status, OfferingStatus::Active);
+    assert_eq!(offering.decimals, 7);
+    // Already-current entries are left untouched
+    assert_eq!(
+        client.get_offering(&issuer, &current_token).status,
+        OfferingStatus::Closed
+    );
+
+    assert_eq!(client.migrate_storage(&issuer), 0);
+}
+
+// ===========================================================================
+// Quote-currency (FX) reports
+// ===========================================================================
+
+#[test]
+fn report_fx_round_trips_quote_token_and_rate() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    let quote = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+
+    let r = client.try_report_revenue_fx(&issuer, &token, &payout, &5_000, &1, &(quote.clone(), 0));
+    assert_eq!(r, Err(Ok(RevoraError::InvalidFxRate)));
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), None);
+
+    let rate: i128 = 10_850_000; // 1.085 at 7 decimals
+    client.report_revenue_fx(&issuer, &token, &payout, &5_000, &1, &(quote.clone(), rate));
+    assert_eq!(
+        client.get_report_fx(&issuer, &token, &1),
+        Some((quote.clone(), rate))
+    );
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(5_000));
+    assert_eq!(client.get_report_fx(&issuer, &token, &2), None);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("rev_fx"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: (u64, Address, i128) = data.into_val(&env);
+    assert_eq!(data, (1, quote.clone(), rate));
+
+    // Re-reporting the period does not silently replace the amount or rate.
+    let r = client.try_report_revenue_fx(&issuer, &token, &payout, &7_000, &1, &(quote.clone(), 1));
+    assert_eq!(r, Err(Ok(RevoraError::DuplicatePeriod)));
+    assert_eq!(client.get_revenue_report(&issuer, &token, &1), Some(5_000));
+    assert_eq!(
+        client.get_report_fx(&issuer, &token, &1),
+        Some((quote, rate))
+    );
+}
+
+// ===========================================================================
+// Offerings page with total
+// ===========================================================================
+
+#[test]
+fn offerings_page_with_total_matches_offering_count() {
+    let (env, client, issuer) = setup();
+    let payout = Address::generate(&env);
+    for _ in 0..7 {
+        client.register_offering(&issuer, &Address::generate(&env), &1_000, &payout);
+    }
+
+    let (page, next, total) = client.get_offerings_page_with_total(&issuer, &0, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(next, Some(3));
+    assert_eq!(total, client.get_offering_count(&issuer));
+    assert_eq!(total, 7);
+
+    let (page, next, total) = client.get_offerings_page_with_total(&issuer, &6, &3);
+    assert_eq!(page.len(), 1);
+    assert_eq!(next, None);
+    assert_eq!(total, 7);
+
+    let other = Address::generate(&env);
+    let (page, next, total) = client.get_offerings_page_with_total(&other, &0, &3);
+    assert_eq!((page.len(), next, total), (0, None, 0));
+}
+
+// ===========================================================================
+// Multiple issuer admins
+// ===========================================================================
+
+#[test]
+fn revoked_admin_can_no_longer_set_metadata() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+
+    client.set_issuer_admin(&issuer, &first);
+    client.add_admin(&issuer, &second);
+    client.add_admin(&issuer, &third);
+    client.add_admin(&issuer, &second);
+    assert_eq!(
+        client.get_admins(&issuer),
+        vec![&env, first.clone(), second.clone(), third.clone()]
+    );
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("admin_add"), issuer.clone()).into_val(&env)
+    );
+
+    let uri = SdkString::from_str(&env, "ipfs://QmByAdmin");
+    client.set_offering_metadata(&second, &token, &uri);
+
+    client.revoke_admin(&issuer, &second);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("admin_rev"), issuer.clone()).into_val(&env)
+    );
+    let revoked: Address = data.into_val(&env);
+    assert_eq!(revoked, second);
+    assert_eq!(
+        client.get_admins(&issuer),
+        vec![&env, first.clone(), third.clone()]
+    );
+
+    let other = SdkString::from_str(&env, "ipfs://QmRevoked");
+    let r = client.try_set_offering_metadata(&second, &token, &other);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    client.set_offering_metadata(&third, &token, &other);
+
+    client.revoke_admin(&issuer, &first);
+    assert_eq!(client.get_issuer_admin(&issuer), None);
+    assert_eq!(client.get_admins(&issuer), vec![&env, third]);
+}
+
+// ===========================================================================
+// Incremental blacklist sync
+// ===========================================================================
+
+#[test]
+fn blacklist_since_returns_exactly_the_intervening_changes() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    assert_eq!(client.get_blacklist_version(&token), 0);
+
+    client.blacklist_add(&issuer, &token, &a);
+    client.blacklist_add(&issuer, &token, &b);
+    let synced = client.get_blacklist_version(&token);
+    assert_eq!(synced, 2);
+
+    client.blacklist_add(&issuer, &token, &c);
+    client.blacklist_add(&issuer, &token, &c); // no-op, not a change
+    client.blacklist_remove(&issuer, &token, &a);
+    client.blacklist_remove(&issuer, &token, &a); // no-op
+    assert_eq!(client.get_blacklist_version(&token), 4);
+
+    assert_eq!(
+        client.get_blacklist_since(&token, &synced),
+        vec![&env, (c.clone(), true), (a.clone(), false)]
+    );
+    assert_eq!(client.get_blacklist_since(&token, &4).len(), 0);
+    assert_eq!(client.get_blacklist_since(&token, &0).len(), 4);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.clear_blacklist(&admin, &token);
+    assert_eq!(
+        client.get_blacklist_since(&token, &4),
+        vec![&env, (b, false), (c, false)]
+    );
+}
+
+#[test]
+fn blacklist_since_is_bounded_by_page_limit() {
+    let (env, client, _issuer) = setup();
+    let token = Address::generate(&env);
+    blacklist_n(&env, &client, &token, 25);
+    assert_eq!(client.get_blacklist_version(&token), 25);
+
+    let first = client.get_blacklist_since(&token, &0);
+    assert_eq!(first.len(), 20);
+    let rest = client.get_blacklist_since(&token, &20);
+    assert_eq!(rest.len(), 5);
+}
+
+// ===========================================================================
+// Blacklist size cap
+// ===========================================================================
+
+#[test]
+fn blacklist_size_cap_blocks_additions_until_space_is_freed() {
+    let (env, client, issuer) = setup();
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    assert_eq!(client.get_max_blacklist_size(), 500);
+    client.set_max_blacklist_size(&3);
+    assert_eq!(client.get_max_blacklist_size(), 3);
+
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let d = Address::generate(&env);
+    client.blacklist_add_batch(&issuer, &token, &vec![&env, a.clone(), b.clone()]);
+    client.blacklist_add(&issuer, &token, &c);
+    // Re-adding an existing entry needs no space
+    client.blacklist_add(&issuer, &token, &c);
+
+    let r = client.try_blacklist_add(&issuer, &token, &d);
+    assert_eq!(r, Err(Ok(RevoraError::BlacklistFull)));
+    let r = client.try_blacklist_add_until(&issuer, &token, &d, &1_000);
+    assert_eq!(r, Err(Ok(RevoraError::BlacklistFull)));
+    assert_eq!(client.count_blacklisted(&token), 3);
+
+    client.blacklist_remove(&issuer, &token, &a);
+    client.blacklist_add(&issuer, &token, &d);
+    assert!(client.is_blacklisted(&token, &d));
+
+    // A batch that does not fit adds nothing
+    client.blacklist_remove(&issuer, &token, &b);
+    let batch = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let r = client.try_blacklist_add_batch(&issuer, &token, &batch);
+    assert_eq!(r, Err(Ok(RevoraError::BlacklistFull)));
+    assert_eq!(client.count_blacklisted(&token), 2);
+}
+
+// ===========================================================================
+// Claim deadlines and sweeping
+// ===========================================================================
+
+#[test]
+fn sweep_expired_returns_unclaimed_funds_only_after_deadline() {
+    let (env, client, issuer, token, payment_token, contract_id) = claim_setup();
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    client.set_holder_share(&issuer, &token, &early, &3_000);
+    client.set_holder_share(&issuer, &token, &late, &2_000);
+    env.ledger().set_timestamp(1_000);
+
+    let r = client.try_report_revenue_with_deadline(
+        &issuer,
+        &token,
+        &payment_token,
+        &100_000,
+        &1,
+        &1_000,
+    );
+    assert_eq!(r, Err(Ok(RevoraError::InvalidDeadline)));
+
+    client.report_revenue_with_deadline(&issuer, &token, &payment_token, &100_000, &1, &5_000);
+    assert_eq!(client.get_claim_deadline(&token, &1), Some(5_000));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("rev_dl"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: (u64, u64) = data.into_val(&env);
+    assert_eq!(data, (1, 5_000));
+
+    client.deposit_revenue(&issuer, &token, &payment_token, &100_000, &1);
+    assert_eq!(client.claim(&early, &token, &0), 30_000);
+
+    // The deadline of a reported period can be neither pulled in nor replaced.
+    let r = client.try_report_revenue_with_deadline(
+        &issuer,
+        &token,
+        &payment_token,
+        &100_000,
+        &1,
+        &1_001,
+    );
+    assert_eq!(r, Err(Ok(RevoraError::InvalidDeadline)));
+    let r = client.try_report_revenue_with_deadline(
+        &issuer,
+        &token,
+        &payment_token,
+        &100_000,
+        &1,
+        &6_000,
+    );
+    assert_eq!(r, Err(Ok(RevoraError::DuplicatePeriod)));
+    assert_eq!(client.get_claim_deadline(&token, &1), Some(5_000));
+
+    let r = client.try_sweep_expired(&issuer, &token, &1);
+    assert_eq!(r, Err(Ok(RevoraError::DeadlineNotReached)));
+
+    env.ledger().set_timestamp(5_000);
+    let issuer_before = balance(&env, &payment_token, &issuer);
+    assert_eq!(client.sweep_expired(&issuer, &token, &1), 70_000);
+    assert_eq!(
+        balance(&env, &payment_token, &issuer),
+        issuer_before + 70_000
+    );
+    assert_eq!(balance(&env, &payment_token, &contract_id), 0);
+    assert_eq!(client.get_claimable_liability(&payment_token), 0);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("swept"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: (u64, i128) = data.into_val(&env);
+    assert_eq!(data, (1, 70_000));
+
+    // The late holder's share went back to the issuer
+    assert_eq!(client.get_claimable(&token, &late), 0);
+    assert_eq!(client.claim(&late, &token, &0), 0);
+    assert_eq!(client.sweep_expired(&issuer, &token, &1), 0);
+}
+
+#[test]
+fn sweep_expired_without_deadline_fails() {
+    let (_env, client, issuer, token, payment_token, _contract_id) = claim_setup();
+    client.deposit_revenue(&issuer, &token, &payment_token, &100_000, &1);
+    let r = client.try_sweep_expired(&issuer, &token, &1);
+    assert_eq!(r, Err(Ok(RevoraError::DeadlineNotReached)));
+}
+
+// ===========================================================================
+// Offering count repair
+// ===========================================================================
+
+#[test]
+fn recompute_offering_count_repairs_desynced_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let payout = Address::generate(&env);
+
+    let r = client.try_recompute_offering_count(&issuer);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    client.set_admin(&admin);
+
+    for _ in 0..3 {
+        client.register_offering(&issuer, &Address::generate(&env), &1_000, &payout);
+    }
+    // Simulate a desync: the stored count no longer matches the three stored entries
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::OfferCount(issuer.clone()), &5u32);
+    });
+    assert_eq!(client.get_offering_count(&issuer), 5);
+
+    assert_eq!(client.recompute_offering_count(&issuer), 3);
+    assert_eq!(client.get_offering_count(&issuer), 3);
+    assert_eq!(client.get_offerings_page(&issuer, &0, &10).0.len(), 3);
+    assert_eq!(client.get_contract_stats().total_offerings, 3);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("cnt_fix"), issuer.clone()).into_val(&env)
+    );
+    let data: (u32, u32) = data.into_val(&env);
+    assert_eq!(data, (5, 3));
+
+    // A consistent count is left as it is
+    assert_eq!(client.recompute_offering_count(&issuer), 3);
+}
+
+// ===========================================================================
+// Blacklist appeals
+// ===========================================================================
+
+#[test]
+fn only_blacklisted_investors_can_file_an_appeal() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let blocked = Address::generate(&env);
+    let free = Address::generate(&env);
+    client.blacklist_add(&issuer, &token, &blocked);
+    let uri = SdkString::from_str(&env, "ipfs://QmAppeal");
+
+    client.request_blacklist_appeal(&blocked, &token, &uri);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("bl_appeal"), token.clone(), blocked.clone()).into_val(&env)
+    );
+    let filed: SdkString = data.into_val(&env);
+    assert_eq!(filed, uri);
+    assert!(client.is_blacklisted(&token, &blocked));
+
+    let r = client.try_request_blacklist_appeal(&free, &token, &uri);
+    assert_eq!(r, Err(Ok(RevoraError::NotBlacklisted)));
+
+    let empty = SdkString::from_str(&env, "");
+    let r = client.try_request_blacklist_appeal(&blocked, &token, &empty);
+    assert_eq!(r, Err(Ok(RevoraError::EmptyMetadata)));
+}
+
+// ===========================================================================
+// Token address correction
+// ===========================================================================
+
+#[test]
+fn set_offering_token_moves_offering_to_corrected_token() {
+    let (env, client, issuer) = setup();
+    let payout = Address::generate(&env);
+    let first = Address::generate(&env);
+    let wrong = Address::generate(&env);
+    let right = Address::generate(&env);
+    client.register_offering(&issuer, &first, &1_000, &payout);
+    client.register_offering(&issuer, &wrong, &2_500, &payout);
+    let uri = SdkString::from_str(&env, "ipfs://QmOffering");
+    client.set_offering_metadata(&issuer, &wrong, &uri);
+
+    let r = client.try_set_offering_token(&issuer, &wrong, &first);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateOffering)));
+
+    client.set_offering_token(&issuer, &wrong, &right);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("tok_fixed"), issuer.clone()).into_val(&env)
+    );
+    let data: (Address, Address) = data.into_val(&env);
+    assert_eq!(data, (wrong.clone(), right.clone()));
+
+    let offering = client.get_offering(&issuer, &right);
+    assert_eq!(offering.token, right);
+    assert_eq!(offering.revenue_share_bps, 2_500);
+    let r = client.try_get_offering(&issuer, &wrong);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+
+    // Index position and metadata are kept
+    let (page, _) = client.get_offerings_page(&issuer, &0, &10);
+    assert_eq!(page.get(1).unwrap().token, right);
+    assert_eq!(client.get_offering_count(&issuer), 2);
+    assert_eq!(client.get_offering_metadata(&issuer, &right), Some(uri));
+    assert_eq!(client.get_offering_metadata(&issuer, &wrong), None);
+    client.report_revenue(&issuer, &right, &payout, &1_000, &1, &false);
+}
+
+#[test]
+fn set_offering_token_keeps_the_report_cooldown() {
+    let (env, client, issuer) = setup();
+    let wrong = Address::generate(&env);
+    let right = Address::generate(&env);
+    client.register_offering(&issuer, &wrong, &1_000, &wrong);
+    client.set_report_cooldown(&issuer, &wrong, &3_600);
+    env.ledger().set_timestamp(10_000);
+    client.report_revenue(&issuer, &wrong, &wrong, &1_000, &1, &false);
+
+    client.set_offering_token(&issuer, &wrong, &right);
+    assert_eq!(client.get_report_cooldown(&right), 3_600);
+    assert_eq!(client.get_report_cooldown(&wrong), 0);
+    let r = client.try_report_revenue(&issuer, &right, &wrong, &1_000, &2, &false);
+    assert_eq!(r, Err(Ok(RevoraError::CooldownActive)));
+
+    env.ledger().set_timestamp(13_600);
+    client.report_revenue(&issuer, &right, &wrong, &1_000, &2, &false);
+}
+
+// ===========================================================================
+// Per-issuer report counter
+// ===========================================================================
+
+#[test]
+fn issuer_report_count_spans_all_tokens() {
+    let (env, client, issuer) = setup();
+    let payout = Address::generate(&env);
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    client.register_offering(&issuer, &token_a, &1_000, &payout);
+    client.register_offering(&issuer, &token_b, &1_000, &payout);
+    assert_eq!(client.get_issuer_report_count(&issuer), 0);
+
+    client.report_revenue(&issuer, &token_a, &payout, &1_000, &1, &false);
+    client.report_revenue(&issuer, &token_b, &payout, &2_000, &1, &false);
+    client.report_revenue(&issuer, &token_a, &payout, &3_000, &2, &false);
+    assert_eq!(client.get_issuer_report_count(&issuer), 3);
+
+    // Rejected duplicates and replayed idempotency keys are not counted
+    let r = client.try_report_revenue(&issuer, &token_a, &payout, &1_000, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicatePeriod)));
+    let key = BytesN::from_array(&env, &[7u8; 32]);
+    client.report_revenue_idempotent(&issuer, &token_b, &payout, &500, &2, &key);
+    let r = client.try_report_revenue_idempotent(&issuer, &token_b, &payout, &500, &3, &key);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateReport)));
+    assert_eq!(client.get_issuer_report_count(&issuer), 4);
+
+    assert_eq!(client.get_issuer_report_count(&Address::generate(&env)), 0);
+}
+
+// ===========================================================================
+// Chunked distribution
+// ===========================================================================
+
+#[test]
+fn distribution_chunks_match_single_shot_up_to_rounding() {
+    let (env, client, issuer, token) = distribution_setup(1_000_003);
+    let holders: [(Address, i128); 5] = [
+        (Address::generate(&env), 400),
+        (Address::generate(&env), 250),
+        (Address::generate(&env), 200),
+        (Address::generate(&env), 100),
+        (Address::generate(&env), 57),
+    ];
+    let total_supply: i128 = holders.iter().map(|(_, h)| h).sum();
+
+    let mut all = Map::new(&env);
+    let mut first = Map::new(&env);
+    let mut second = Map::new(&env);
+    for (i, (holder, holding)) in holders.iter().enumerate() {
+        all.set(holder.clone(), *holding);
+        if i < 2 {
+            first.set(holder.clone(), *holding);
+        } else {
+            second.set(holder.clone(), *holding);
+        }
+    }
+
+    let whole = client.compute_distribution(&issuer, &token, &1, &all);
+    let single: i128 = whole.values().iter().sum();
+    let chunk_a = client.compute_distribution_chunk(&issuer, &token, &1, &first, &total_supply);
+    let chunk_b = client.compute_distribution_chunk(&issuer, &token, &1, &second, &total_supply);
+    assert_eq!(chunk_a.len() + chunk_b.len(), 5);
+    let chunked: i128 =
+        chunk_a.values().iter().sum::<i128>() + chunk_b.values().iter().sum::<i128>();
+
+    // Pot = floor(1_000_003 * 15%) = 150_000; chunks only drop flooring remainders
+    assert_eq!(single, 150_000);
+    assert!(chunked <= single);
+    assert!(single - chunked < holders.len() as i128);
+    for (holder, _) in holders.iter() {
+        let whole = client
+            .compute_distribution(&issuer, &token, &1, &all)
+            .get(holder.clone())
+            .unwrap();
+        let part = chunk_a
+            .get(holder.clone())
+            .or(chunk_b.get(holder.clone()))
+            .unwrap();
+        assert!(whole - part <= single - chunked);
+    }
+
+    let r = client.try_compute_distribution_chunk(&issuer, &token, &1, &first, &0);
+    assert_eq!(r, Err(Ok(RevoraError::EmptyHoldings)));
+}
+
+// ===========================================================================
+// Offering config lock
+// ===========================================================================
+
+#[test]
+fn locked_offering_rejects_parameter_changes_but_accepts_reports() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &payout);
+    client.update_offering(&issuer, &token, &1_200);
+    assert!(!client.is_offering_config_locked(&token));
+
+    client.lock_offering_config(&issuer, &token);
+    assert!(client.is_offering_config_locked(&token));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("cfg_lock"), issuer.clone()).into_val(&env)
+    );
+    let locked: Address = data.into_val(&env);
+    assert_eq!(locked, token);
+
+    let r = client.try_update_offering(&issuer, &token, &2_000);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingLocked)));
+    let r = client.try_set_min_report_amount(&issuer, &token, &100);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingLocked)));
+    let r = client.try_set_claim_delay(&issuer, &token, &60);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingLocked)));
+    assert_eq!(
+        client.get_offering(&issuer, &token).revenue_share_bps,
+        1_200
+    );
+
+    client.report_revenue(&issuer, &token, &payout, &5_000, &1, &false);
+    client.set_offering_metadata(
+        &issuer,
+        &token,
+        &SdkString::from_str(&env, "ipfs://QmLocked"),
+    );
+    client.lock_offering_config(&issuer, &token);
+}
+
+#[test]
+fn removed_offering_does_not_leave_its_config_lock_behind() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.lock_offering_config(&issuer, &token);
+    client.remove_offering(&issuer, &token);
+    assert!(!client.is_offering_config_locked(&token));
+
+    let second_issuer = Address::generate(&env);
+    client.register_offering(&second_issuer, &token, &1_000, &token);
+    client.update_offering(&second_issuer, &token, &2_000);
+    assert_eq!(
+        client
+            .get_offering(&second_issuer, &token)
+            .revenue_share_bps,
+        2_000
+    );
+
+    client.lock_offering_config(&second_issuer, &token);
+    client.remove_all_offerings(&second_issuer, &true);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.update_offering(&issuer, &token, &3_000);
+    assert!(!client.is_offering_config_locked(&token));
+}
+
+// ===========================================================================
+// Default metadata fallback
+// ===========================================================================
+
+#[test]
+fn metadata_resolves_specific_then_default_then_none() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let series_a = SdkString::from_str(&env, "series-a");
+    client.register_offering_with_id(&issuer, &token, &1_000, &token, &series_a);
+
+    // Neither set
+    assert_eq!(client.get_metadata_resolved(&issuer, &series_a), None);
+    assert_eq!(client.get_offering_metadata(&issuer, &token), None);
+
+    // Issuer default only
+    let default_uri = SdkString::from_str(&env, "ipfs://QmDefault");
+    client.set_default_metadata(&issuer, &default_uri);
+    assert_eq!(
+        client.get_metadata_resolved(&issuer, &series_a),
+        Some((default_uri.clone(), crate::METADATA_SOURCE_DEFAULT))
+    );
+    assert_eq!(
+        client.get_offering_metadata(&issuer, &token),
+        Some(default_uri.clone())
+    );
+
+    // Offering-specific wins
+    let specific = SdkString::from_str(&env, "ipfs://QmSeriesA");
+    client.set_offering_metadata(&issuer, &token, &specific);
+    assert_eq!(
+        client.get_metadata_resolved(&issuer, &series_a),
+        Some((specific, crate::METADATA_SOURCE_OFFERING))
+    );
+
+    // Unknown id
+    let unknown = SdkString::from_str(&env, "series-z");
+    assert_eq!(client.get_metadata_resolved(&issuer, &unknown), None);
+}
+
+#[test]
+fn set_default_metadata_validates_and_emits() {
+    let (env, client, issuer) = setup();
+    let r = client.try_set_default_metadata(&issuer, &SdkString::from_str(&env, ""));
+    assert_eq!(r, Err(Ok(RevoraError::EmptyMetadata)));
+
+    let uri = SdkString::from_str(&env, "https://example.com/issuer.json");
+    client.set_default_metadata(&issuer, &uri);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("meta_dflt"), issuer.clone()).into_val(&env)
+    );
+    let emitted: SdkString = data.into_val(&env);
+    assert_eq!(emitted, uri);
+}
+
+// ===========================================================================
+// Custom integrator events
+// ===========================================================================
+
+#[test]
+fn emit_custom_event_publishes_tag_issuer_token() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    let data = SdkString::from_str(&env, "{\"order\":42}");
+    client.emit_custom_event(&issuer, &token, &symbol_short!("app_sync"), &data);
+
+    let (_, topics, payload) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("app_sync"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let emitted: SdkString = payload.into_val(&env);
+    assert_eq!(emitted, data);
+}
+
+#[test]
+fn emit_custom_event_rejects_reserved_tags_long_data_and_other_issuers() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let data = SdkString::from_str(&env, "x");
+
+    let r = client.try_emit_custom_event(&issuer, &token, &symbol_short!("rev_rep"), &data);
+    assert_eq!(r, Err(Ok(RevoraError::ReservedEventTag)));
+    let r = client.try_emit_custom_event(&issuer, &token, &symbol_short!("offer_reg"), &data);
+    assert_eq!(r, Err(Ok(RevoraError::ReservedEventTag)));
+
+    let long = SdkString::from_bytes(&env, &[b'a'; 257]);
+    let r = client.try_emit_custom_event(&issuer, &token, &symbol_short!("app"), &long);
+    assert_eq!(r, Err(Ok(RevoraError::MemoTooLong)));
+
+    let stranger = Address::generate(&env);
+    let r = client.try_emit_custom_event(&stranger, &token, &symbol_short!("app"), &data);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingNotFound)));
+}
+
+// ===========================================================================
+// Token reverse lookup
+// ===========================================================================
+
+#[test]
+fn get_offerings_for_token_finds_offering_across_issuers() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    assert_eq!(client.get_offerings_for_token(&token).len(), 0);
+
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let other_issuer = Address::generate(&env);
+    client.register_offering(&other_issuer, &token, &2_000, &token);
+
+    let found = client.get_offerings_for_token(&token);
+    assert_eq!(found.len(), 2);
+    assert_eq!(found.get(0).unwrap().issuer, issuer);
+    assert_eq!(found.get(0).unwrap().revenue_share_bps, 1_000);
+    assert_eq!(found.get(1).unwrap().issuer, other_issuer);
+    assert_eq!(found.get(1).unwrap().revenue_share_bps, 2_000);
+
+    let (page, next) = client.get_offerings_for_token_page(&token, &0, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(next, Some(1));
+    let (page, next) = client.get_offerings_for_token_page(&token, &1, &1);
+    assert_eq!(page.get(0).unwrap().issuer, other_issuer);
+    assert_eq!(next, None);
+
+    // An issuer cannot be handed a second offering for a token it already holds.
+    let r = client.try_transfer_issuer(&issuer, &other_issuer, &token);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicateOffering)));
+
+    // Removing the current issuer's offering drops it and hands the token to the next.
+    client.remove_offering(&issuer, &token);
+    let found = client.get_offerings_for_token(&token);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found.get(0).unwrap().issuer, other_issuer);
+    client.propose_issuer_transfer(&token, &issuer);
+    assert_eq!(
+        client.get_pending_issuer_transfer(&token),
+        Some(issuer.clone())
+    );
+
+    client.remove_offering(&other_issuer, &token);
+    assert_eq!(client.get_offerings_for_token(&token).len(), 0);
+}
+
+// ===========================================================================
+// Report dry run
+// ===========================================================================
+
+#[test]
+fn dry_run_report_previews_without_side_effects() {
+    let (env, client, issuer, token) = distribution_setup(1_000_000);
+    blacklist_n(&env, &client, &token, 2);
+    let events_before = env.events().all().len();
+
+    // 15% of 10_000, no platform fee
+    let preview = client.dry_run_report(&issuer, &token, &10_000, &2);
+    assert_eq!(preview, (1_500, 2, 0));
+    let duplicate = client.dry_run_report(&issuer, &token, &10_000, &1);
+    assert_eq!(duplicate.2, RevoraError::DuplicatePeriod as u32);
+
+    assert_eq!(env.events().all().len(), events_before);
+    assert_eq!(client.get_last_reported_period(&issuer, &token), Some(1));
+}
+
+#[test]
+fn dry_run_report_returns_closed_code_without_failing() {
+    let (env, client, issuer, token) = distribution_setup(1_000_000);
+    client.close_offering(&issuer, &token);
+
+    let (distributable, blacklist_count, code) =
+        client.dry_run_report(&issuer, &token, &10_000, &2);
+    assert_eq!(code, RevoraError::OfferingClosed as u32);
+    assert_eq!((distributable, blacklist_count), (1_500, 0));
+
+    let unknown = client.dry_run_report(&issuer, &Address::generate(&env), &10_000, &2);
+    assert_eq!(unknown, (0, 0, RevoraError::OfferingNotFound as u32));
+}
+
+// ===========================================================================
+// Batch revenue reporting
+// ===========================================================================
+
+fn rev_rep_count(env: &Env, issuer: &Address, token: &Address) -> u32 {
+    let topics: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("rev_rep"), issuer.clone(), token.clone()).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, t, _)| *t == topics)
+        .count() as u32
+}
+
+#[test]
+fn batch_report_revenue_reports_each_period() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    let reports = vec![&env, (1_000_i128, 1_u64), (2_000, 2), (3_000, 3)];
+    client.batch_report_revenue(&issuer, &token, &reports);
+
+    assert_eq!(rev_rep_count(&env, &issuer, &token), 3);
+    assert_eq!(client.get_last_reported_period(&issuer, &token), Some(3));
+}
+
+#[test]
+fn batch_report_revenue_with_duplicate_fails_atomically() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let events_before = env.events().all().len();
+
+    let reports = vec![&env, (1_000_i128, 1_u64), (2_000, 2), (3_000, 1)];
+    let r = client.try_batch_report_revenue(&issuer, &token, &reports);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicatePeriod)));
+    assert_eq!(env.events().all().len(), events_before);
+    assert_eq!(client.get_last_reported_period(&issuer, &token), None);
+
+    // Already-reported periods count as duplicates too.
+    client.report_revenue(&issuer, &token, &token, &500, &2, &false);
+    let reports = vec![&env, (1_000_i128, 1_u64), (2_000, 2)];
+    let r = client.try_batch_report_revenue(&issuer, &token, &reports);
+    assert_eq!(r, Err(Ok(RevoraError::DuplicatePeriod)));
+    assert_eq!(rev_rep_count(&env, &issuer, &token), 1);
+
+    client.set_period_guard(&issuer, &issuer, &token, &true, &false);
+    let reports = vec![&env, (1_000_i128, 5_u64), (2_000, 4)];
+    let r = client.try_batch_report_revenue(&issuer, &token, &reports);
+    assert_eq!(r, Err(Ok(RevoraError::NonMonotonicPeriod)));
+
+    let too_many = Vec::from_array(&env, [(1_i128, 10_u64); 21]);
+    let r = client.try_batch_report_revenue(&issuer, &token, &too_many);
+    assert_eq!(r, Err(Ok(RevoraError::BatchTooLarge)));
+}
+
+// ===========================================================================
+// Contract version
+// ===========================================================================
+
+#[test]
+fn get_contract_version_returns_constant() {
+    let env = Env::default();
+    let client = make_client(&env);
+    assert_eq!(client.get_contract_version(), crate::CONTRACT_VERSION);
+    assert_eq!(client.get_contract_version(), 3);
+}
+
+// ===========================================================================
+// Blacklist batch preview
+// ===========================================================================
+
+#[test]
+fn preview_blacklist_batch_counts_new_and_present_without_writing() {
+    let (env, client, _issuer) = setup();
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let token = Address::generate(&env);
+    let existing = Address::generate(&env);
+    client.blacklist_add(&admin, &token, &existing);
+    let events_before = env.events().all().len();
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let batch = vec![&env, a.clone(), existing.clone(), b.clone()];
+    assert_eq!(client.preview_blacklist_batch(&token, &batch), (2, 1));
+
+    assert_eq!(env.events().all().len(), events_before);
+    assert!(!client.is_blacklisted(&token, &a));
+    assert_eq!(client.get_blacklist(&token).len(), 1);
+
+    // Repeats within the batch would be skipped.
+    let repeated = vec![&env, a.clone(), a];
+    assert_eq!(client.preview_blacklist_batch(&token, &repeated), (1, 1));
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..101 {
+        too_many.push_back(Address::generate(&env));
+    }
+    let r = client.try_preview_blacklist_batch(&token, &too_many);
+    assert_eq!(r, Err(Ok(RevoraError::BatchTooLarge)));
+}
+
+// ===========================================================================
+// Report cooldown
+// ===========================================================================
+
+#[test]
+fn report_cooldown_rejects_reports_inside_window() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_report_cooldown(&issuer, &token, &3_600);
+    assert_eq!(client.get_report_cooldown(&token), 3_600);
+
+    env.ledger().set_timestamp(10_000);
+    client.report_revenue(&issuer, &token, &token, &1_000, &1, &false);
+
+    env.ledger().set_timestamp(13_599);
+    let r = client.try_report_revenue(&issuer, &token, &token, &1_000, &2, &false);
+    assert_eq!(r, Err(Ok(RevoraError::CooldownActive)));
+    assert_eq!(
+        client.dry_run_report(&issuer, &token, &1_000, &2).2,
+        RevoraError::CooldownActive as u32
+    );
+
+    // Clawbacks are exempt and do not restart the window.
+    client.report_revenue(&issuer, &token, &token, &-100, &1, &true);
+
+    env.ledger().set_timestamp(13_600);
+    client.report_revenue(&issuer, &token, &token, &1_000, &2, &false);
+    assert_eq!(client.get_last_reported_period(&issuer, &token), Some(2));
+}
+
+#[test]
+fn report_cooldown_treats_batch_as_one_report_and_respects_lock() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.set_report_cooldown(&issuer, &token, &60);
+
+    let reports = vec![&env, (1_000_i128, 1_u64), (2_000, 2)];
+    client.batch_report_revenue(&issuer, &token, &reports);
+    let reports = vec![&env, (3_000_i128, 3_u64)];
+    let r = client.try_batch_report_revenue(&issuer, &token, &reports);
+    assert_eq!(r, Err(Ok(RevoraError::CooldownActive)));
+
+    client.lock_offering_config(&issuer, &token);
+    let r = client.try_set_report_cooldown(&issuer, &token, &0);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingLocked)));
+}
+
+// ===========================================================================
+// Issuer enumeration
+// ===========================================================================
+
+#[test]
+fn get_issuers_page_lists_each_issuer_once_until_cleared() {
+    let (env, client, issuer_a) = setup();
+    let issuer_b = Address::generate(&env);
+    let (token_a1, token_a2, token_b) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    client.register_offering(&issuer_a, &token_a1, &1_000, &token_a1);
+    client.register_offering(&issuer_b, &token_b, &1_000, &token_b);
+    client.register_offering(&issuer_a, &token_a2, &1_000, &token_a2);
+
+    let (page, next) = client.get_issuers_page(&0, &0);
+    assert_eq!(page, vec![&env, issuer_a.clone(), issuer_b.clone()]);
+    assert_eq!(next, None);
+
+    let (page, next) = client.get_issuers_page(&0, &1);
+    assert_eq!(page, vec![&env, issuer_a.clone()]);
+    assert_eq!(next, Some(1));
+
+    // Removing one of two offerings keeps the issuer listed.
+    client.remove_offering(&issuer_a, &token_a1);
+    assert_eq!(client.get_issuers_page(&0, &0).0.len(), 2);
+
+    client.remove_all_offerings(&issuer_a, &true);
+    assert_eq!(
+        client.get_issuers_page(&0, &0).0,
+        vec![&env, issuer_b.clone()]
+    );
+
+    client.remove_offering(&issuer_b, &token_b);
+    assert_eq!(client.get_issuers_page(&0, &0), (Vec::new(&env), None));
+}
+
+// ===========================================================================
+// Metadata content type
+// ===========================================================================
+
+#[test]
+fn metadata_content_type_round_trips_and_defaults_to_unknown() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let id = SdkString::from_str(&env, "series-a");
+    client.register_offering_with_id(&issuer, &token, &1_000, &token, &id);
+    assert_eq!(client.get_metadata_full(&issuer, &id), None);
+
+    let plain = SdkString::from_str(&env, "ipfs://QmPlain");
+    client.set_offering_metadata(&issuer, &token, &plain);
+    assert_eq!(
+        client.get_metadata_full(&issuer, &id),
+        Some((plain, crate::METADATA_TYPE_UNKNOWN, 1))
+    );
+
+    let doc = SdkString::from_str(&env, "ipfs://QmDoc");
+    client.set_offering_metadata_with_type(&issuer, &token, &doc, &symbol_short!("json"));
+    assert_eq!(
+        client.get_metadata_full(&issuer, &id),
+        Some((doc.clone(), symbol_short!("json"), 2))
+    );
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("meta_type"), issuer.clone(), token.clone()).into_val(&env)
+    );
+    let data: (Symbol, u32) = data.into_val(&env);
+    assert_eq!(data, (symbol_short!("json"), 2));
+
+    // Same value and type again is a no-op; a plain update clears the type.
+    client.set_offering_metadata_with_type(&issuer, &token, &doc, &symbol_short!("json"));
+    assert_eq!(client.get_metadata_version(&issuer, &token), 2);
+    client.set_offering_metadata(&issuer, &token, &doc);
+    assert_eq!(
+        client.get_metadata_full(&issuer, &id),
+        Some((doc, crate::METADATA_TYPE_UNKNOWN, 3))
+    );
+}
+
+// ===========================================================================
+// Reentrancy guard
+// ===========================================================================
+
+mod reentrant_token {
+    use crate::RevoraRevenueShareClient;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    /// Token whose `transfer` tries to re-enter the revenue-share contract's `claim`
+    /// and records whether that call was rejected.
+    #[contract]
+    pub struct ReentrantToken;
+
+    #[contractimpl]
+    impl ReentrantToken {
+        pub fn arm(env: Env, target: Address, holder: Address, offering_token: Address) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("target"), &(target, holder, offering_token));
+        }
+
+        pub fn transfer(env: Env, _from: Address, _to: Address, _amount: i128) {
+            let (target, holder, offering_token): (Address, Address, Address) = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("target"))
+                .unwrap();
+            let rejected = RevoraRevenueShareClient::new(&env, &target)
+                .try_claim(&holder, &offering_token, &0)
+                .is_err();
+            env.storage()
+                .instance()
+                .set(&symbol_short!("rejected"), &rejected);
+        }
+
+        pub fn reentry_rejected(env: Env) -> bool {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("rejected"))
+                .unwrap_or(false)
+        }
+    }
+}
+
+#[test]
+fn reentrant_claim_from_token_transfer_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let mock_id = env.register_contract(None, reentrant_token::ReentrantToken);
+    let mock = reentrant_token::ReentrantTokenClient::new(&env, &mock_id);
+
+    let issuer = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &5_000, &mock_id);
+    mock.arm(&contract_id, &holder, &token);
+
+    client.deposit_revenue(&issuer, &token, &mock_id, &1_000, &1);
+    assert!(mock.reentry_rejected());
+    assert_eq!(client.get_period_count(&token), 1);
+}
+
+#[test]
+fn token_moving_calls_fail_while_guard_is_held() {
+    let (env, client, issuer, token, payment_token, contract_id) = claim_setup();
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .temporary()
+            .set(&crate::DataKeyExt::Entered, &true);
+    });
+
+    let reentrancy = soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::Reentrancy as u32);
+    let holder = Address::generate(&env);
+    let r = client.try_claim(&holder, &token, &0);
+    assert_eq!(r.unwrap_err().unwrap_err(), reentrancy);
+    let r = client.try_deposit_revenue(&issuer, &token, &payment_token, &1_000, &9);
+    assert_eq!(r.unwrap_err().unwrap_err(), reentrancy);
+}
+
+// ===========================================================================
+// Period claim status
+// ===========================================================================
+
+#[test]
+fn get_period_claim_status_tracks_claimed_total_and_claimants() {
+    let (env, client, issuer, token, payment_token, _contract_id) = claim_setup();
+    let holders = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for (holder, share) in holders.iter().zip([5_000_u32, 3_000, 2_000]) {
+        client.set_holder_share(&issuer, &token, holder, &share);
+    }
+    assert_eq!(client.get_period_claim_status(&token, &1), (0, 0, 0));
+
+    client.deposit_revenue(&issuer, &token, &payment_token, &100_000, &1);
+    assert_eq!(client.get_period_claim_status(&token, &1), (100_000, 0, 0));
+
+    client.claim(&holders[0], &token, &0);
+    client.claim(&holders[1], &token, &0);
+    assert_eq!(
+        client.get_period_claim_status(&token, &1),
+        (100_000, 80_000, 2)
+    );
+}
+
+// ===========================================================================
+// Contract deprecation
+// ===========================================================================
+
+#[test]
+fn mark_deprecated_blocks_registration_and_reports_but_not_reads_or_claims() {
+    let (env, client, issuer, token, payment_token, _contract_id) = claim_setup();
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let holder = Address::generate(&env);
+    client.set_holder_share(&issuer, &token, &holder, &5_000);
+    client.deposit_revenue(&issuer, &token, &payment_token, &100_000, &1);
+
+    let r = client.try_mark_deprecated(&Address::generate(&env));
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    client.mark_deprecated(&admin);
+    assert!(client.is_deprecated());
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("deprecate"), admin.clone()).into_val(&env)
+    );
+
+    let deprecated =
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::ContractDeprecated as u32);
+    let new_token = Address::generate(&env);
+    let r = client.try_register_offering(&issuer, &new_token, &1_000, &new_token);
+    assert_eq!(r.unwrap_err().unwrap_err(), deprecated);
+    let r = client.try_report_revenue(&issuer, &token, &payment_token, &1_000, &1, &false);
+    assert_eq!(r.unwrap_err().unwrap_err(), deprecated);
+    assert_eq!(
+        client.dry_run_report(&issuer, &token, &1_000, &1).2,
+        crate::RevoraErrorExt::ContractDeprecated as u32
+    );
+
+    let (page, next) = client.get_offerings_page(&issuer, &0, &10);
+    assert_eq!((page.len(), next), (1, None));
+    assert_eq!(client.claim(&holder, &token, &0), 50_000);
+}
+
+// ===========================================================================
+// Accumulator overflow
+// ===========================================================================
+
+#[test]
+fn cumulative_revenue_adds_normally_up_to_the_i128_boundary() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+
+    client.report_revenue(&issuer, &token, &token, &100, &1, &false);
+    client.report_revenue(&issuer, &token, &token, &200, &2, &false);
+    assert_eq!(client.get_total_revenue(&issuer, &token), 300);
+
+    // Exactly reaching i128::MAX is fine; overriding back down is too.
+    client.report_revenue(&issuer, &token, &token, &(i128::MAX - 300), &3, &false);
+    assert_eq!(client.get_total_revenue(&issuer, &token), i128::MAX);
+    client.report_revenue(&issuer, &token, &token, &0, &3, &true);
+    assert_eq!(client.get_total_revenue(&issuer, &token), 300);
+
+    // Overriding a huge clawback with a huge report overflows the delta itself.
+    client.report_revenue(&issuer, &token, &token, &i128::MIN, &4, &false);
+    let r = client.try_report_revenue(&issuer, &token, &token, &i128::MAX, &4, &true);
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::Overflow as u32)
+    );
+}
+
+// ===========================================================================
+// Register with metadata
+// ===========================================================================
+
+#[test]
+fn register_offering_with_metadata_registers_and_emits_both_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let offering_id = SdkString::from_str(&env, "series-a");
+    let uri = SdkString::from_str(&env, "ipfs://QmSeriesA");
+
+    client.register_offering_with_metadata(&issuer, &token, &1_500, &token, &offering_id, &uri);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                contract_id.clone(),
+                (
+                    symbol_short!("offer_reg"),
+                    issuer.clone(),
+                    offering_id.clone()
+                )
+                    .into_val(&env),
+                (token.clone(), 1_500u32, token.clone()).into_val(&env),
+            ),
+            (
+                contract_id.clone(),
+                (symbol_short!("meta_set"), issuer.clone(), token.clone()).into_val(&env),
+                uri.clone().into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(
+        client
+            .get_offering_by_id(&issuer, &offering_id)
+            .unwrap()
+            .token,
+        token
+    );
+    assert_eq!(client.get_offering_metadata(&issuer, &token), Some(uri));
+    assert_eq!(client.get_metadata_version(&issuer, &token), 1);
+}
+
+#[test]
+fn register_offering_with_metadata_writes_nothing_when_validation_fails() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let offering_id = SdkString::from_str(&env, "series-a");
+    let uri = SdkString::from_str(&env, "ipfs://QmSeriesA");
+
+    let r = client.try_register_offering_with_metadata(
+        &issuer,
+        &token,
+        &10_001,
+        &token,
+        &offering_id,
+        &uri,
+    );
+    assert_eq!(r, Err(Ok(RevoraError::InvalidRevenueShareBps)));
+    assert_eq!(client.get_offering_metadata(&issuer, &token), None);
+    assert_eq!(client.get_metadata_version(&issuer, &token), 0);
+
+    let empty = SdkString::from_str(&env, "");
+    let r = client.try_register_offering_with_metadata(
+        &issuer,
+        &token,
+        &1_500,
+        &token,
+        &offering_id,
+        &empty,
+    );
+    assert_eq!(r, Err(Ok(RevoraError::EmptyMetadata)));
+    assert_eq!(client.get_offering_count(&issuer), 0);
+    assert_eq!(client.get_offering_by_id(&issuer, &offering_id), None);
+    assert!(env.events().all().is_empty());
+}
+
+// ===========================================================================
+// Checked offerings pagination
+// ===========================================================================
+
+#[test]
+fn get_offerings_page_checked_rejects_cursor_past_count() {
+    let (env, client, issuer) = setup();
+    for _ in 0..3 {
+        let token = Address::generate(&env);
+        client.register_offering(&issuer, &token, &1_000, &token);
+    }
+
+    let (page, next) = client.get_offerings_page_checked(&issuer, &0, &2);
+    assert_eq!((page.len(), next), (2, Some(2)));
+    let (page, next) = client.get_offerings_page_checked(&issuer, &3, &2);
+    assert_eq!((page.len(), next), (0, None));
+
+    // A cursor from a larger issuer's pagination: the checked variant errors, the
+    // unchecked one still returns an empty page.
+    let r = client.try_get_offerings_page_checked(&issuer, &4, &2);
+    assert_eq!(r, Err(Ok(crate::RevoraErrorExt::InvalidCursor)));
+    let (page, next) = client.get_offerings_page(&issuer, &4, &2);
+    assert_eq!((page.len(), next), (0, None));
+
+    let other = Address::generate(&env);
+    let r = client.try_get_offerings_page_checked(&other, &1, &2);
+    assert_eq!(r, Err(Ok(crate::RevoraErrorExt::InvalidCursor)));
+}
+
+// ===========================================================================
+// Fee destination allowlist
+// ===========================================================================
+
+#[test]
+fn withdraw_fees_only_pays_the_registered_destination() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let (payout_asset, asset_admin) = create_payment_token(&env);
+    client.initialize(&admin, &None::<Address>, &250);
+    client.register_offering(&issuer, &token, &1_000, &payout_asset);
+    client.report_revenue(&issuer, &token, &payout_asset, &1_000_000, &1, &false);
+    mint_tokens(&env, &payout_asset, &asset_admin, &contract_id, &25_000);
+
+    let stranger = Address::generate(&env);
+    let r = client.try_set_fee_destination(&stranger, &treasury);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    // The first registration applies immediately.
+    client.set_fee_destination(&admin, &treasury);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("fee_dest"), admin.clone()).into_val(&env)
+    );
+    let data: (Address, u64) = data.into_val(&env);
+    assert_eq!(data, (treasury.clone(), 0));
+    assert_eq!(client.get_fee_destination(), Some(treasury.clone()));
+    assert_eq!(client.get_pending_fee_destination(), None);
+
+    let r = client.try_withdraw_fees(&admin, &payout_asset, &attacker);
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::UnregisteredDestination as u32)
+    );
+    assert_eq!(client.get_accrued_fees(&payout_asset), 25_000);
+
+    assert_eq!(
+        client.withdraw_fees(&admin, &payout_asset, &treasury),
+        25_000
+    );
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("fee_wd"),
+            payout_asset.clone(),
+            treasury.clone()
+        )
+            .into_val(&env)
+    );
+    let data: i128 = data.into_val(&env);
+    assert_eq!(data, 25_000);
+    assert_eq!(balance(&env, &payout_asset, &treasury), 25_000);
+    assert_eq!(balance(&env, &payout_asset, &attacker), 0);
+}
+
+#[test]
+fn changing_fee_destination_waits_for_the_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let new_treasury = Address::generate(&env);
+    let asset = Address::generate(&env);
+    client.initialize(&admin, &None::<Address>, &250);
+    env.ledger().set_timestamp(1_000);
+
+    let r = client.try_accept_fee_destination(&admin);
+    assert_eq!(r, Err(Ok(RevoraError::NoTransferPending)));
+
+    client.set_fee_destination(&admin, &treasury);
+    client.set_fee_destination(&admin, &new_treasury);
+    let effective_at = 1_000 + crate::FEE_DESTINATION_DELAY_SECS;
+    assert_eq!(
+        client.get_pending_fee_destination(),
+        Some((new_treasury.clone(), effective_at))
+    );
+    assert_eq!(client.get_fee_destination(), Some(treasury.clone()));
+
+    // Until accepted, only the old destination may receive fees.
+    let r = client.try_withdraw_fees(&admin, &asset, &new_treasury);
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::UnregisteredDestination as u32)
+    );
+
+    env.ledger().set_timestamp(effective_at - 1);
+    let r = client.try_accept_fee_destination(&admin);
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::TimelockActive as u32)
+    );
+    assert_eq!(client.get_fee_destination(), Some(treasury.clone()));
+
+    env.ledger().set_timestamp(effective_at);
+    client.accept_fee_destination(&admin);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("fee_dacc"), admin.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, new_treasury);
+    assert_eq!(client.get_fee_destination(), Some(new_treasury.clone()));
+    assert_eq!(client.get_pending_fee_destination(), None);
+    assert_eq!(client.withdraw_fees(&admin, &asset, &new_treasury), 0);
+}
+
+// ===========================================================================
+// Supply cap
+// ===========================================================================
+
+#[test]
+fn record_holdings_enforces_the_offering_supply_cap() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    client.register_offering_with_cap(&issuer, &token, &1_000, &token, &1_000);
+    assert_eq!(client.get_offering_supply_cap(&token), 1_000);
+    assert_eq!(client.get_offering(&issuer, &token).max_supply, 1_000);
+
+    let mut holdings = Map::new(&env);
+    holdings.set(a.clone(), 600i128);
+    holdings.set(b.clone(), 400i128);
+    client.record_holdings(&issuer, &token, &1, &holdings);
+    assert_eq!(client.get_holdings(&token, &1), Some(holdings.clone()));
+
+    holdings.set(b.clone(), 401i128);
+    let r = client.try_record_holdings(&issuer, &token, &2, &holdings);
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::SupplyCapExceeded as u32)
+    );
+    assert_eq!(client.get_holdings(&token, &2), None);
+
+    // Uncapped offerings accept any positive total.
+    let uncapped = Address::generate(&env);
+    client.register_offering(&issuer, &uncapped, &1_000, &uncapped);
+    assert_eq!(client.get_offering_supply_cap(&uncapped), 0);
+    client.record_holdings(&issuer, &uncapped, &1, &holdings);
+
+    let r = client.try_register_offering_with_cap(
+        &issuer,
+        &Address::generate(&env),
+        &1_000,
+        &token,
+        &-1,
+    );
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::InvalidSupplyCap as u32)
+    );
+    assert_eq!(client.get_offering_supply_cap(&Address::generate(&env)), 0);
+}
+
+#[test]
+fn migrate_storage_adds_uncapped_supply_to_v2_offerings() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.set_admin(&admin);
+    client.register_offering_with_decimals(&issuer, &token, &1_000, &token, &6);
+
+    let v2 = crate::OfferingV2 {
+        issuer: issuer.clone(),
+        token: token.clone(),
+        revenue_share_bps: 1_000,
+        payout_asset: token.clone(),
+        active: true,
+        decimals: 6,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::OfferItem(issuer.clone(), 0), &v2);
+    });
+
+    assert_eq!(client.migrate_storage(&issuer), 1);
+    let offering = client.get_offering(&issuer, &token);
+    assert_eq!(offering.decimals, 6);
+    assert_eq!(offering.max_supply, 0);
+    assert_eq!(client.migrate_storage(&issuer), 0);
+}
+
+// ===========================================================================
+// Blacklist reasons export
+// ===========================================================================
+
+#[test]
+fn get_blacklist_reasons_page_returns_reasons_and_times_in_insertion_order() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+
+    env.ledger().set_timestamp(100);
+    client.blacklist_add_with_reason(&issuer, &token, &a, &7);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("bl_add_r"), token.clone(), issuer.clone()).into_val(&env)
+    );
+    let data: (Address, u32) = data.into_val(&env);
+    assert_eq!(data, (a.clone(), 7));
+
+    env.ledger().set_timestamp(200);
+    client.blacklist_add(&issuer, &token, &b);
+    env.ledger().set_timestamp(300);
+    client.blacklist_add_with_reason(&issuer, &token, &c, &3);
+    // Re-listing keeps the first reason and time.
+    client.blacklist_add_with_reason(&issuer, &token, &a, &9);
+
+    let (page, next) = client.get_blacklist_reasons_page(&token, &0, &2);
+    assert_eq!(
+        page,
+        vec![
+            &env,
+            (a.clone(), 7u32, 100u64),
+            (b.clone(), crate::BLACKLIST_REASON_UNSPECIFIED, 200u64)
+        ]
+    );
+    assert_eq!(next, Some(2));
+    let (page, next) = client.get_blacklist_reasons_page(&token, &2, &2);
+    assert_eq!(page, vec![&env, (c.clone(), 3u32, 300u64)]);
+    assert_eq!(next, None);
+
+    // Removal drops the details; re-adding records fresh ones.
+    client.blacklist_remove(&issuer, &token, &a);
+    env.ledger().set_timestamp(400);
+    client.blacklist_add_with_reason(&issuer, &token, &a, &5);
+    let (page, _) = client.get_blacklist_reasons_page(&token, &0, &0);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(2), Some((a.clone(), 5u32, 400u64)));
+
+    // Transferred entries keep their reason and listing time.
+    let new_token = Address::generate(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.blacklist_transfer(&admin, &token, &new_token);
+    let (moved, _) = client.get_blacklist_reasons_page(&new_token, &0, &0);
+    assert_eq!(moved, page);
+}
+
+#[test]
+fn get_blacklist_reasons_page_caps_limit_and_handles_empty() {
+    let (env, client, _issuer) = setup();
+    let token = Address::generate(&env);
+    let (page, next) = client.get_blacklist_reasons_page(&token, &0, &5);
+    assert_eq!((page.len(), next), (0, None));
+
+    blacklist_n(&env, &client, &token, 25);
+    let (page, next) = client.get_blacklist_reasons_page(&token, &0, &100);
+    assert_eq!((page.len(), next), (20, Some(20)));
+    let (page, next) = client.get_blacklist_reasons_page(&token, &20, &100);
+    assert_eq!((page.len(), next), (5, None));
+}
+
+// ===========================================================================
+// Offering status
+// ===========================================================================
+
+#[test]
+fn set_offering_status_walks_valid_transitions() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    assert_eq!(
+        client.get_offering_status(&token),
+        Some(OfferingStatus::Active)
+    );
+    assert_eq!(client.get_offering_status(&Address::generate(&env)), None);
+
+    client.set_offering_status(&admin, &token, &OfferingStatus::Paused);
+    assert_eq!(
+        client.get_offering_status(&token),
+        Some(OfferingStatus::Paused)
+    );
+    assert!(client.is_offering_paused(&token));
+    let r = client.try_report_revenue(&issuer, &token, &token, &100, &1, &false);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingPaused)));
+
+    client.set_offering_status(&issuer, &token, &OfferingStatus::Active);
+    assert_eq!(
+        client.get_offering_status(&token),
+        Some(OfferingStatus::Active)
+    );
+    client.report_revenue(&issuer, &token, &token, &100, &1, &false);
+
+    // Only the issuer may close; the admin can merely pause.
+    let r = client.try_set_offering_status(&admin, &token, &OfferingStatus::Closed);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+    let stranger = Address::generate(&env);
+    let r = client.try_set_offering_status(&stranger, &token, &OfferingStatus::Paused);
+    assert_eq!(r, Err(Ok(RevoraError::Unauthorized)));
+
+    client.set_offering_status(&issuer, &token, &OfferingStatus::Paused);
+    client.set_offering_status(&issuer, &token, &OfferingStatus::Closed);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("offer_cls"), issuer.clone()).into_val(&env)
+    );
+    let data: Address = data.into_val(&env);
+    assert_eq!(data, token);
+    assert_eq!(
+        client.get_offering_status(&token),
+        Some(OfferingStatus::Closed)
+    );
+    assert!(client
+        .get_offering_history(&issuer, &token)
+        .unwrap()
+        .closed_at
+        .is_some());
+    let r = client.try_report_revenue(&issuer, &token, &token, &100, &2, &false);
+    assert_eq!(r, Err(Ok(RevoraError::OfferingClosed)));
+}
+
+#[test]
+fn closed_offering_cannot_be_reopened_or_paused() {
+    let (env, client, issuer) = setup();
+    let token = Address::generate(&env);
+    client.register_offering(&issuer, &token, &1_000, &token);
+    client.close_offering(&issuer, &token);
+
+    for status in [
+        OfferingStatus::Active,
+        OfferingStatus::Paused,
+        OfferingStatus::Closed,
+    ] {
+        let r = client.try_set_offering_status(&issuer, &token, &status);
+        assert_eq!(
+            r.unwrap_err().unwrap_err(),
+            soroban_sdk::InvokeError::Contract(
+                crate::RevoraErrorExt::InvalidStatusTransition as u32
+            )
+        );
+    }
+    let r = client.try_unpause_offering(&issuer, &token);
+    assert_eq!(
+        r.unwrap_err().unwrap_err(),
+        soroban_sdk::InvokeError::Contract(crate::RevoraErrorExt::InvalidStatusTransition as u32)
+    );
+    assert_eq!(
+        client.get_offering_status(&token),
+        Some(OfferingStatus::Closed)
+    );
+}
+
+#[test]
+fn migrate_storage_folds_active_and_pause_flags_into_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, RevoraRevenueShare);
+    let client = RevoraRevenueShareClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let mut tokens = Vec::new(&env);
+    for (i, (active, paused)) in [(true, false), (true, true), (false, true)]
+        .iter()
+        .enumerate()
+    {
+        let token = Address::generate(&env);
+        client.register_offering_with_cap(&issuer, &token, &1_000, &token, &500);
+        let v3 = crate::OfferingV3 {
+            issuer: issuer.clone(),
+            token: token.clone(),
+            revenue_share_bps: 1_000,
+            payout_asset: token.clone(),
+            active: *active,
+            decimals: 7,
+            max_supply: 500,
+        };
+        env.as_contract(&contract_id, || {
+            let store = env.storage().persistent();
+            store.set(&crate::DataKey::OfferItem(issuer.clone(), i as u32), &v3);
+            if *paused {
+                store.set(&crate::DataKeyExt::OfferingPaused(token.clone()), &true);
+            }
+        });
+        tokens.push_back(token);
+    }
+
+    assert_eq!(client.migrate_storage(&issuer), 3);
+    let statuses: Vec<Option<OfferingStatus>> = vec![
+        &env,
+        client.get_offering_status(&tokens.get(0).unwrap()),
+        client.get_offering_status(&tokens.get(1).unwrap()),
+        client.get_offering_status(&tokens.get(2).unwrap()),
+    ];
+    assert_eq!(
+        statuses,
+        vec![
+            &env,
+            Some(OfferingStatus::Active),
+            Some(OfferingStatus::Paused),
+            Some(OfferingStatus::Closed)
+        ]
+    );
+    assert_eq!(client.get_offering_supply_cap(&tokens.get(1).unwrap()), 500);
+    assert_eq!(client.migrate_storage(&issuer), 0);
+}